@@ -18,6 +18,7 @@
     clippy::struct_field_names,
     clippy::too_many_arguments,
     clippy::too_many_lines,
+    clippy::unnecessary_trailing_comma,
     clippy::wildcard_imports
 )]
 
@@ -26,12 +27,13 @@
 //
 
 use std::collections::HashMap;
-use std::ffi::{c_void, CString};
+use std::ffi::{c_void, CStr, CString};
 use std::mem::{size_of, size_of_val, zeroed, MaybeUninit};
 use std::ptr::{addr_of, addr_of_mut, null, null_mut};
+use std::sync::{Mutex, MutexGuard};
 
 use anyhow::{bail, ensure, Context, Result};
-use log::{debug, error, log};
+use log::{debug, error, log, warn};
 use vulk::vk;
 
 //
@@ -0,0 +1,892 @@
+use std::collections::BTreeSet;
+
+use super::*;
+
+/// Size of the `VkDeviceMemory` blocks allocations are carved out of. Larger
+/// requests get a block of their own, sized exactly to the request.
+pub const MEMORY_BLOCK_SIZE: vk::DeviceSize = 64 * 1024 * 1024;
+
+/// Smallest sub-allocation, smaller requests are rounded up.
+const MIN_ALLOCATION_SIZE: vk::DeviceSize = 256;
+
+/// Fraction of a heap budget above which the allocator starts warning.
+pub const MEMORY_BUDGET_WARNING_FRACTION: f64 = 0.9;
+
+/// Resources with different tilings must not share a `bufferImageGranularity`
+/// page. Buffers are always linear.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AllocationTiling {
+    Linear,
+    Optimal,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MemoryAllocation {
+    memory: vk::DeviceMemory,
+    offset: vk::DeviceSize,
+    size: vk::DeviceSize,
+    memory_type_index: u32,
+    block_id: u64,
+    ptr: Option<*mut c_void>,
+}
+
+impl MemoryAllocation {
+    #[must_use]
+    pub fn memory(&self) -> vk::DeviceMemory {
+        self.memory
+    }
+
+    #[must_use]
+    pub fn offset(&self) -> vk::DeviceSize {
+        self.offset
+    }
+
+    /// Size reserved in the block, at least the requested size.
+    #[must_use]
+    pub fn size(&self) -> vk::DeviceSize {
+        self.size
+    }
+
+    #[must_use]
+    pub fn memory_type_index(&self) -> u32 {
+        self.memory_type_index
+    }
+
+    /// Host pointer to the start of the allocation if the memory type is host
+    /// visible.
+    #[must_use]
+    pub fn ptr(&self) -> Option<*mut c_void> {
+        self.ptr
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MemoryHeapStatistics {
+    pub block_count: u32,
+    pub block_bytes: vk::DeviceSize,
+    pub allocation_count: u32,
+    pub allocation_bytes: vk::DeviceSize,
+}
+
+/// Allocator statistics of a heap next to its budget.
+#[derive(Clone, Copy, Debug)]
+pub struct MemoryHeapReport {
+    pub heap_index: u32,
+    pub budget: MemoryHeapBudget,
+    pub statistics: MemoryHeapStatistics,
+}
+
+impl MemoryHeapReport {
+    #[must_use]
+    pub fn is_near_budget(&self) -> bool {
+        self.budget.usage_fraction() >= MEMORY_BUDGET_WARNING_FRACTION
+    }
+}
+
+/// Buddy allocator over large `VkDeviceMemory` blocks, one set of blocks per
+/// memory type. When `bufferImageGranularity` is larger than the smallest
+/// sub-allocation, linear and optimal resources are kept in separate blocks.
+///
+/// Budget warnings are only as good as the last budget passed to
+/// [`MemoryAllocator::update_budget`], which callers must call periodically,
+/// for example once per frame.
+#[derive(Debug)]
+pub struct MemoryAllocator {
+    memory_properties: vk::PhysicalDeviceMemoryProperties,
+    buffer_image_granularity: vk::DeviceSize,
+    blocks: Vec<MemoryBlock>,
+    next_block_id: u64,
+    budget: Vec<MemoryHeapBudget>,
+    /// Block bytes allocated and freed per heap since the last budget update.
+    budget_allocated: Vec<vk::DeviceSize>,
+    budget_freed: Vec<vk::DeviceSize>,
+}
+
+// Mapped pointers are only handed out through allocations, the allocator
+// itself never dereferences them.
+unsafe impl Send for MemoryAllocator {}
+
+impl MemoryAllocator {
+    #[must_use]
+    pub fn new(
+        memory_properties: &vk::PhysicalDeviceMemoryProperties,
+        buffer_image_granularity: vk::DeviceSize,
+    ) -> Self {
+        Self {
+            memory_properties: *memory_properties,
+            buffer_image_granularity,
+            blocks: vec![],
+            next_block_id: 0,
+            budget: vec![],
+            budget_allocated: vec![0; memory_properties.memory_heap_count as _],
+            budget_freed: vec![0; memory_properties.memory_heap_count as _],
+        }
+    }
+
+    pub unsafe fn destroy(&mut self, device: &vulk::Device) {
+        for block in self.blocks.drain(..) {
+            if !block.allocator.is_empty() {
+                error!(
+                    "Freeing memory block {} with {} live allocations",
+                    block.id,
+                    block.allocator.allocation_count()
+                );
+            }
+            device.free_memory(block.memory);
+        }
+    }
+
+    pub unsafe fn allocate(
+        &mut self,
+        device: &vulk::Device,
+        requirements: &vk::MemoryRequirements,
+        property_flags: impl Into<vk::MemoryPropertyFlags> + Copy,
+        tiling: AllocationTiling,
+    ) -> Result<MemoryAllocation> {
+        // Memory type index.
+        let memory_type_index = memory_type_index(
+            &self.memory_properties,
+            property_flags,
+            requirements.memory_type_bits,
+        )?;
+        let tiling = self.block_tiling(tiling);
+
+        // Existing blocks.
+        if !is_dedicated_request(requirements) {
+            if let Some(allocation) =
+                self.allocate_from_blocks(memory_type_index, tiling, requirements)
+            {
+                return Ok(allocation);
+            }
+        }
+
+        // New block.
+        let block_allocator = BlockAllocator::for_request(requirements);
+        let block_size = block_allocator.size();
+        let heap_index = self.heap_index(memory_type_index);
+        if let Some((usage, budget)) = self.budget_usage(heap_index) {
+            if usage + block_size > budget {
+                warn!(
+                    "Allocating memory block size={block_size} exceeds the last known budget \
+                    of heap {heap_index}, usage={usage} budget={budget}"
+                );
+            }
+        }
+        let mut block = MemoryBlock::create(
+            device,
+            &self.memory_properties,
+            self.next_block_id,
+            memory_type_index,
+            tiling,
+            block_allocator,
+        )?;
+        self.next_block_id += 1;
+        let (offset, size) = block
+            .allocator
+            .allocate(requirements.size, requirements.alignment)
+            .context("Allocating from a new memory block")?;
+        let allocation = block.allocation(offset, size);
+        self.blocks.push(block);
+        self.budget_allocated[heap_index] += block_size;
+        Ok(allocation)
+    }
+
+    fn heap_index(&self, memory_type_index: u32) -> usize {
+        self.memory_properties.memory_types[memory_type_index as usize].heap_index as usize
+    }
+
+    /// Estimated usage and budget of a heap, if a budget has been recorded.
+    fn budget_usage(&self, heap_index: usize) -> Option<(vk::DeviceSize, vk::DeviceSize)> {
+        let budget = self.budget.get(heap_index)?;
+        let usage = (budget.usage + self.budget_allocated[heap_index])
+            .saturating_sub(self.budget_freed[heap_index]);
+        Some((usage, budget.budget))
+    }
+
+    /// Tiling that blocks are kept separate by, if any.
+    fn block_tiling(&self, tiling: AllocationTiling) -> Option<AllocationTiling> {
+        (self.buffer_image_granularity > MIN_ALLOCATION_SIZE).then_some(tiling)
+    }
+
+    unsafe fn allocate_from_blocks(
+        &mut self,
+        memory_type_index: u32,
+        tiling: Option<AllocationTiling>,
+        requirements: &vk::MemoryRequirements,
+    ) -> Option<MemoryAllocation> {
+        for block in &mut self.blocks {
+            if block.memory_type_index != memory_type_index || block.tiling != tiling {
+                continue;
+            }
+            if let Some((offset, size)) = block
+                .allocator
+                .allocate(requirements.size, requirements.alignment)
+            {
+                return Some(block.allocation(offset, size));
+            }
+        }
+        None
+    }
+
+    /// Returns the allocation to its block. Empty dedicated blocks are
+    /// released, while one empty block per memory type and tiling is kept for
+    /// later allocations until [`MemoryAllocator::trim`].
+    pub unsafe fn free(&mut self, device: &vulk::Device, allocation: MemoryAllocation) {
+        let block_index = self
+            .blocks
+            .iter()
+            .position(|block| block.id == allocation.block_id)
+            .expect("Allocation does not belong to this allocator");
+        let block = &mut self.blocks[block_index];
+        block.allocator.free(allocation.offset);
+        if block.allocator.is_empty() && self.releases_empty_block(block_index) {
+            self.release_block(device, block_index);
+        }
+    }
+
+    /// Releases every empty block, including the ones kept for later
+    /// allocations.
+    pub unsafe fn trim(&mut self, device: &vulk::Device) {
+        let mut block_index = 0;
+        while block_index < self.blocks.len() {
+            if self.blocks[block_index].allocator.is_empty() {
+                self.release_block(device, block_index);
+            } else {
+                block_index += 1;
+            }
+        }
+    }
+
+    /// Whether an empty block is released rather than kept, which is the case
+    /// for dedicated blocks and for blocks with another empty block of the
+    /// same memory type and tiling.
+    fn releases_empty_block(&self, block_index: usize) -> bool {
+        let block = &self.blocks[block_index];
+        block.allocator.is_dedicated()
+            || self.blocks.iter().enumerate().any(|(index, other)| {
+                index != block_index
+                    && other.memory_type_index == block.memory_type_index
+                    && other.tiling == block.tiling
+                    && !other.allocator.is_dedicated()
+                    && other.allocator.is_empty()
+            })
+    }
+
+    unsafe fn release_block(&mut self, device: &vulk::Device, block_index: usize) {
+        let block = self.blocks.swap_remove(block_index);
+        device.free_memory(block.memory);
+        let heap_index = self.heap_index(block.memory_type_index);
+        self.budget_freed[heap_index] += block.allocator.size();
+    }
+
+    /// Records the latest budget from [`PhysicalDevice::memory_budget`] and
+    /// logs the allocator usage against it. Later block allocations warn if
+    /// they would exceed the recorded budget, counting the blocks allocated and
+    /// freed since this call.
+    pub fn update_budget(&mut self, budget: &[MemoryHeapBudget]) -> Vec<MemoryHeapReport> {
+        self.budget = budget.to_vec();
+        self.budget_allocated.fill(0);
+        self.budget_freed.fill(0);
+        let reports = budget
+            .iter()
+            .zip(self.statistics())
+            .enumerate()
+            .map(|(heap_index, (&budget, statistics))| MemoryHeapReport {
+                heap_index: heap_index as u32,
+                budget,
+                statistics,
+            })
+            .collect::<Vec<_>>();
+        for report in &reports {
+            let level = if report.is_near_budget() {
+                log::Level::Warn
+            } else {
+                log::Level::Debug
+            };
+            log!(
+                level,
+                "Heap {}: allocator blocks={} bytes={} used={}, process usage={} budget={} size={}",
+                report.heap_index,
+                report.statistics.block_count,
+                report.statistics.block_bytes,
+                report.statistics.allocation_bytes,
+                report.budget.usage,
+                report.budget.budget,
+                report.budget.size
+            );
+        }
+        reports
+    }
+
+    #[must_use]
+    pub fn statistics(&self) -> Vec<MemoryHeapStatistics> {
+        let memory = &self.memory_properties;
+        let mut statistics = vec![MemoryHeapStatistics::default(); memory.memory_heap_count as _];
+        for block in &self.blocks {
+            let memory_type = memory.memory_types[block.memory_type_index as usize];
+            let heap = &mut statistics[memory_type.heap_index as usize];
+            heap.block_count += 1;
+            heap.block_bytes += block.allocator.size();
+            heap.allocation_count += block.allocator.allocation_count() as u32;
+            heap.allocation_bytes += block.allocator.allocated_bytes();
+        }
+        statistics
+    }
+}
+
+//
+// Blocks
+//
+
+#[derive(Debug)]
+struct MemoryBlock {
+    id: u64,
+    memory: vk::DeviceMemory,
+    memory_type_index: u32,
+    tiling: Option<AllocationTiling>,
+    ptr: Option<*mut c_void>,
+    allocator: BlockAllocator,
+}
+
+impl MemoryBlock {
+    unsafe fn create(
+        device: &vulk::Device,
+        memory_properties: &vk::PhysicalDeviceMemoryProperties,
+        id: u64,
+        memory_type_index: u32,
+        tiling: Option<AllocationTiling>,
+        allocator: BlockAllocator,
+    ) -> Result<Self> {
+        // Allocation.
+        let size = allocator.size();
+        let memory_allocate_flags_info = vk::MemoryAllocateFlagsInfo {
+            s_type: vk::StructureType::MemoryAllocateFlagsInfo,
+            p_next: null(),
+            flags: vk::MemoryAllocateFlagBits::DeviceAddress.into(),
+            device_mask: 0,
+        };
+        let memory = device
+            .allocate_memory(&vk::MemoryAllocateInfo {
+                s_type: vk::StructureType::MemoryAllocateInfo,
+                p_next: addr_of!(memory_allocate_flags_info).cast(),
+                allocation_size: size,
+                memory_type_index,
+            })
+            .with_context(|| {
+                format!("Allocating memory block size={size} type={memory_type_index}")
+            })?;
+
+        // Map memory.
+        let mut ptr = None;
+        if memory_properties.memory_types[memory_type_index as usize]
+            .property_flags
+            .contains(vk::MemoryPropertyFlagBits::HostVisible)
+        {
+            let mapped = device
+                .map_memory2_khr(&vk::MemoryMapInfoKHR {
+                    s_type: vk::StructureType::MemoryMapInfoKHR,
+                    p_next: null(),
+                    flags: vk::MemoryMapFlags::empty(),
+                    memory,
+                    offset: 0,
+                    size,
+                })
+                .with_context(|| format!("Mapping memory block size={size}"))?;
+            ptr = Some(mapped);
+        }
+
+        debug!("Allocated memory block id={id} size={size} type={memory_type_index}");
+        Ok(Self {
+            id,
+            memory,
+            memory_type_index,
+            tiling,
+            ptr,
+            allocator,
+        })
+    }
+
+    unsafe fn allocation(&self, offset: vk::DeviceSize, size: vk::DeviceSize) -> MemoryAllocation {
+        MemoryAllocation {
+            memory: self.memory,
+            offset,
+            size,
+            memory_type_index: self.memory_type_index,
+            block_id: self.id,
+            ptr: self.ptr.map(|ptr| ptr.add(offset as _)),
+        }
+    }
+}
+
+/// Sub-allocator of a memory block.
+#[derive(Debug)]
+enum BlockAllocator {
+    Buddy(BuddyAllocator),
+    /// The whole block belongs to a single allocation.
+    Dedicated {
+        size: vk::DeviceSize,
+        allocated: bool,
+    },
+}
+
+impl BlockAllocator {
+    /// Allocator of a new block for `requirements`.
+    fn for_request(requirements: &vk::MemoryRequirements) -> Self {
+        if is_dedicated_request(requirements) {
+            Self::Dedicated {
+                size: requirements.size,
+                allocated: false,
+            }
+        } else {
+            Self::Buddy(BuddyAllocator::new(MEMORY_BLOCK_SIZE))
+        }
+    }
+
+    fn allocate(
+        &mut self,
+        size: vk::DeviceSize,
+        alignment: vk::DeviceSize,
+    ) -> Option<(vk::DeviceSize, vk::DeviceSize)> {
+        match self {
+            Self::Buddy(buddy) => buddy.allocate(size, alignment),
+            Self::Dedicated {
+                size: block_size,
+                allocated,
+            } => {
+                if *allocated || size > *block_size {
+                    return None;
+                }
+                *allocated = true;
+                Some((0, *block_size))
+            }
+        }
+    }
+
+    fn free(&mut self, offset: vk::DeviceSize) {
+        match self {
+            Self::Buddy(buddy) => buddy.free(offset),
+            Self::Dedicated { allocated, .. } => {
+                assert!(
+                    *allocated && offset == 0,
+                    "Freeing an offset that was not allocated"
+                );
+                *allocated = false;
+            }
+        }
+    }
+
+    fn is_dedicated(&self) -> bool {
+        matches!(self, Self::Dedicated { .. })
+    }
+
+    fn size(&self) -> vk::DeviceSize {
+        match *self {
+            Self::Buddy(ref buddy) => buddy.size,
+            Self::Dedicated { size, .. } => size,
+        }
+    }
+
+    fn allocated_bytes(&self) -> vk::DeviceSize {
+        match *self {
+            Self::Buddy(ref buddy) => buddy.allocated_bytes,
+            Self::Dedicated { size, allocated } => {
+                if allocated {
+                    size
+                } else {
+                    0
+                }
+            }
+        }
+    }
+
+    fn allocation_count(&self) -> usize {
+        match *self {
+            Self::Buddy(ref buddy) => buddy.allocation_count(),
+            Self::Dedicated { allocated, .. } => usize::from(allocated),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match *self {
+            Self::Buddy(ref buddy) => buddy.is_empty(),
+            Self::Dedicated { allocated, .. } => !allocated,
+        }
+    }
+}
+
+//
+// Buddy allocator
+//
+
+/// Power-of-two buddy allocator. Every allocation is aligned to its own size,
+/// so any power-of-two alignment up to the size is satisfied for free.
+#[derive(Debug)]
+struct BuddyAllocator {
+    size: vk::DeviceSize,
+    free_lists: Vec<BTreeSet<vk::DeviceSize>>,
+    allocated: HashMap<vk::DeviceSize, u32>,
+    allocated_bytes: vk::DeviceSize,
+}
+
+impl BuddyAllocator {
+    const MIN_ORDER: u32 = MIN_ALLOCATION_SIZE.trailing_zeros();
+
+    fn new(size: vk::DeviceSize) -> Self {
+        assert!(size.is_power_of_two() && size >= MIN_ALLOCATION_SIZE);
+        let max_order = size.trailing_zeros();
+        let mut free_lists = vec![BTreeSet::new(); (max_order - Self::MIN_ORDER + 1) as usize];
+        free_lists.last_mut().unwrap().insert(0);
+        Self {
+            size,
+            free_lists,
+            allocated: HashMap::new(),
+            allocated_bytes: 0,
+        }
+    }
+
+    fn max_order(&self) -> u32 {
+        self.size.trailing_zeros()
+    }
+
+    fn free_list(&mut self, order: u32) -> &mut BTreeSet<vk::DeviceSize> {
+        &mut self.free_lists[(order - Self::MIN_ORDER) as usize]
+    }
+
+    fn allocate(
+        &mut self,
+        size: vk::DeviceSize,
+        alignment: vk::DeviceSize,
+    ) -> Option<(vk::DeviceSize, vk::DeviceSize)> {
+        let order = size
+            .max(alignment)
+            .max(MIN_ALLOCATION_SIZE)
+            .next_power_of_two()
+            .trailing_zeros();
+        if order > self.max_order() {
+            return None;
+        }
+
+        // Smallest free order that fits, then split down.
+        let mut free_order = (order..=self.max_order()).find(|&o| !self.free_list(o).is_empty())?;
+        let offset = self.free_list(free_order).pop_first()?;
+        while free_order > order {
+            free_order -= 1;
+            self.free_list(free_order)
+                .insert(offset + (1 << free_order));
+        }
+
+        self.allocated.insert(offset, order);
+        self.allocated_bytes += 1 << order;
+        Some((offset, 1 << order))
+    }
+
+    fn free(&mut self, offset: vk::DeviceSize) {
+        let mut order = self
+            .allocated
+            .remove(&offset)
+            .expect("Freeing an offset that was not allocated");
+        self.allocated_bytes -= 1 << order;
+
+        // Merge with free buddies.
+        let mut offset = offset;
+        while order < self.max_order() {
+            let buddy = offset ^ (1 << order);
+            if !self.free_list(order).remove(&buddy) {
+                break;
+            }
+            offset = offset.min(buddy);
+            order += 1;
+        }
+        self.free_list(order).insert(offset);
+    }
+
+    fn allocation_count(&self) -> usize {
+        self.allocated.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.allocated.is_empty()
+    }
+}
+
+//
+// Utilities
+//
+
+/// Requests that do not fit in a regular block get a dedicated block of their
+/// exact size, which satisfies any alignment since it starts at offset zero.
+fn is_dedicated_request(requirements: &vk::MemoryRequirements) -> bool {
+    requirements.size.max(requirements.alignment) > MEMORY_BLOCK_SIZE
+}
+
+fn memory_type_index(
+    memory: &vk::PhysicalDeviceMemoryProperties,
+    property_flags: impl Into<vk::MemoryPropertyFlags> + Copy,
+    memory_type_bits: u32,
+) -> Result<u32> {
+    let property_flags = property_flags.into();
+    for memory_type_index in 0..memory.memory_type_count {
+        let memory_type = memory.memory_types[memory_type_index as usize];
+        let type_matches = (1 << memory_type_index) & memory_type_bits != 0;
+        let property_matches = memory_type.property_flags.contains(property_flags);
+        if type_matches && property_matches {
+            debug!(
+                "index={}, type={:?}, heap={:?}",
+                memory_type_index,
+                &memory.memory_types[memory_type_index as usize].property_flags,
+                &memory.memory_heaps[memory_type.heap_index as usize].flags
+            );
+            return Ok(memory_type_index);
+        }
+    }
+    bail!(
+        "Unable to find a memory type with memory_type_bits=0b{memory_type_bits:b} \
+        and property_flags={property_flags:?}"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buddy_split_and_merge() {
+        let mut buddy = BuddyAllocator::new(4 * MIN_ALLOCATION_SIZE);
+        assert_eq!(buddy.allocate(1, 1), Some((0, MIN_ALLOCATION_SIZE)));
+        assert_eq!(
+            buddy.allocate(MIN_ALLOCATION_SIZE, 1),
+            Some((MIN_ALLOCATION_SIZE, MIN_ALLOCATION_SIZE))
+        );
+        assert_eq!(
+            buddy.allocate(2 * MIN_ALLOCATION_SIZE, 1),
+            Some((2 * MIN_ALLOCATION_SIZE, 2 * MIN_ALLOCATION_SIZE))
+        );
+        assert_eq!(buddy.allocation_count(), 3);
+        assert_eq!(buddy.allocated_bytes, buddy.size);
+
+        // Buddies merge back into a single free block.
+        buddy.free(MIN_ALLOCATION_SIZE);
+        buddy.free(0);
+        assert_eq!(
+            buddy.allocate(2 * MIN_ALLOCATION_SIZE, 1),
+            Some((0, 2 * MIN_ALLOCATION_SIZE))
+        );
+        buddy.free(0);
+        buddy.free(2 * MIN_ALLOCATION_SIZE);
+        assert!(buddy.is_empty());
+        assert_eq!(buddy.allocated_bytes, 0);
+        assert_eq!(buddy.allocate(buddy.size, 1), Some((0, buddy.size)));
+    }
+
+    #[test]
+    fn buddy_alignment() {
+        let mut buddy = BuddyAllocator::new(16 * MIN_ALLOCATION_SIZE);
+        assert_eq!(buddy.allocate(1, 1), Some((0, MIN_ALLOCATION_SIZE)));
+
+        // Alignments above the smallest order round the allocation up.
+        let alignment = 4 * MIN_ALLOCATION_SIZE;
+        let (offset, size) = buddy.allocate(1, alignment).unwrap();
+        assert_eq!(offset % alignment, 0);
+        assert_eq!(offset, alignment);
+        assert_eq!(size, alignment);
+    }
+
+    #[test]
+    fn buddy_exhaustion() {
+        let mut buddy = BuddyAllocator::new(2 * MIN_ALLOCATION_SIZE);
+        assert_eq!(buddy.allocate(4 * MIN_ALLOCATION_SIZE, 1), None);
+        assert_eq!(buddy.allocate(1, 4 * MIN_ALLOCATION_SIZE), None);
+        assert!(buddy.allocate(1, 1).is_some());
+        assert!(buddy.allocate(1, 1).is_some());
+        assert_eq!(buddy.allocate(1, 1), None);
+        buddy.free(0);
+        assert_eq!(buddy.allocate(1, 1), Some((0, MIN_ALLOCATION_SIZE)));
+    }
+
+    #[test]
+    fn memory_type_index_without_match() {
+        let mut memory_properties = vk::PhysicalDeviceMemoryProperties {
+            memory_type_count: 2,
+            memory_heap_count: 1,
+            ..Default::default()
+        };
+        memory_properties.memory_types[0].property_flags =
+            vk::MemoryPropertyFlagBits::DeviceLocal.into();
+        memory_properties.memory_types[1].property_flags =
+            vk::MemoryPropertyFlagBits::HostVisible | vk::MemoryPropertyFlagBits::HostCoherent;
+
+        assert_eq!(
+            memory_type_index(
+                &memory_properties,
+                vk::MemoryPropertyFlagBits::HostVisible,
+                0b11
+            )
+            .unwrap(),
+            1
+        );
+        let error = memory_type_index(
+            &memory_properties,
+            vk::MemoryPropertyFlagBits::HostVisible,
+            0b01,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(error.contains("memory_type_bits=0b1 "), "{error}");
+        assert!(error.contains("HostVisible"), "{error}");
+    }
+
+    #[test]
+    fn allocator_budget_usage() {
+        let memory_properties = vk::PhysicalDeviceMemoryProperties {
+            memory_type_count: 1,
+            memory_heap_count: 1,
+            ..Default::default()
+        };
+        let mut allocator = MemoryAllocator::new(&memory_properties, 1);
+        assert_eq!(allocator.budget_usage(0), None);
+
+        let budget = MemoryHeapBudget {
+            flags: vk::MemoryHeapFlags::empty(),
+            size: 4 * MEMORY_BLOCK_SIZE,
+            budget: 2 * MEMORY_BLOCK_SIZE,
+            usage: MEMORY_BLOCK_SIZE,
+        };
+        allocator.update_budget(&[budget]);
+        assert_eq!(
+            allocator.budget_usage(0),
+            Some((MEMORY_BLOCK_SIZE, 2 * MEMORY_BLOCK_SIZE))
+        );
+
+        // Blocks allocated and freed since the update count against it.
+        allocator.budget_allocated[0] += 2 * MEMORY_BLOCK_SIZE;
+        allocator.budget_freed[0] += MEMORY_BLOCK_SIZE;
+        assert_eq!(
+            allocator.budget_usage(0),
+            Some((2 * MEMORY_BLOCK_SIZE, 2 * MEMORY_BLOCK_SIZE))
+        );
+        allocator.update_budget(&[budget]);
+        assert_eq!(
+            allocator.budget_usage(0),
+            Some((MEMORY_BLOCK_SIZE, 2 * MEMORY_BLOCK_SIZE))
+        );
+    }
+
+    #[test]
+    fn allocator_tiling_separation() {
+        let requirements = vk::MemoryRequirements {
+            size: MIN_ALLOCATION_SIZE,
+            alignment: 1,
+            memory_type_bits: 1,
+        };
+        let block = |tiling| MemoryBlock {
+            id: 0,
+            memory: vk::DeviceMemory::null(),
+            memory_type_index: 0,
+            tiling,
+            ptr: None,
+            allocator: BlockAllocator::Buddy(BuddyAllocator::new(MEMORY_BLOCK_SIZE)),
+        };
+        let memory_properties = vk::PhysicalDeviceMemoryProperties::default();
+
+        // Granularity larger than the smallest sub-allocation.
+        let mut allocator = MemoryAllocator::new(&memory_properties, 4 * MIN_ALLOCATION_SIZE);
+        let linear = allocator.block_tiling(AllocationTiling::Linear);
+        let optimal = allocator.block_tiling(AllocationTiling::Optimal);
+        assert_eq!(linear, Some(AllocationTiling::Linear));
+        assert_eq!(optimal, Some(AllocationTiling::Optimal));
+        allocator.blocks.push(block(linear));
+        unsafe {
+            assert!(allocator
+                .allocate_from_blocks(0, optimal, &requirements)
+                .is_none());
+            assert!(allocator
+                .allocate_from_blocks(0, linear, &requirements)
+                .is_some());
+            assert!(allocator
+                .allocate_from_blocks(1, linear, &requirements)
+                .is_none());
+        }
+
+        // Granularity that never puts two sub-allocations on a page.
+        let mut allocator = MemoryAllocator::new(&memory_properties, MIN_ALLOCATION_SIZE);
+        let linear = allocator.block_tiling(AllocationTiling::Linear);
+        let optimal = allocator.block_tiling(AllocationTiling::Optimal);
+        assert_eq!(linear, None);
+        assert_eq!(optimal, None);
+        allocator.blocks.push(block(linear));
+        unsafe {
+            assert!(allocator
+                .allocate_from_blocks(0, optimal, &requirements)
+                .is_some());
+        }
+    }
+
+    #[test]
+    fn dedicated_block_allocator() {
+        let requirements = |size, alignment| vk::MemoryRequirements {
+            size,
+            alignment,
+            memory_type_bits: 1,
+        };
+
+        // Requests that fit share regular blocks.
+        for request in [requirements(1, 1), requirements(MEMORY_BLOCK_SIZE, 1)] {
+            let allocator = BlockAllocator::for_request(&request);
+            assert!(!allocator.is_dedicated());
+            assert_eq!(allocator.size(), MEMORY_BLOCK_SIZE);
+        }
+
+        // Larger requests get exactly their size, not a power of two.
+        let size = MEMORY_BLOCK_SIZE + MIN_ALLOCATION_SIZE;
+        let mut allocator = BlockAllocator::for_request(&requirements(size, 4096));
+        assert!(allocator.is_dedicated());
+        assert_eq!(allocator.size(), size);
+        assert_eq!(allocator.allocate(size, 4096), Some((0, size)));
+        assert_eq!(allocator.allocation_count(), 1);
+        assert_eq!(allocator.allocated_bytes(), size);
+        assert_eq!(allocator.allocate(1, 1), None);
+        allocator.free(0);
+        assert!(allocator.is_empty());
+        assert_eq!(allocator.allocated_bytes(), 0);
+
+        // So do alignments larger than a regular block.
+        let allocator = BlockAllocator::for_request(&requirements(1, 2 * MEMORY_BLOCK_SIZE));
+        assert!(allocator.is_dedicated());
+        assert_eq!(allocator.size(), 1);
+    }
+
+    #[test]
+    fn allocator_keeps_one_empty_block() {
+        let block = |id, memory_type_index, allocator| MemoryBlock {
+            id,
+            memory: vk::DeviceMemory::null(),
+            memory_type_index,
+            tiling: None,
+            ptr: None,
+            allocator,
+        };
+        let buddy = || BlockAllocator::Buddy(BuddyAllocator::new(MEMORY_BLOCK_SIZE));
+        let dedicated = || BlockAllocator::Dedicated {
+            size: 2 * MEMORY_BLOCK_SIZE,
+            allocated: false,
+        };
+        let memory_properties = vk::PhysicalDeviceMemoryProperties::default();
+        let mut allocator = MemoryAllocator::new(&memory_properties, 1);
+
+        // The only empty block of its memory type is kept.
+        allocator.blocks.push(block(0, 0, buddy()));
+        allocator.blocks.push(block(1, 1, buddy()));
+        allocator.blocks.push(block(2, 0, dedicated()));
+        assert!(!allocator.releases_empty_block(0));
+        assert!(!allocator.releases_empty_block(1));
+
+        // Dedicated blocks are never kept.
+        assert!(allocator.releases_empty_block(2));
+
+        // A second empty block is released, a used one doesn't count.
+        allocator.blocks.push(block(3, 0, buddy()));
+        assert!(allocator.releases_empty_block(3));
+        allocator.blocks[0].allocator.allocate(1, 1).unwrap();
+        assert!(!allocator.releases_empty_block(3));
+    }
+}
@@ -75,13 +75,8 @@ impl BufferResource {
         }
 
         // Buffer allocations.
-        let buffer_allocations = BufferAllocations::allocate(
-            physical_device,
-            device,
-            &buffers,
-            &buffer_create_infos,
-            property_flags,
-        )?;
+        let buffer_allocations =
+            BufferAllocations::allocate(device, &buffers, &buffer_create_infos, property_flags)?;
 
         // Descriptors.
         let mut descriptors = Vec::with_capacity(buffer_creators.len());
@@ -239,7 +234,6 @@ pub struct BufferDedicatedTransfer {
 
 impl BufferDedicatedTransfer {
     pub unsafe fn create(
-        physical_device: &PhysicalDevice,
         device: &Device,
         buffer_creator: BufferCreator,
         property_flags: impl Into<vk::MemoryPropertyFlags> + Copy,
@@ -266,13 +260,8 @@ impl BufferDedicatedTransfer {
         let (buffer, buffer_create_info) = buffer_creator.create(device)?;
 
         // Allocation.
-        let buffer_allocations = BufferAllocations::allocate(
-            physical_device,
-            device,
-            &[buffer],
-            &[buffer_create_info],
-            property_flags,
-        )?;
+        let buffer_allocations =
+            BufferAllocations::allocate(device, &[buffer], &[buffer_create_info], property_flags)?;
         let buffer_allocation = buffer_allocations.allocations()[0];
 
         Ok(Self {
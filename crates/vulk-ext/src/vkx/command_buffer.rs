@@ -5,6 +5,7 @@ use super::*;
 #[derive(Debug)]
 pub struct CommandBuffer {
     command_buffer: vk::CommandBuffer,
+    command_pool: vk::CommandPool,
 }
 
 impl CommandBuffer {
@@ -13,12 +14,18 @@ impl CommandBuffer {
     //
 
     pub unsafe fn create(device: &Device) -> Result<Self> {
+        Self::create_for_queue(device, device.queue())
+    }
+
+    /// Allocates from the command pool of `queue`, the command buffer can only
+    /// be submitted to queues of the same family.
+    pub unsafe fn create_for_queue(device: &Device, queue: &Queue) -> Result<Self> {
         // Command buffer.
         let command_buffer = {
             let command_buffer_allocate_info = vk::CommandBufferAllocateInfo {
                 s_type: vk::StructureType::CommandBufferAllocateInfo,
                 p_next: null(),
-                command_pool: device.command_pool,
+                command_pool: queue.command_pool(),
                 level: vk::CommandBufferLevel::Primary,
                 command_buffer_count: 1,
             };
@@ -30,11 +37,14 @@ impl CommandBuffer {
             command_buffer.assume_init()
         };
 
-        Ok(Self { command_buffer })
+        Ok(Self {
+            command_buffer,
+            command_pool: queue.command_pool(),
+        })
     }
 
     pub unsafe fn destroy(self, device: &Device) {
-        device.free_command_buffers(device.command_pool, 1, &raw const self.command_buffer);
+        device.free_command_buffers(self.command_pool, 1, &raw const self.command_buffer);
     }
 
     pub unsafe fn begin(&self, device: &Device) -> Result<()> {
@@ -194,6 +204,57 @@ impl CommandBuffer {
         src_access_mask: impl Into<vk::AccessFlags2>,
         dst_stage_mask: impl Into<vk::PipelineStageFlags2>,
         dst_access_mask: impl Into<vk::AccessFlags2>,
+    ) {
+        self.buffer_barrier_with_queues(
+            device,
+            buffer,
+            src_stage_mask,
+            src_access_mask,
+            dst_stage_mask,
+            dst_access_mask,
+            vk::QUEUE_FAMILY_IGNORED,
+            vk::QUEUE_FAMILY_IGNORED,
+        );
+    }
+
+    /// Queue family ownership transfer of `buffer` from `src_queue` to
+    /// `dst_queue`. The same barrier must be recorded twice: as a release on
+    /// `src_queue` with empty destination masks, and as an acquire on
+    /// `dst_queue` with empty source masks. The two submissions must be
+    /// ordered with a semaphore.
+    pub unsafe fn buffer_ownership_barrier(
+        &self,
+        device: &Device,
+        buffer: &impl BufferOps,
+        src_queue: &Queue,
+        dst_queue: &Queue,
+        src_stage_mask: impl Into<vk::PipelineStageFlags2>,
+        src_access_mask: impl Into<vk::AccessFlags2>,
+        dst_stage_mask: impl Into<vk::PipelineStageFlags2>,
+        dst_access_mask: impl Into<vk::AccessFlags2>,
+    ) {
+        self.buffer_barrier_with_queues(
+            device,
+            buffer,
+            src_stage_mask,
+            src_access_mask,
+            dst_stage_mask,
+            dst_access_mask,
+            src_queue.family_index(),
+            dst_queue.family_index(),
+        );
+    }
+
+    unsafe fn buffer_barrier_with_queues(
+        &self,
+        device: &Device,
+        buffer: &impl BufferOps,
+        src_stage_mask: impl Into<vk::PipelineStageFlags2>,
+        src_access_mask: impl Into<vk::AccessFlags2>,
+        dst_stage_mask: impl Into<vk::PipelineStageFlags2>,
+        dst_access_mask: impl Into<vk::AccessFlags2>,
+        src_queue_family_index: u32,
+        dst_queue_family_index: u32,
     ) {
         device.cmd_pipeline_barrier2(
             self.command_buffer,
@@ -211,8 +272,8 @@ impl CommandBuffer {
                     src_access_mask: src_access_mask.into(),
                     dst_stage_mask: dst_stage_mask.into(),
                     dst_access_mask: dst_access_mask.into(),
-                    src_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
-                    dst_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+                    src_queue_family_index,
+                    dst_queue_family_index,
                     buffer: buffer.buffer_handle(),
                     offset: 0,
                     size: vk::WHOLE_SIZE,
@@ -233,6 +294,63 @@ impl CommandBuffer {
         dst_access_mask: impl Into<vk::AccessFlags2>,
         old_layout: vk::ImageLayout,
         new_layout: vk::ImageLayout,
+    ) {
+        self.image_barrier_with_queues(
+            device,
+            image,
+            src_stage_mask,
+            src_access_mask,
+            dst_stage_mask,
+            dst_access_mask,
+            old_layout,
+            new_layout,
+            vk::QUEUE_FAMILY_IGNORED,
+            vk::QUEUE_FAMILY_IGNORED,
+        );
+    }
+
+    /// Queue family ownership transfer of `image`, see
+    /// [`CommandBuffer::buffer_ownership_barrier`]. The layouts must match
+    /// between the release and the acquire.
+    pub unsafe fn image_ownership_barrier(
+        &self,
+        device: &Device,
+        image: &impl ImageOps,
+        src_queue: &Queue,
+        dst_queue: &Queue,
+        src_stage_mask: impl Into<vk::PipelineStageFlags2>,
+        src_access_mask: impl Into<vk::AccessFlags2>,
+        dst_stage_mask: impl Into<vk::PipelineStageFlags2>,
+        dst_access_mask: impl Into<vk::AccessFlags2>,
+        old_layout: vk::ImageLayout,
+        new_layout: vk::ImageLayout,
+    ) {
+        self.image_barrier_with_queues(
+            device,
+            image,
+            src_stage_mask,
+            src_access_mask,
+            dst_stage_mask,
+            dst_access_mask,
+            old_layout,
+            new_layout,
+            src_queue.family_index(),
+            dst_queue.family_index(),
+        );
+    }
+
+    unsafe fn image_barrier_with_queues(
+        &self,
+        device: &Device,
+        image: &impl ImageOps,
+        src_stage_mask: impl Into<vk::PipelineStageFlags2>,
+        src_access_mask: impl Into<vk::AccessFlags2>,
+        dst_stage_mask: impl Into<vk::PipelineStageFlags2>,
+        dst_access_mask: impl Into<vk::AccessFlags2>,
+        old_layout: vk::ImageLayout,
+        new_layout: vk::ImageLayout,
+        src_queue_family_index: u32,
+        dst_queue_family_index: u32,
     ) {
         device.cmd_pipeline_barrier2(
             self.command_buffer,
@@ -254,8 +372,8 @@ impl CommandBuffer {
                     dst_access_mask: dst_access_mask.into(),
                     old_layout,
                     new_layout,
-                    src_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
-                    dst_queue_family_index: vk::QUEUE_FAMILY_IGNORED,
+                    src_queue_family_index,
+                    dst_queue_family_index,
                     image: image.image_handle(),
                     subresource_range: image.subresource_range(),
                 },
@@ -483,14 +601,13 @@ impl CommandBuffer {
                 usage: ds.usage,
             },
         );
-        device.cmd_set_descriptor_buffer_offsets_ext(
+        device.cmd_set_descriptor_buffer_offsets_ext_slice(
             self.command_buffer,
             pipeline_bind_point,
             ds.pipeline_layout,
             0,
-            ds.set_count,
-            ds.buffer_indices.as_ptr(),
-            ds.offsets.as_ptr(),
+            &ds.buffer_indices,
+            &ds.offsets,
         );
     }
 
@@ -521,12 +638,7 @@ impl CommandBuffer {
     //
 
     pub unsafe fn bind_shader(&self, device: &Device, shader: &Shader) {
-        device.cmd_bind_shaders_ext(
-            self.command_buffer,
-            shader.stages.len() as _,
-            shader.stages.as_ptr(),
-            shader.shaders.as_ptr(),
-        );
+        device.cmd_bind_shaders_ext_slice(self.command_buffer, &shader.stages, &shader.shaders);
     }
 
     //
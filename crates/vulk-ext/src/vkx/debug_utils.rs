@@ -87,32 +87,20 @@ unsafe extern "C" fn debug_utils_messenger_callback(
         // called on that image.
         return vk::FALSE;
     }
-    if message_id_name == "UNASSIGNED-BestPractices-vkAllocateMemory-small-allocation" {
-        // vkAllocateMemory(): Allocating a VkDeviceMemory of size 256. This is
-        // a very small allocation (current threshold is 262144 bytes). You
-        // should make large allocations and sub-allocate from one large
-        // VkDeviceMemory.
-        return vk::FALSE;
-    }
-    if message_id_name == "UNASSIGNED-BestPractices-vkBindMemory-small-dedicated-allocation" {
-        // vkBindBufferMemory2() pBindInfos[0]: Trying to bind VkBuffer
-        // 0xcb3ee80000000007[] to a memory block which is fully consumed by the
-        // buffer. The required size of the allocation is 256, but smaller
-        // buffers like this should be sub-allocated from larger memory blocks.
-        // (Current threshold is 1048576 bytes.)
-        return vk::FALSE;
-    }
     if message_id_name == "UNASSIGNED-BestPractices-pipeline-stage-flags" &&
         message.contains("You are using VK_PIPELINE_STAGE_2_ALL_COMMANDS_BIT_KHR when vkCmdWriteTimestamp2 is called") {
         return vk::FALSE;
     }
 
     // Severity.
+    #[allow(clippy::match_same_arms)]
     let level = match message_severity {
         vk::DebugUtilsMessageSeverityFlagBitsEXT::VerboseEXT => log::Level::Debug,
         vk::DebugUtilsMessageSeverityFlagBitsEXT::InfoEXT => log::Level::Info,
         vk::DebugUtilsMessageSeverityFlagBitsEXT::WarningEXT => log::Level::Warn,
         vk::DebugUtilsMessageSeverityFlagBitsEXT::ErrorEXT => log::Level::Error,
+        // Severities added by newer layers.
+        _ => log::Level::Warn,
     };
 
     // Log.
@@ -0,0 +1,203 @@
+use std::collections::hash_map::Entry;
+
+use super::*;
+
+/// Destroys resources once the GPU is done with them.
+///
+/// Each resource is queued with a timeline semaphore and a value that is
+/// signaled after the last submission using it, such as the semaphore of a
+/// [`FrameRing`]. [`SharedDevice`] queues a resource behind every semaphore
+/// it has recorded a submission for. [`DeletionQueue::poll`] reads the counter of every
+/// semaphore in the queue and destroys every resource whose value has been
+/// reached, typically once per frame.
+#[derive(Default)]
+pub struct DeletionQueue {
+    entries: Vec<DeletionEntry>,
+}
+
+struct DeletionEntry {
+    waits: Vec<(vk::Semaphore, u64)>,
+    destroy: Box<dyn FnOnce(&Device)>,
+}
+
+impl DeletionQueue {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues `resource` for destruction once `semaphore` reaches `value`.
+    pub fn push<T>(&mut self, resource: T, semaphore: &TimelineSemaphore, value: u64)
+    where
+        T: DeviceResource + 'static,
+    {
+        self.push_waits(resource, vec![(semaphore.handle(), value)]);
+    }
+
+    /// Queues `resource` for destruction once every semaphore reaches its
+    /// value.
+    pub(crate) fn push_waits<T>(&mut self, resource: T, waits: Vec<(vk::Semaphore, u64)>)
+    where
+        T: DeviceResource + 'static,
+    {
+        self.entries.push(DeletionEntry {
+            waits,
+            destroy: Box::new(move |device| unsafe { resource.destroy_resource(device) }),
+        });
+    }
+
+    /// Destroys every resource whose semaphores have reached their values and
+    /// returns how many were destroyed.
+    pub unsafe fn poll(&mut self, device: &Device) -> Result<usize> {
+        let mut counter_values = HashMap::new();
+        for &(semaphore, _) in self.entries.iter().flat_map(|entry| &entry.waits) {
+            if let Entry::Vacant(counter_value) = counter_values.entry(semaphore) {
+                counter_value.insert(device.get_semaphore_counter_value(semaphore)?);
+            }
+        }
+        let retired = self.retire(&counter_values);
+        let retired_count = retired.len();
+        retired.destroy_now(device);
+        Ok(retired_count)
+    }
+
+    /// Waits until every semaphore reaches its highest pending value, then
+    /// destroys everything.
+    pub unsafe fn destroy(self, device: &Device) -> Result<()> {
+        for (semaphore, value) in self.pending_values() {
+            device.wait_semaphores(
+                &vk::SemaphoreWaitInfo {
+                    s_type: vk::StructureType::SemaphoreWaitInfo,
+                    p_next: null(),
+                    flags: vk::SemaphoreWaitFlags::empty(),
+                    semaphore_count: 1,
+                    p_semaphores: &raw const semaphore,
+                    p_values: &raw const value,
+                },
+                u64::MAX,
+            )?;
+        }
+        self.destroy_now(device);
+        Ok(())
+    }
+
+    /// Destroys everything without waiting, for when the device is known to
+    /// be idle.
+    pub(crate) unsafe fn destroy_now(self, device: &Device) {
+        for entry in self.entries {
+            (entry.destroy)(device);
+        }
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the highest pending value of each semaphore in the queue.
+    pub(crate) fn pending_values(&self) -> HashMap<vk::Semaphore, u64> {
+        let mut pending_values = HashMap::new();
+        for &(semaphore, value) in self.entries.iter().flat_map(|entry| &entry.waits) {
+            let pending_value = pending_values.entry(semaphore).or_insert(value);
+            *pending_value = (*pending_value).max(value);
+        }
+        pending_values
+    }
+
+    /// Removes the entries whose semaphores have all reached their values
+    /// and returns them as a new queue, keeping the queue order of both the
+    /// retired and the pending ones.
+    pub(crate) fn retire(&mut self, counter_values: &HashMap<vk::Semaphore, u64>) -> Self {
+        let (retired, pending) = std::mem::take(&mut self.entries)
+            .into_iter()
+            .partition(|entry| {
+                entry.waits.iter().all(|(semaphore, value)| {
+                    counter_values
+                        .get(semaphore)
+                        .is_some_and(|counter_value| value <= counter_value)
+                })
+            });
+        self.entries = pending;
+        Self { entries: retired }
+    }
+}
+
+impl std::fmt::Debug for DeletionQueue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DeletionQueue")
+            .field("len", &self.entries.len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use vk::Handle;
+
+    struct TestResource;
+
+    impl DeviceResource for TestResource {
+        unsafe fn destroy_resource(self, _device: &Device) {}
+    }
+
+    fn values(queue: &DeletionQueue) -> Vec<u64> {
+        queue.entries.iter().map(|entry| entry.waits[0].1).collect()
+    }
+
+    #[test]
+    fn deletion_queue_retire() {
+        let first = vk::Semaphore::from_raw(1);
+        let second = vk::Semaphore::from_raw(2);
+        let mut queue = DeletionQueue::new();
+        for (semaphore, value) in [(first, 2), (second, 1), (first, 3), (second, 2)] {
+            queue.push_waits(TestResource, vec![(semaphore, value)]);
+        }
+        assert_eq!(
+            queue.pending_values(),
+            HashMap::from([(first, 3), (second, 2)])
+        );
+        assert!(queue.retire(&HashMap::new()).is_empty());
+
+        let retired = queue.retire(&HashMap::from([(first, 2), (second, 0)]));
+        assert_eq!(values(&retired), [2]);
+        assert_eq!(values(&queue), [1, 3, 2]);
+
+        let retired = queue.retire(&HashMap::from([(first, 2), (second, 2)]));
+        assert_eq!(values(&retired), [1, 2]);
+        assert_eq!(values(&queue), [3]);
+        assert_eq!(queue.len(), 1);
+
+        assert_eq!(values(&queue.retire(&HashMap::from([(first, 3)]))), [3]);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn deletion_queue_retire_every_wait() {
+        let first = vk::Semaphore::from_raw(1);
+        let second = vk::Semaphore::from_raw(2);
+        let mut queue = DeletionQueue::new();
+        queue.push_waits(TestResource, vec![(first, 2), (second, 1)]);
+        assert_eq!(
+            queue.pending_values(),
+            HashMap::from([(first, 2), (second, 1)])
+        );
+
+        assert!(queue.retire(&HashMap::from([(first, 2)])).is_empty());
+        assert!(queue
+            .retire(&HashMap::from([(first, 2), (second, 0)]))
+            .is_empty());
+        assert_eq!(
+            queue
+                .retire(&HashMap::from([(first, 2), (second, 1)]))
+                .len(),
+            1
+        );
+        assert!(queue.is_empty());
+    }
+}
@@ -246,7 +246,6 @@ pub struct DescriptorStorage {
     allocations: BufferAllocations,
     pub(super) allocation: BufferAllocation,
     set_layout: vk::DescriptorSetLayout,
-    pub(super) set_count: u32,
     pub(super) buffer_indices: Vec<u32>,
     pub(super) offsets: Vec<vk::DeviceSize>,
     pub(super) push_constant_range: Option<vk::PushConstantRange>,
@@ -256,7 +255,6 @@ pub struct DescriptorStorage {
 
 impl DescriptorStorage {
     pub unsafe fn create(
-        physical_device: &PhysicalDevice,
         device: &Device,
         bindings: &[DescriptorBinding],
         push_constant_range: Option<vk::PushConstantRange>,
@@ -299,7 +297,6 @@ impl DescriptorStorage {
                 binding_count: set_layout_bindings.len() as _,
                 p_bindings: set_layout_bindings.as_ptr(),
             })?;
-        let set_count = 1;
         let buffer_indices = vec![0];
         let offsets = vec![0];
         let size = device.get_descriptor_set_layout_size_ext(set_layout);
@@ -315,7 +312,6 @@ impl DescriptorStorage {
 
         // Allocate.
         let allocations = BufferAllocations::allocate(
-            physical_device,
             device,
             &[buffer],
             &[buffer_create_info],
@@ -366,7 +362,6 @@ impl DescriptorStorage {
             allocations,
             allocation,
             set_layout,
-            set_count,
             buffer_indices,
             offsets,
             push_constant_range,
@@ -401,14 +396,13 @@ impl DescriptorStorage {
         cmd: vk::CommandBuffer,
         pipeline_bind_point: vk::PipelineBindPoint,
     ) {
-        device.cmd_set_descriptor_buffer_offsets_ext(
+        device.cmd_set_descriptor_buffer_offsets_ext_slice(
             cmd,
             pipeline_bind_point,
             self.pipeline_layout,
             0,
-            self.set_count,
-            self.buffer_indices.as_ptr(),
-            self.offsets.as_ptr(),
+            &self.buffer_indices,
+            &self.offsets,
         );
     }
 
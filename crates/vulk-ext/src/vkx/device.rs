@@ -2,10 +2,13 @@ use super::*;
 
 pub struct Device {
     device: vulk::Device,
-    queue: vk::Queue,
-    queue_family_index: u32,
-    queue_family_properties: vk::QueueFamilyProperties,
-    pub(crate) command_pool: vk::CommandPool,
+    queue: Queue,
+    compute_queue: Option<Queue>,
+    transfer_queue: Option<Queue>,
+    features: DeviceFeatures,
+    enabled_extensions: Vec<vk::ExtensionProperties>,
+    host_time_domain: Option<vk::TimeDomainEXT>,
+    allocator: Mutex<MemoryAllocator>,
 }
 
 impl std::fmt::Debug for Device {
@@ -13,13 +16,124 @@ impl std::fmt::Debug for Device {
         f.debug_struct("Device")
             .field("device", &"Device {..}")
             .field("queue", &self.queue)
-            .field("queue_family_index", &self.queue_family_index)
-            .field("queue_family_properties", &self.queue_family_properties)
-            .field("command_pool", &self.command_pool)
+            .field("compute_queue", &self.compute_queue)
+            .field("transfer_queue", &self.transfer_queue)
+            .field("features", &self.features)
+            .field(
+                "enabled_extensions",
+                &self
+                    .enabled_extensions
+                    .iter()
+                    .map(extension_name)
+                    .collect::<Vec<_>>(),
+            )
+            .field("host_time_domain", &self.host_time_domain)
+            .field("allocator", &self.allocator)
             .finish()
     }
 }
 
+/// How [`Device::create`] treats an optional [`DeviceFeatureGroup`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FeatureRequirement {
+    /// Device creation fails if the group is not supported.
+    #[default]
+    Required,
+    /// Enabled if supported, silently skipped otherwise.
+    Preferred,
+    /// Never enabled.
+    Disabled,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct DeviceFeatureRequest {
+    pub ray_tracing: FeatureRequirement,
+    pub mesh_shader: FeatureRequirement,
+    pub descriptor_buffer: FeatureRequirement,
+    pub shader_object: FeatureRequirement,
+    /// Enabled in addition to the extensions vkx needs.
+    pub extra_extensions: Vec<CString>,
+    /// Removed from the extensions vkx would enable. Extensions of enabled
+    /// feature groups can't be dropped, disable the group instead.
+    pub dropped_extensions: Vec<CString>,
+}
+
+impl DeviceFeatureRequest {
+    #[must_use]
+    pub fn requirement(&self, group: DeviceFeatureGroup) -> FeatureRequirement {
+        match group {
+            DeviceFeatureGroup::RayTracing => self.ray_tracing,
+            DeviceFeatureGroup::MeshShader => self.mesh_shader,
+            DeviceFeatureGroup::DescriptorBuffer => self.descriptor_buffer,
+            DeviceFeatureGroup::ShaderObject => self.shader_object,
+        }
+    }
+
+    #[must_use]
+    pub fn is_dropped(&self, extension_name: &CStr) -> bool {
+        self.dropped_extensions
+            .iter()
+            .any(|name| name.as_c_str() == extension_name)
+    }
+
+    pub fn resolve(&self, physical_device: &PhysicalDevice) -> Result<DeviceFeatures> {
+        let mut features = DeviceFeatures::default();
+        for group in DeviceFeatureGroup::ALL {
+            let supported = physical_device.supports_feature_group(group);
+            let enabled = match self.requirement(group) {
+                FeatureRequirement::Required => {
+                    ensure!(
+                        supported,
+                        "Required feature group {group:?} is not supported by {}",
+                        physical_device.device_name()
+                    );
+                    true
+                }
+                FeatureRequirement::Preferred => {
+                    if !supported {
+                        debug!("Preferred feature group {group:?} is not supported, skipping");
+                    }
+                    supported
+                }
+                FeatureRequirement::Disabled => false,
+            };
+            features.set(group, enabled);
+        }
+        Ok(features)
+    }
+}
+
+/// Feature groups enabled on a [`Device`].
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DeviceFeatures {
+    pub ray_tracing: bool,
+    pub mesh_shader: bool,
+    pub descriptor_buffer: bool,
+    pub shader_object: bool,
+}
+
+impl DeviceFeatures {
+    #[must_use]
+    pub fn get(&self, group: DeviceFeatureGroup) -> bool {
+        match group {
+            DeviceFeatureGroup::RayTracing => self.ray_tracing,
+            DeviceFeatureGroup::MeshShader => self.mesh_shader,
+            DeviceFeatureGroup::DescriptorBuffer => self.descriptor_buffer,
+            DeviceFeatureGroup::ShaderObject => self.shader_object,
+        }
+    }
+
+    fn set(&mut self, group: DeviceFeatureGroup, enabled: bool) {
+        match group {
+            DeviceFeatureGroup::RayTracing => self.ray_tracing = enabled,
+            DeviceFeatureGroup::MeshShader => self.mesh_shader = enabled,
+            DeviceFeatureGroup::DescriptorBuffer => self.descriptor_buffer = enabled,
+            DeviceFeatureGroup::ShaderObject => self.shader_object = enabled,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct TimestampCalibration {
     // On Posix, host_domain seems to match machine uptime.
@@ -33,6 +147,7 @@ impl Device {
         instance: &Instance,
         physical_device: &PhysicalDevice,
         surface: Option<&Surface>,
+        feature_request: &DeviceFeatureRequest,
     ) -> Result<Self> {
         // Find compatible queue family.
         let (queue_family_index, queue_family_properties) = physical_device
@@ -68,7 +183,50 @@ impl Device {
             })
             .context("Finding compatible queue families")?;
 
-        // Required features.
+        // Find dedicated queue families. Async compute families lack graphics,
+        // transfer families lack both graphics and compute.
+        let find_dedicated_family = |required: vk::QueueFlagBits, excluded: vk::QueueFlags| {
+            physical_device
+                .queue_family_properties
+                .iter()
+                .copied()
+                .enumerate()
+                .find(|(_, queue_family_properties)| {
+                    let flags = queue_family_properties.queue_flags;
+                    flags.contains(required) && (flags & excluded) == vk::QueueFlags::empty()
+                })
+                .map(|(index, queue_family_properties)| (index as u32, queue_family_properties))
+        };
+        let compute_family = find_dedicated_family(
+            vk::QueueFlagBits::Compute,
+            vk::QueueFlagBits::Graphics.into(),
+        );
+        let transfer_family = find_dedicated_family(
+            vk::QueueFlagBits::Transfer,
+            vk::QueueFlagBits::Graphics | vk::QueueFlagBits::Compute,
+        );
+        let queue_priorities = [1.0];
+        let queue_create_infos = [
+            Some((queue_family_index, queue_family_properties)),
+            compute_family,
+            transfer_family,
+        ]
+        .into_iter()
+        .flatten()
+        .map(|(queue_family_index, _)| vk::DeviceQueueCreateInfo {
+            s_type: vk::StructureType::DeviceQueueCreateInfo,
+            p_next: null(),
+            flags: vk::DeviceQueueCreateFlags::empty(),
+            queue_family_index,
+            queue_count: 1,
+            p_queue_priorities: queue_priorities.as_ptr(),
+        })
+        .collect::<Vec<_>>();
+
+        // Resolve optional features.
+        let features = feature_request.resolve(physical_device)?;
+
+        // Features.
         let mut physical_device_ray_tracing_maintenance1_features_khr =
             vk::PhysicalDeviceRayTracingMaintenance1FeaturesKHR {
                 s_type: vk::StructureType::PhysicalDeviceRayTracingMaintenance1FeaturesKHR,
@@ -79,7 +237,7 @@ impl Device {
         let mut physical_device_acceleration_structure_features_khr =
             vk::PhysicalDeviceAccelerationStructureFeaturesKHR {
                 s_type: vk::StructureType::PhysicalDeviceAccelerationStructureFeaturesKHR,
-                p_next: addr_of_mut!(physical_device_ray_tracing_maintenance1_features_khr).cast(),
+                p_next: null_mut(),
                 acceleration_structure: vk::TRUE,
                 acceleration_structure_capture_replay: vk::FALSE,
                 acceleration_structure_indirect_build: vk::FALSE,
@@ -89,7 +247,7 @@ impl Device {
         let mut physical_device_ray_tracing_pipeline_features_khr =
             vk::PhysicalDeviceRayTracingPipelineFeaturesKHR {
                 s_type: vk::StructureType::PhysicalDeviceRayTracingPipelineFeaturesKHR,
-                p_next: addr_of_mut!(physical_device_acceleration_structure_features_khr).cast(),
+                p_next: null_mut(),
                 ray_tracing_pipeline: vk::TRUE,
                 ray_tracing_pipeline_shader_group_handle_capture_replay: vk::FALSE,
                 ray_tracing_pipeline_shader_group_handle_capture_replay_mixed: vk::FALSE,
@@ -98,13 +256,13 @@ impl Device {
             };
         let mut physical_device_ray_query_features_khr = vk::PhysicalDeviceRayQueryFeaturesKHR {
             s_type: vk::StructureType::PhysicalDeviceRayQueryFeaturesKHR,
-            p_next: addr_of_mut!(physical_device_ray_tracing_pipeline_features_khr).cast(),
+            p_next: null_mut(),
             ray_query: vk::TRUE,
         };
         let mut physical_device_mesh_shader_features_ext =
             vk::PhysicalDeviceMeshShaderFeaturesEXT {
                 s_type: vk::StructureType::PhysicalDeviceMeshShaderFeaturesEXT,
-                p_next: addr_of_mut!(physical_device_ray_query_features_khr).cast(),
+                p_next: null_mut(),
                 task_shader: vk::TRUE,
                 mesh_shader: vk::TRUE,
                 multiview_mesh_shader: vk::FALSE,
@@ -114,21 +272,22 @@ impl Device {
         let mut physical_device_shader_object_features_ext =
             vk::PhysicalDeviceShaderObjectFeaturesEXT {
                 s_type: vk::StructureType::PhysicalDeviceShaderObjectFeaturesEXT,
-                p_next: addr_of_mut!(physical_device_mesh_shader_features_ext).cast(),
+                p_next: null_mut(),
                 shader_object: vk::TRUE,
             };
         let mut physical_device_descriptor_buffer_features_ext =
             vk::PhysicalDeviceDescriptorBufferFeaturesEXT {
                 s_type: vk::StructureType::PhysicalDeviceDescriptorBufferFeaturesEXT,
-                p_next: addr_of_mut!(physical_device_shader_object_features_ext).cast(),
+                p_next: null_mut(),
                 descriptor_buffer: vk::TRUE,
                 descriptor_buffer_capture_replay: vk::FALSE,
                 descriptor_buffer_image_layout_ignored: vk::FALSE,
                 descriptor_buffer_push_descriptors: vk::FALSE,
             };
+
         let mut physical_device_vulkan13_features = vk::PhysicalDeviceVulkan13Features {
             s_type: vk::StructureType::PhysicalDeviceVulkan13Features,
-            p_next: addr_of_mut!(physical_device_descriptor_buffer_features_ext).cast(),
+            p_next: null_mut(),
             robust_image_access: vk::FALSE,
             inline_uniform_block: vk::FALSE,
             descriptor_binding_inline_uniform_block_update_after_bind: vk::FALSE,
@@ -147,7 +306,7 @@ impl Device {
         };
         let mut physical_device_vulkan12_features = vk::PhysicalDeviceVulkan12Features {
             s_type: vk::StructureType::PhysicalDeviceVulkan12Features,
-            p_next: addr_of_mut!(physical_device_vulkan13_features).cast(),
+            p_next: null_mut(),
             sampler_mirror_clamp_to_edge: vk::FALSE,
             draw_indirect_count: vk::FALSE,
             storage_buffer8_bit_access: vk::FALSE,
@@ -198,7 +357,7 @@ impl Device {
         };
         let mut physical_device_vulkan11_features = vk::PhysicalDeviceVulkan11Features {
             s_type: vk::StructureType::PhysicalDeviceVulkan11Features,
-            p_next: addr_of_mut!(physical_device_vulkan12_features).cast(),
+            p_next: null_mut(),
             storage_buffer16_bit_access: vk::TRUE,
             uniform_and_storage_buffer16_bit_access: vk::TRUE,
             storage_push_constant16: vk::TRUE,
@@ -212,9 +371,9 @@ impl Device {
             sampler_ycbcr_conversion: vk::FALSE,
             shader_draw_parameters: vk::FALSE,
         };
-        let physical_device_features2 = vk::PhysicalDeviceFeatures2 {
+        let mut physical_device_features2 = vk::PhysicalDeviceFeatures2 {
             s_type: vk::StructureType::PhysicalDeviceFeatures2,
-            p_next: addr_of_mut!(physical_device_vulkan11_features).cast(),
+            p_next: null_mut(),
             features: vk::PhysicalDeviceFeatures {
                 robust_buffer_access: vk::FALSE,
                 full_draw_index_uint32: vk::FALSE,
@@ -274,15 +433,104 @@ impl Device {
             },
         };
 
+        // Chain core features and only the enabled optional feature groups.
+        physical_device_features2
+            .push_next(&mut physical_device_vulkan11_features)
+            .push_next(&mut physical_device_vulkan12_features)
+            .push_next(&mut physical_device_vulkan13_features);
+        if features.ray_tracing {
+            physical_device_features2
+                .push_next(&mut physical_device_acceleration_structure_features_khr)
+                .push_next(&mut physical_device_ray_tracing_pipeline_features_khr)
+                .push_next(&mut physical_device_ray_query_features_khr)
+                .push_next(&mut physical_device_ray_tracing_maintenance1_features_khr);
+        }
+        if features.mesh_shader {
+            physical_device_features2.push_next(&mut physical_device_mesh_shader_features_ext);
+        }
+        if features.shader_object {
+            physical_device_features2.push_next(&mut physical_device_shader_object_features_ext);
+        }
+        if features.descriptor_buffer {
+            physical_device_features2
+                .push_next(&mut physical_device_descriptor_buffer_features_ext);
+        }
+
         // Extensions.
-        let mut enabled_extension_names = vec![];
-        enabled_extension_names.extend_from_slice(&vulk::REQUIRED_DEVICE_EXTENSIONS);
+        let mut extension_names = vec![];
+        extension_names.extend(
+            vulk::REQUIRED_DEVICE_EXTENSIONS
+                .iter()
+                .copied()
+                .filter(|&name| {
+                    DeviceFeatureGroup::of_extension(CStr::from_ptr(name))
+                        .is_none_or(|group| features.get(group))
+                }),
+        );
         if instance.validation_layers() {
-            enabled_extension_names.extend_from_slice(&vulk::DEBUGGING_DEVICE_EXTENSIONS);
+            extension_names.extend_from_slice(&vulk::DEBUGGING_DEVICE_EXTENSIONS);
         }
         if cfg!(windows) {
-            enabled_extension_names.extend_from_slice(&vulk::WIN32_DEVICE_EXTENSIONS);
+            extension_names.extend_from_slice(&vulk::WIN32_DEVICE_EXTENSIONS);
+        }
+        if physical_device.supports_memory_budget() {
+            extension_names.extend_from_slice(&vulk::MEMORY_BUDGET_DEVICE_EXTENSIONS);
+        }
+        let mut enabled_extensions = extension_names
+            .into_iter()
+            .map(|name| CStr::from_ptr(name))
+            .collect::<Vec<_>>();
+
+        // Caller overrides.
+        for extension_name in &feature_request.extra_extensions {
+            if !enabled_extensions.contains(&extension_name.as_c_str()) {
+                enabled_extensions.push(extension_name);
+            }
+        }
+        for extension_name in &feature_request.dropped_extensions {
+            if let Some(group) = DeviceFeatureGroup::of_extension(extension_name) {
+                ensure!(
+                    !features.get(group),
+                    "Can't drop {}, feature group {group:?} is enabled",
+                    extension_name.to_string_lossy()
+                );
+            }
         }
+        enabled_extensions.retain(|&name| !feature_request.is_dropped(name));
+
+        // Check availability up front instead of failing with
+        // `ErrorExtensionNotPresent`.
+        let missing_extensions = enabled_extensions
+            .iter()
+            .filter(|&&name| !physical_device.supports_extension(name))
+            .map(|&name| match vulk::extension_spec_version(name) {
+                Some(spec_version) => {
+                    format!("{} (spec version {spec_version})", name.to_string_lossy())
+                }
+                None => name.to_string_lossy().into_owned(),
+            })
+            .collect::<Vec<_>>();
+        ensure!(
+            missing_extensions.is_empty(),
+            "{} does not support device extensions: {}",
+            physical_device.device_name(),
+            missing_extensions.join(", ")
+        );
+        let enabled_extensions = enabled_extensions
+            .into_iter()
+            .filter_map(|name| physical_device.extension(name).copied())
+            .collect::<Vec<_>>();
+        for extension in &enabled_extensions {
+            debug!(
+                "Enabling device extension {} (spec version {})",
+                extension_name(extension).to_string_lossy(),
+                extension.spec_version
+            );
+        }
+        let enabled_extension_names = enabled_extensions
+            .iter()
+            .map(|extension| extension.extension_name.as_ptr())
+            .collect::<Vec<_>>();
 
         // Device.
         let device = instance.create_device(
@@ -291,15 +539,8 @@ impl Device {
                 s_type: vk::StructureType::DeviceCreateInfo,
                 p_next: addr_of!(physical_device_features2).cast(),
                 flags: vk::DeviceCreateFlags::empty(),
-                queue_create_info_count: 1,
-                p_queue_create_infos: &vk::DeviceQueueCreateInfo {
-                    s_type: vk::StructureType::DeviceQueueCreateInfo,
-                    p_next: null(),
-                    flags: vk::DeviceQueueCreateFlags::empty(),
-                    queue_family_index,
-                    queue_count: 1,
-                    p_queue_priorities: [1.0].as_ptr(),
-                },
+                queue_create_info_count: queue_create_infos.len() as _,
+                p_queue_create_infos: queue_create_infos.as_ptr(),
                 enabled_layer_count: 0,
                 pp_enabled_layer_names: null(),
                 enabled_extension_count: enabled_extension_names.len() as _,
@@ -309,82 +550,141 @@ impl Device {
         )?;
         let device = vulk::Device::load(instance, device)?;
 
-        // Queue.
-        let queue = device.get_device_queue2(&vk::DeviceQueueInfo2 {
-            s_type: vk::StructureType::DeviceQueueInfo2,
-            p_next: null(),
-            flags: vk::DeviceQueueCreateFlags::empty(),
-            queue_family_index,
-            queue_index: 0,
-        });
-
-        // Command pool.
-        let command_pool = device.create_command_pool(&vk::CommandPoolCreateInfo {
-            s_type: vk::StructureType::CommandPoolCreateInfo,
-            p_next: null(),
-            flags: vk::CommandPoolCreateFlagBits::ResetCommandBuffer.into(),
-            queue_family_index,
-        })?;
+        // Queues.
+        let queue = Queue::create(&device, queue_family_index, queue_family_properties)?;
+        let compute_queue = compute_family
+            .map(|(index, properties)| Queue::create(&device, index, properties))
+            .transpose()?;
+        let transfer_queue = transfer_family
+            .map(|(index, properties)| Queue::create(&device, index, properties))
+            .transpose()?;
 
         // Timestamp calibration support.
-        let time_domains = vulk::read_to_vec(
-            |count, ptr| {
-                instance.get_physical_device_calibrateable_time_domains_ext(
-                    physical_device.handle(),
-                    count,
-                    ptr,
-                )
-            },
-            None,
-        )?;
-        let supports_host_domain = time_domains.iter().any(|td| {
-            matches!(
-                *td,
-                vk::TimeDomainEXT::ClockMonotonicEXT
-                    | vk::TimeDomainEXT::QueryPerformanceCounterEXT
-            )
-        });
-        ensure!(supports_host_domain);
-        let supports_device_domain = time_domains
+        let host_time_domain = if enabled_extensions
             .iter()
-            .any(|td| matches!(*td, vk::TimeDomainEXT::DeviceEXT));
-        ensure!(supports_device_domain);
+            .any(|extension| extension_name(extension) == c"VK_EXT_calibrated_timestamps")
+        {
+            let time_domains = instance.get_physical_device_calibrateable_time_domains_ext_to_vec(
+                physical_device.handle(),
+            )?;
+            let host_time_domain = time_domains.iter().copied().find(|td| {
+                matches!(
+                    *td,
+                    vk::TimeDomainEXT::ClockMonotonicEXT
+                        | vk::TimeDomainEXT::QueryPerformanceCounterEXT
+                )
+            });
+            ensure!(host_time_domain.is_some());
+            let supports_device_domain = time_domains
+                .iter()
+                .any(|td| matches!(*td, vk::TimeDomainEXT::DeviceEXT));
+            ensure!(supports_device_domain);
+            host_time_domain
+        } else {
+            None
+        };
 
         Ok(Self {
             device,
             queue,
-            queue_family_index,
-            queue_family_properties,
-            command_pool,
+            compute_queue,
+            transfer_queue,
+            features,
+            enabled_extensions,
+            host_time_domain,
+            allocator: Mutex::new(MemoryAllocator::new(
+                &physical_device.memory_properties,
+                physical_device.properties.limits.buffer_image_granularity,
+            )),
         })
     }
 
     pub unsafe fn destroy(self) {
-        self.device.destroy_command_pool(self.command_pool);
+        self.allocator.into_inner().unwrap().destroy(&self.device);
+        for queue in [Some(self.queue), self.compute_queue, self.transfer_queue]
+            .into_iter()
+            .flatten()
+        {
+            queue.destroy(&self.device);
+        }
         self.device.destroy_device();
     }
 
+    /// The general purpose queue, supports graphics, compute, transfer and
+    /// presentation if a surface was given.
+    #[must_use]
+    pub fn queue(&self) -> &Queue {
+        &self.queue
+    }
+
+    /// Dedicated async compute queue, or the general purpose queue if the
+    /// device has no compute-only family.
+    #[must_use]
+    pub fn compute_queue(&self) -> &Queue {
+        self.compute_queue.as_ref().unwrap_or(&self.queue)
+    }
+
+    /// Dedicated transfer queue, or the general purpose queue if the device
+    /// has no transfer-only family.
+    #[must_use]
+    pub fn transfer_queue(&self) -> &Queue {
+        self.transfer_queue.as_ref().unwrap_or(&self.queue)
+    }
+
     #[must_use]
     pub fn queue_handle(&self) -> vk::Queue {
-        self.queue
+        self.queue.handle()
     }
 
     #[must_use]
     pub fn queue_family_index(&self) -> u32 {
-        self.queue_family_index
+        self.queue.family_index()
     }
 
     #[must_use]
     pub fn queue_family_properties(&self) -> vk::QueueFamilyProperties {
-        self.queue_family_properties
+        self.queue.family_properties()
+    }
+
+    #[must_use]
+    pub fn features(&self) -> DeviceFeatures {
+        self.features
+    }
+
+    /// Extensions the device was created with, including their spec versions.
+    #[must_use]
+    pub fn enabled_extensions(&self) -> &[vk::ExtensionProperties] {
+        &self.enabled_extensions
+    }
+
+    #[must_use]
+    pub fn is_extension_enabled(&self, name: &CStr) -> bool {
+        self.enabled_extensions
+            .iter()
+            .any(|extension| extension_name(extension) == name)
+    }
+
+    /// Whether `VK_EXT_calibrated_timestamps` is enabled, which
+    /// [`Device::timestamp_calibration`] and [`TimestampQuery`] rely on.
+    #[must_use]
+    pub fn supports_timestamp_calibration(&self) -> bool {
+        self.host_time_domain.is_some()
+    }
+
+    /// Memory allocator shared by all vkx resources created on this device.
+    pub fn allocator(&self) -> MutexGuard<'_, MemoryAllocator> {
+        self.allocator.lock().unwrap()
     }
 
     pub unsafe fn timestamp_calibration(&self) -> Result<TimestampCalibration> {
+        let Some(host_time_domain) = self.host_time_domain else {
+            bail!("Timestamp calibration requires VK_EXT_calibrated_timestamps");
+        };
         let calibrated_timestamp_info_ext = [
             vk::CalibratedTimestampInfoEXT {
                 s_type: vk::StructureType::CalibratedTimestampInfoEXT,
                 p_next: null(),
-                time_domain: vk::TimeDomainEXT::ClockMonotonicEXT,
+                time_domain: host_time_domain,
             },
             vk::CalibratedTimestampInfoEXT {
                 s_type: vk::StructureType::CalibratedTimestampInfoEXT,
@@ -394,10 +694,9 @@ impl Device {
         ];
         let mut timestamps = [0_u64; 2];
         let mut max_deviation = 0;
-        self.device.get_calibrated_timestamps_ext(
-            calibrated_timestamp_info_ext.len() as _,
-            calibrated_timestamp_info_ext.as_ptr(),
-            timestamps.as_mut_ptr(),
+        self.device.get_calibrated_timestamps_ext_slice(
+            &calibrated_timestamp_info_ext,
+            &mut timestamps,
             &raw mut max_deviation,
         )?;
         let host_domain = timestamps[0];
@@ -410,6 +709,27 @@ impl Device {
     }
 }
 
+#[cfg(test)]
+impl Device {
+    /// Device without any loaded commands, for tests that never call into
+    /// Vulkan. Every loader entry is an `Option`, so zeroes are valid.
+    pub(crate) unsafe fn null() -> Self {
+        Self {
+            device: zeroed(),
+            queue: zeroed(),
+            compute_queue: None,
+            transfer_queue: None,
+            features: DeviceFeatures::default(),
+            enabled_extensions: vec![],
+            host_time_domain: None,
+            allocator: Mutex::new(MemoryAllocator::new(
+                &vk::PhysicalDeviceMemoryProperties::default(),
+                1,
+            )),
+        }
+    }
+}
+
 impl std::ops::Deref for Device {
     type Target = vulk::Device;
 
@@ -417,3 +737,7 @@ impl std::ops::Deref for Device {
         &self.device
     }
 }
+
+fn extension_name(extension: &vk::ExtensionProperties) -> &CStr {
+    unsafe { CStr::from_ptr(extension.extension_name.as_ptr()) }
+}
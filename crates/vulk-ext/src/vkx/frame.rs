@@ -0,0 +1,413 @@
+use super::*;
+
+/// Sub-allocations from a [`TransientAllocator`] are aligned to at least this
+/// many bytes, which satisfies every uniform and storage buffer offset
+/// alignment allowed by the spec.
+pub const TRANSIENT_MIN_ALIGNMENT: vk::DeviceSize = 256;
+
+//
+// Frame ring
+//
+
+/// Owns `N` frame slots and a timeline semaphore that tracks their retirement.
+///
+/// [`FrameRing::begin_frame`] hands out the slots in order, waiting only when
+/// the slot's previous submission is still in flight. This lets the CPU record
+/// up to `N` frames ahead of the GPU. Timeline values only advance in
+/// [`FrameRing::submit`], so a frame that is begun but never submitted is
+/// recorded again into the same slot by the next [`FrameRing::begin_frame`].
+#[derive(Debug)]
+pub struct FrameRing<const N: usize> {
+    frames: [Frame; N],
+    queue: Queue,
+    semaphore: TimelineSemaphore,
+    timeline: FrameTimeline<N>,
+}
+
+impl<const N: usize> FrameRing<N> {
+    /// Creates `N` frames submitting to `queue`. Each frame gets its own
+    /// host-visible transient buffer described by `transient_buffer`.
+    pub unsafe fn create(
+        device: &Device,
+        queue: &Queue,
+        transient_buffer: BufferCreator,
+    ) -> Result<Self> {
+        ensure!(N > 0);
+
+        let mut frames = Vec::with_capacity(N);
+        for _ in 0..N {
+            frames.push(Frame {
+                command_buffer: CommandBuffer::create_for_queue(device, queue)?,
+                transient: TransientAllocator::create(device, transient_buffer)?,
+                value: 0,
+            });
+        }
+        let Ok(frames) = frames.try_into() else {
+            unreachable!();
+        };
+        let semaphore = TimelineSemaphore::create(device, 0)?;
+
+        Ok(Self {
+            frames,
+            queue: *queue,
+            semaphore,
+            timeline: FrameTimeline::default(),
+        })
+    }
+
+    /// Waits for every frame to retire, then destroys everything.
+    pub unsafe fn destroy(self, device: &Device) -> Result<()> {
+        self.wait_idle(device)?;
+        for frame in self.frames {
+            frame.command_buffer.destroy(device);
+            frame.transient.destroy(device);
+        }
+        self.semaphore.destroy(device);
+        Ok(())
+    }
+
+    /// Waits until the next slot has retired, resets its transient allocator
+    /// and begins its command buffer.
+    pub unsafe fn begin_frame(&mut self, device: &Device) -> Result<&mut Frame> {
+        // Wait for the previous submission from this slot.
+        if let Some(value) = self.timeline.slot_wait_value() {
+            self.semaphore.wait(device, value, u64::MAX)?;
+        }
+
+        // Reset.
+        let (frame_index, value) = self.timeline.begin();
+        let frame = &mut self.frames[frame_index];
+        frame.value = value;
+        frame.transient.reset();
+        frame.command_buffer.begin(device)?;
+
+        Ok(frame)
+    }
+
+    /// Ends the current frame's command buffer and submits it. The frame
+    /// retires once the ring semaphore reaches [`Frame::value`].
+    pub unsafe fn submit(
+        &mut self,
+        device: &Device,
+        wait_semaphores: &[vk::SemaphoreSubmitInfo],
+        signal_semaphores: &[vk::SemaphoreSubmitInfo],
+    ) -> Result<()> {
+        ensure!(self.timeline.pending, "No frame has begun");
+        let frame = &self.frames[self.timeline.slot()];
+        frame.command_buffer.end(device)?;
+
+        let mut signal_semaphores = signal_semaphores.to_vec();
+        signal_semaphores.push(
+            self.semaphore
+                .submit_info(frame.value, vk::PipelineStageFlagBits2::AllCommands),
+        );
+        queue_submit_to(
+            device,
+            &self.queue,
+            &frame.command_buffer,
+            wait_semaphores,
+            &signal_semaphores,
+        )?;
+        self.timeline.submit();
+        Ok(())
+    }
+
+    /// Waits until every submitted frame has retired.
+    pub unsafe fn wait_idle(&self, device: &Device) -> Result<()> {
+        if self.timeline.submitted > 0 {
+            self.semaphore
+                .wait(device, self.timeline.submitted, u64::MAX)?;
+        }
+        Ok(())
+    }
+
+    /// The frame being recorded, or the most recently submitted one.
+    #[must_use]
+    pub fn current_frame(&self) -> &Frame {
+        &self.frames[self.timeline.current_slot()]
+    }
+
+    /// Number of frames submitted so far.
+    #[must_use]
+    pub fn frame_count(&self) -> u64 {
+        self.timeline.submitted
+    }
+
+    #[must_use]
+    pub fn semaphore(&self) -> &TimelineSemaphore {
+        &self.semaphore
+    }
+
+    #[must_use]
+    pub fn queue(&self) -> &Queue {
+        &self.queue
+    }
+}
+
+/// Slot and timeline value bookkeeping of a [`FrameRing`]. The submission with
+/// value `v` uses slot `(v - 1) % N`.
+#[derive(Debug, Default)]
+struct FrameTimeline<const N: usize> {
+    submitted: u64,
+    pending: bool,
+}
+
+impl<const N: usize> FrameTimeline<N> {
+    /// Marks a frame as begun and returns its slot and the value its
+    /// submission will signal.
+    fn begin(&mut self) -> (usize, u64) {
+        self.pending = true;
+        (self.slot(), self.submitted + 1)
+    }
+
+    /// Marks the begun frame as submitted and returns the value it signals.
+    fn submit(&mut self) -> u64 {
+        assert!(self.pending);
+        self.pending = false;
+        self.submitted += 1;
+        self.submitted
+    }
+
+    /// Value signaled by the previous submission from the next slot, if any.
+    fn slot_wait_value(&self) -> Option<u64> {
+        (self.submitted + 1)
+            .checked_sub(N as u64)
+            .filter(|&value| value > 0)
+    }
+
+    /// Slot of the frame being begun or recorded.
+    fn slot(&self) -> usize {
+        (self.submitted % N as u64) as usize
+    }
+
+    fn current_slot(&self) -> usize {
+        if self.pending {
+            self.slot()
+        } else {
+            (self.slot() + N - 1) % N
+        }
+    }
+}
+
+/// A slot handed out by [`FrameRing::begin_frame`].
+#[derive(Debug)]
+pub struct Frame {
+    command_buffer: CommandBuffer,
+    transient: TransientAllocator,
+    value: u64,
+}
+
+impl Frame {
+    #[must_use]
+    pub fn command_buffer(&self) -> &CommandBuffer {
+        &self.command_buffer
+    }
+
+    #[must_use]
+    pub fn transient(&self) -> &TransientAllocator {
+        &self.transient
+    }
+
+    #[must_use]
+    pub fn transient_mut(&mut self) -> &mut TransientAllocator {
+        &mut self.transient
+    }
+
+    /// Ring semaphore value signaled when this frame's submission completes.
+    /// Useful with [`DeletionQueue::push`].
+    #[must_use]
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+}
+
+//
+// Transient allocator
+//
+
+/// Linear allocator over a single host-visible buffer. Everything allocated
+/// is released at once by [`TransientAllocator::reset`].
+#[derive(Debug)]
+pub struct TransientAllocator {
+    buffer: vk::Buffer,
+    buffer_create_info: vk::BufferCreateInfo,
+    buffer_allocations: BufferAllocations,
+    buffer_allocation: BufferAllocation,
+    offset: vk::DeviceSize,
+}
+
+/// A range of a [`TransientAllocator`], valid until the allocator is reset.
+#[derive(Clone, Copy, Debug)]
+pub struct TransientAllocation {
+    buffer: vk::Buffer,
+    offset: vk::DeviceSize,
+    size: vk::DeviceSize,
+    device_address: vk::DeviceAddress,
+    ptr: *mut c_void,
+}
+
+impl TransientAllocator {
+    pub unsafe fn create(device: &Device, buffer_creator: BufferCreator) -> Result<Self> {
+        // Buffer.
+        let (buffer, buffer_create_info) = buffer_creator.create(device)?;
+
+        // Allocation.
+        let buffer_allocations = BufferAllocations::allocate(
+            device,
+            &[buffer],
+            &[buffer_create_info],
+            vk::MemoryPropertyFlagBits::HostVisible | vk::MemoryPropertyFlagBits::HostCoherent,
+        )?;
+        let buffer_allocation = buffer_allocations.allocations()[0];
+        ensure!(!buffer_allocation.as_mut_ptr::<c_void>().is_null());
+
+        Ok(Self {
+            buffer,
+            buffer_create_info,
+            buffer_allocations,
+            buffer_allocation,
+            offset: 0,
+        })
+    }
+
+    pub unsafe fn destroy(self, device: &Device) {
+        device.destroy_buffer(self.buffer);
+        self.buffer_allocations.free(device);
+    }
+
+    /// Allocates `size` bytes aligned to `alignment`, which is raised to
+    /// [`TRANSIENT_MIN_ALIGNMENT`] if smaller.
+    pub fn allocate(
+        &mut self,
+        size: vk::DeviceSize,
+        alignment: vk::DeviceSize,
+    ) -> Result<TransientAllocation> {
+        ensure!(alignment.is_power_of_two(), "got alignment={alignment}");
+        let alignment = alignment.max(TRANSIENT_MIN_ALIGNMENT);
+        let offset = self.offset.next_multiple_of(alignment);
+        let capacity = self.buffer_create_info.size;
+        ensure!(
+            offset + size <= capacity,
+            "Transient allocator out of memory: offset={offset} size={size} capacity={capacity}"
+        );
+        self.offset = offset + size;
+
+        Ok(TransientAllocation {
+            buffer: self.buffer,
+            offset,
+            size,
+            device_address: self.buffer_allocation.device_address() + offset,
+            ptr: unsafe {
+                self.buffer_allocation
+                    .as_mut_ptr::<u8>()
+                    .add(offset as usize)
+                    .cast()
+            },
+        })
+    }
+
+    /// Allocates space for `data` and copies it in.
+    pub fn push<T: Copy>(&mut self, data: &[T]) -> Result<TransientAllocation> {
+        let allocation = self.allocate(size_of_val(data) as _, std::mem::align_of::<T>() as _)?;
+        unsafe {
+            std::ptr::copy_nonoverlapping(data.as_ptr(), allocation.as_mut_ptr(), data.len());
+        }
+        Ok(allocation)
+    }
+
+    /// Releases every allocation.
+    pub fn reset(&mut self) {
+        self.offset = 0;
+    }
+
+    /// Bytes allocated since the last reset.
+    #[must_use]
+    pub fn used(&self) -> vk::DeviceSize {
+        self.offset
+    }
+}
+
+impl BufferOps for TransientAllocator {
+    fn buffer_handle(&self) -> vk::Buffer {
+        self.buffer
+    }
+
+    fn create_info(&self) -> &vk::BufferCreateInfo {
+        &self.buffer_create_info
+    }
+
+    fn memory(&self) -> &BufferAllocation {
+        &self.buffer_allocation
+    }
+
+    fn memory_mut(&mut self) -> &mut BufferAllocation {
+        &mut self.buffer_allocation
+    }
+}
+
+impl TransientAllocation {
+    #[must_use]
+    pub fn buffer_handle(&self) -> vk::Buffer {
+        self.buffer
+    }
+
+    #[must_use]
+    pub fn offset(&self) -> vk::DeviceSize {
+        self.offset
+    }
+
+    #[must_use]
+    pub fn size(&self) -> vk::DeviceSize {
+        self.size
+    }
+
+    #[must_use]
+    pub fn device_address(&self) -> vk::DeviceAddress {
+        self.device_address
+    }
+
+    #[must_use]
+    pub fn as_mut_ptr<T>(&self) -> *mut T {
+        self.ptr.cast()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_timeline_submit() {
+        let mut timeline = FrameTimeline::<2>::default();
+        assert_eq!(timeline.slot_wait_value(), None);
+        assert_eq!(timeline.begin(), (0, 1));
+        assert_eq!(timeline.submit(), 1);
+        assert_eq!(timeline.slot_wait_value(), None);
+        assert_eq!(timeline.begin(), (1, 2));
+        assert_eq!(timeline.submit(), 2);
+        assert_eq!(timeline.slot_wait_value(), Some(1));
+        assert_eq!(timeline.begin(), (0, 3));
+        assert_eq!(timeline.submit(), 3);
+        assert_eq!(timeline.slot_wait_value(), Some(2));
+        assert_eq!(timeline.current_slot(), 0);
+    }
+
+    #[test]
+    fn frame_timeline_begin_without_submit() {
+        let mut timeline = FrameTimeline::<2>::default();
+        assert_eq!(timeline.begin(), (0, 1));
+        assert_eq!(timeline.submitted, 0);
+        assert_eq!(timeline.slot_wait_value(), None);
+
+        // Beginning again records into the same slot and signals the same
+        // value, nothing is waited on.
+        assert_eq!(timeline.begin(), (0, 1));
+        assert_eq!(timeline.slot_wait_value(), None);
+        assert_eq!(timeline.submit(), 1);
+
+        assert_eq!(timeline.begin(), (1, 2));
+        assert_eq!(timeline.slot_wait_value(), None);
+        assert_eq!(timeline.submitted, 1);
+        assert_eq!(timeline.current_slot(), 1);
+    }
+}
@@ -196,13 +196,8 @@ impl ImageResource {
         }
 
         // Image allocations.
-        let image_allocations = ImageAllocations::allocate(
-            physical_device,
-            device,
-            &images,
-            &image_create_infos,
-            property_flags,
-        )?;
+        let image_allocations =
+            ImageAllocations::allocate(device, &images, &image_create_infos, property_flags)?;
 
         // Image views.
         let mut image_views = Vec::with_capacity(image_creators.len());
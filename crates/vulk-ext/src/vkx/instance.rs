@@ -1,10 +1,13 @@
 use super::*;
 
+const VALIDATION_LAYER_NAME: &CStr = c"VK_LAYER_KHRONOS_validation";
+
 #[derive(Debug)]
 pub struct InstanceCreateInfo<'a> {
     pub application_name: &'a str,
     pub engine_name: &'a str,
     pub validation_layers: bool,
+    pub headless_surface: bool,
 }
 
 impl Default for InstanceCreateInfo<'_> {
@@ -13,6 +16,7 @@ impl Default for InstanceCreateInfo<'_> {
             application_name: "vulk",
             engine_name: "vulk",
             validation_layers: true,
+            headless_surface: false,
         }
     }
 }
@@ -22,6 +26,9 @@ pub struct Instance {
     instance: vulk::Instance,
     debug_utils: Option<DebugUtils>,
     validation_layers: bool,
+    headless_surface: bool,
+    enabled_layers: Vec<String>,
+    enabled_extensions: Vec<String>,
 }
 
 impl std::fmt::Debug for Instance {
@@ -31,6 +38,9 @@ impl std::fmt::Debug for Instance {
             .field("instance", &"Instance {..}")
             .field("debug_utils", &self.debug_utils)
             .field("validation_layers", &self.validation_layers)
+            .field("headless_surface", &self.headless_surface)
+            .field("enabled_layers", &self.enabled_layers)
+            .field("enabled_extensions", &self.enabled_extensions)
             .finish()
     }
 }
@@ -40,6 +50,51 @@ impl Instance {
         // Vulk.
         let init = vulk::Init::load().context("Initializing Vulk")?;
 
+        // Instance version, `vkEnumerateInstanceVersion` is missing from 1.0
+        // loaders.
+        let instance_version = if init.is_loaded("vkEnumerateInstanceVersion") {
+            init.enumerate_instance_version()?
+        } else {
+            vk::make_api_version(0, 1, 0, 0)
+        };
+        ensure!(
+            instance_version >= vulk::REQUIRED_VULKAN_VERSION,
+            "Vulkan {} is required, but the loader only supports Vulkan {}",
+            version_string(vulk::REQUIRED_VULKAN_VERSION),
+            version_string(instance_version)
+        );
+
+        // Available layers and extensions.
+        let available_layers = init.enumerate_instance_layer_properties_to_vec()?;
+        let mut available_extensions =
+            init.enumerate_instance_extension_properties_to_vec(null())?;
+
+        // Validation, falls back to running without it.
+        let mut validation_layers = create_info.validation_layers;
+        if validation_layers {
+            if has_layer(&available_layers, VALIDATION_LAYER_NAME) {
+                // `VK_EXT_validation_features` is provided by the layer itself.
+                available_extensions.extend(init.enumerate_instance_extension_properties_to_vec(
+                    VALIDATION_LAYER_NAME.as_ptr(),
+                )?);
+                let missing_extensions =
+                    missing_extensions(&available_extensions, &vulk::DEBUGGING_INSTANCE_EXTENSIONS);
+                if !missing_extensions.is_empty() {
+                    warn!(
+                        "Validation requested, but {} is not available, continuing without validation",
+                        missing_extensions.join(", ")
+                    );
+                    validation_layers = false;
+                }
+            } else {
+                warn!(
+                    "Validation requested, but {} is not available, continuing without validation",
+                    VALIDATION_LAYER_NAME.to_string_lossy()
+                );
+                validation_layers = false;
+            }
+        }
+
         // Instance-specific debug messenger.
         let debug_utils_messenger_create_info_ext =
             debug_utils::debug_utils_messenger_create_info_ext();
@@ -60,26 +115,72 @@ impl Instance {
 
         // Extensions.
         let mut enabled_extension_names = vec![];
+        let missing_required_extensions =
+            missing_extensions(&available_extensions, &vulk::REQUIRED_INSTANCE_EXTENSIONS);
+        ensure!(
+            missing_required_extensions.is_empty(),
+            "Missing required instance extensions: {}",
+            missing_required_extensions.join(", ")
+        );
         enabled_extension_names.extend_from_slice(&vulk::REQUIRED_INSTANCE_EXTENSIONS);
-        if create_info.validation_layers {
+        if validation_layers {
             enabled_extension_names.extend_from_slice(&vulk::DEBUGGING_INSTANCE_EXTENSIONS);
         }
-        if cfg!(windows) {
-            enabled_extension_names.extend_from_slice(&vulk::WIN32_INSTANCE_EXTENSIONS);
+
+        // Window system extensions, only the available ones are enabled.
+        let mut window_system_extension_names = vec![];
+        if cfg!(target_family = "windows") {
+            window_system_extension_names.extend_from_slice(&vulk::WIN32_INSTANCE_EXTENSIONS);
+        }
+        if cfg!(target_family = "unix") {
+            window_system_extension_names.extend_from_slice(&vulk::WAYLAND_INSTANCE_EXTENSIONS);
+            window_system_extension_names.extend_from_slice(&vulk::XLIB_INSTANCE_EXTENSIONS);
+            window_system_extension_names.extend_from_slice(&vulk::XCB_INSTANCE_EXTENSIONS);
+        }
+        for extension_name in window_system_extension_names {
+            let name = CStr::from_ptr(extension_name);
+            if has_extension(&available_extensions, name) {
+                enabled_extension_names.push(extension_name);
+            } else {
+                debug!(
+                    "Instance extension {} is not available, skipping",
+                    name.to_string_lossy()
+                );
+            }
+        }
+
+        // Headless surface.
+        if create_info.headless_surface {
+            let missing_headless_extensions =
+                missing_extensions(&available_extensions, &vulk::HEADLESS_INSTANCE_EXTENSIONS);
+            ensure!(
+                missing_headless_extensions.is_empty(),
+                "Headless surfaces require missing instance extensions: {}",
+                missing_headless_extensions.join(", ")
+            );
+            enabled_extension_names.extend_from_slice(&vulk::HEADLESS_INSTANCE_EXTENSIONS);
         }
 
         // Layers.
         let mut enabled_layer_names = vec![];
-        if create_info.validation_layers {
-            enabled_layer_names.push(c"VK_LAYER_KHRONOS_validation".as_ptr().cast());
+        if validation_layers {
+            enabled_layer_names.push(VALIDATION_LAYER_NAME.as_ptr());
         }
+        let enabled_layers = to_strings(&enabled_layer_names);
+        let enabled_extensions = to_strings(&enabled_extension_names);
+        debug!("Enabled instance layers: {enabled_layers:?}");
+        debug!("Enabled instance extensions: {enabled_extensions:?}");
 
         // Instance.
         let application_name = std::ffi::CString::new(create_info.application_name)?;
         let engine_name = std::ffi::CString::new(create_info.engine_name)?;
         let instance = init.create_instance(&vk::InstanceCreateInfo {
             s_type: vk::StructureType::InstanceCreateInfo,
-            p_next: addr_of!(validation_features_ext).cast(),
+            p_next: if validation_layers {
+                addr_of!(validation_features_ext).cast()
+            } else {
+                null()
+            },
             flags: vk::InstanceCreateFlags::empty(),
             p_application_info: &vk::ApplicationInfo {
                 s_type: vk::StructureType::ApplicationInfo,
@@ -98,7 +199,7 @@ impl Instance {
         let instance = vulk::Instance::load(&init, instance)?;
 
         // Debug utils.
-        let debug_utils = if create_info.validation_layers {
+        let debug_utils = if validation_layers {
             Some(debug_utils::DebugUtils::create(&instance)?)
         } else {
             None
@@ -108,7 +209,10 @@ impl Instance {
             _init: init,
             instance,
             debug_utils,
-            validation_layers: create_info.validation_layers,
+            validation_layers,
+            headless_surface: create_info.headless_surface,
+            enabled_layers,
+            enabled_extensions,
         })
     }
 
@@ -119,10 +223,27 @@ impl Instance {
         self.instance.destroy_instance();
     }
 
+    /// Whether validation is actually enabled, which is `false` when it was
+    /// requested but the layer or its extensions were not available.
     #[must_use]
     pub fn validation_layers(&self) -> bool {
         self.validation_layers
     }
+
+    #[must_use]
+    pub fn headless_surface(&self) -> bool {
+        self.headless_surface
+    }
+
+    #[must_use]
+    pub fn enabled_layers(&self) -> &[String] {
+        &self.enabled_layers
+    }
+
+    #[must_use]
+    pub fn enabled_extensions(&self) -> &[String] {
+        &self.enabled_extensions
+    }
 }
 
 impl std::ops::Deref for Instance {
@@ -132,3 +253,41 @@ impl std::ops::Deref for Instance {
         &self.instance
     }
 }
+
+fn version_string(version: u32) -> String {
+    let major = (version >> 22) & 0x7f;
+    let minor = (version >> 12) & 0x3ff;
+    let patch = version & 0xfff;
+    format!("{major}.{minor}.{patch}")
+}
+
+fn has_layer(layer_properties: &[vk::LayerProperties], layer_name: &CStr) -> bool {
+    layer_properties
+        .iter()
+        .any(|props| unsafe { CStr::from_ptr(props.layer_name.as_ptr()) } == layer_name)
+}
+
+fn has_extension(extension_properties: &[vk::ExtensionProperties], extension_name: &CStr) -> bool {
+    extension_properties
+        .iter()
+        .any(|props| unsafe { CStr::from_ptr(props.extension_name.as_ptr()) } == extension_name)
+}
+
+unsafe fn missing_extensions(
+    extension_properties: &[vk::ExtensionProperties],
+    extension_names: &[*const std::ffi::c_char],
+) -> Vec<String> {
+    extension_names
+        .iter()
+        .map(|&extension_name| CStr::from_ptr(extension_name))
+        .filter(|&extension_name| !has_extension(extension_properties, extension_name))
+        .map(|extension_name| extension_name.to_string_lossy().into_owned())
+        .collect()
+}
+
+unsafe fn to_strings(names: &[*const std::ffi::c_char]) -> Vec<String> {
+    names
+        .iter()
+        .map(|&name| CStr::from_ptr(name).to_string_lossy().into_owned())
+        .collect()
+}
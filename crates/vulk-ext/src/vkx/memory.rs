@@ -9,6 +9,7 @@ pub struct BufferAllocation {
     address: vk::DeviceAddress,
     size: vk::DeviceSize,
     ptr: Option<*mut c_void>,
+    memory: MemoryAllocation,
 }
 
 impl BufferAllocation {
@@ -22,6 +23,11 @@ impl BufferAllocation {
         self.size
     }
 
+    #[must_use]
+    pub fn memory_allocation(&self) -> &MemoryAllocation {
+        &self.memory
+    }
+
     #[must_use]
     pub fn as_ptr<T>(&self) -> *const T {
         if let Some(ptr) = self.ptr {
@@ -61,13 +67,11 @@ impl BufferAllocation {
 
 #[derive(Debug)]
 pub struct BufferAllocations {
-    memory: vk::DeviceMemory,
     allocations: Vec<BufferAllocation>,
 }
 
 impl BufferAllocations {
     pub unsafe fn allocate(
-        physical_device: &PhysicalDevice,
         device: &Device,
         buffers: &[vk::Buffer],
         buffer_create_infos: &[vk::BufferCreateInfo],
@@ -116,76 +120,26 @@ impl BufferAllocations {
             memory_requirements.push(memory_requirements2.memory_requirements);
         }
 
-        // Buffers must be compatible with the allocation we make.
-        ensure!(memory_requirements
-            .iter()
-            .all(|req| req.alignment == memory_requirements[0].alignment));
-        ensure!(memory_requirements
-            .iter()
-            .all(|req| req.memory_type_bits == memory_requirements[0].memory_type_bits));
-        let alignment = memory_requirements[0].alignment;
-        let memory_type_bits = memory_requirements[0].memory_type_bits;
-
-        // Memory type index.
-        let memory_type_index = memory_type_index(
-            &physical_device.memory_properties,
-            property_flags,
-            memory_type_bits,
-        );
-
-        // Allocation size.
-        let allocation_size = memory_requirements
-            .iter()
-            .map(|req| aligned_size(req.size, alignment))
-            .sum::<vk::DeviceSize>();
-
-        // Allocation.
-        let device_memory = {
-            let memory_allocate_flags_info = vk::MemoryAllocateFlagsInfo {
-                s_type: vk::StructureType::MemoryAllocateFlagsInfo,
-                p_next: null(),
-                flags: vk::MemoryAllocateFlagBits::DeviceAddress.into(),
-                device_mask: 0,
-            };
-            device
-                .allocate_memory(&vk::MemoryAllocateInfo {
-                    s_type: vk::StructureType::MemoryAllocateInfo,
-                    p_next: addr_of!(memory_allocate_flags_info).cast(),
-                    allocation_size,
-                    memory_type_index,
-                })
-                .with_context(|| {
-                    format!("Allocating device memory for {} buffers", buffers.len())
-                })?
-        };
-
-        // Map memory.
-        let mut memory_ptr = None;
-        if property_flags
-            .into()
-            .contains(vk::MemoryPropertyFlagBits::HostVisible)
-        {
-            let ptr = device
-                .map_memory2_khr(&vk::MemoryMapInfoKHR {
-                    s_type: vk::StructureType::MemoryMapInfoKHR,
-                    p_next: null(),
-                    flags: vk::MemoryMapFlags::empty(),
-                    memory: device_memory,
-                    offset: 0,
-                    size: allocation_size,
-                })
-                .with_context(|| format!("Mapping buffer size={allocation_size}"))?;
-            memory_ptr = Some(ptr);
-        }
-
         // Sub-allocations.
+        let mut allocator = device.allocator();
         let mut allocations = vec![];
-        let mut memory_offset = 0;
         for (buffer_index, (buffer, requirements)) in
             buffers.iter().zip(memory_requirements).enumerate()
         {
-            // Aligned size.
-            let aligned_size = aligned_size(requirements.size, alignment);
+            // Allocate.
+            let memory = allocator
+                .allocate(
+                    device,
+                    &requirements,
+                    property_flags,
+                    AllocationTiling::Linear,
+                )
+                .with_context(|| {
+                    format!(
+                        "Allocating device memory for buffer {buffer_index} size={}",
+                        requirements.size
+                    )
+                })?;
 
             // Bind buffer memory.
             device
@@ -195,15 +149,17 @@ impl BufferAllocations {
                         s_type: vk::StructureType::BindBufferMemoryInfo,
                         p_next: null(),
                         buffer: *buffer,
-                        memory: device_memory,
-                        memory_offset,
+                        memory: memory.memory(),
+                        memory_offset: memory.offset(),
                     },
                 )
                 .with_context(|| {
                     format!(
                         "Binding buffer {buffer_index} \
-                        size={aligned_size} into \
-                        device memory offset={memory_offset}"
+                        size={} into \
+                        device memory offset={}",
+                        requirements.size,
+                        memory.offset()
                     )
                 })?;
 
@@ -214,28 +170,23 @@ impl BufferAllocations {
                 buffer: *buffer,
             });
 
-            // Map memory.
-            let buffer_ptr = memory_ptr.map(|ptr| ptr.add(memory_offset as _));
-
-            // Advance.
-            memory_offset += aligned_size;
-
             // Output.
             allocations.push(BufferAllocation {
                 address: device_address,
                 size: requirements.size,
-                ptr: buffer_ptr,
+                ptr: memory.ptr(),
+                memory,
             });
         }
 
-        Ok(Self {
-            memory: device_memory,
-            allocations,
-        })
+        Ok(Self { allocations })
     }
 
     pub unsafe fn free(self, device: &Device) {
-        device.free_memory(self.memory);
+        let mut allocator = device.allocator();
+        for allocation in self.allocations {
+            allocator.free(device, allocation.memory);
+        }
     }
 
     #[must_use]
@@ -250,12 +201,11 @@ impl BufferAllocations {
 
 #[derive(Debug)]
 pub struct ImageAllocations {
-    memory: vk::DeviceMemory,
+    allocations: Vec<MemoryAllocation>,
 }
 
 impl ImageAllocations {
     pub unsafe fn allocate(
-        physical_device: &PhysicalDevice,
         device: &Device,
         images: &[vk::Image],
         image_create_infos: &[vk::ImageCreateInfo],
@@ -311,52 +261,26 @@ impl ImageAllocations {
             memory_requirements.push(memory_requirements2.memory_requirements);
         }
 
-        // Images must be compatible with the allocation we make.
-        ensure!(memory_requirements
-            .iter()
-            .all(|req| req.alignment == memory_requirements[0].alignment));
-        ensure!(memory_requirements
-            .iter()
-            .all(|req| req.memory_type_bits == memory_requirements[0].memory_type_bits));
-        let alignment = memory_requirements[0].alignment;
-        let memory_type_bits = memory_requirements[0].memory_type_bits;
-
-        // Memory type index.
-        let memory_type_index = memory_type_index(
-            &physical_device.memory_properties,
-            property_flags,
-            memory_type_bits,
-        );
-
-        // Allocation size.
-        let allocation_size = memory_requirements
-            .iter()
-            .map(|req| aligned_size(req.size, alignment))
-            .sum::<vk::DeviceSize>();
-
-        // Allocation.
-        let device_memory = device
-            .allocate_memory(&vk::MemoryAllocateInfo {
-                s_type: vk::StructureType::MemoryAllocateInfo,
-                p_next: null(),
-                allocation_size,
-                memory_type_index,
-            })
-            .with_context(|| {
-                format!(
-                    "\
-                    Allocating device memory for {} images",
-                    images.len()
-                )
-            })?;
-
         // Sub-allocations.
-        let mut memory_offset = 0;
+        let mut allocator = device.allocator();
+        let mut allocations = vec![];
         for (image_index, (image, requirements)) in
             images.iter().zip(memory_requirements).enumerate()
         {
-            // Aligned size.
-            let aligned_size = aligned_size(requirements.size, alignment);
+            // Allocate.
+            let memory = allocator
+                .allocate(
+                    device,
+                    &requirements,
+                    property_flags,
+                    AllocationTiling::Optimal,
+                )
+                .with_context(|| {
+                    format!(
+                        "Allocating device memory for image {image_index} size={}",
+                        requirements.size
+                    )
+                })?;
 
             // Bind image memory.
             device
@@ -366,55 +290,37 @@ impl ImageAllocations {
                         s_type: vk::StructureType::BindImageMemoryInfo,
                         p_next: null(),
                         image: *image,
-                        memory: device_memory,
-                        memory_offset,
+                        memory: memory.memory(),
+                        memory_offset: memory.offset(),
                     },
                 )
                 .with_context(|| {
                     format!(
                         "\
                         Binding image {image_index} \
-                        size={aligned_size} into \
-                        device memory offset={memory_offset}"
+                        size={} into \
+                        device memory offset={}",
+                        requirements.size,
+                        memory.offset()
                     )
                 })?;
 
-            // Advance.
-            memory_offset += aligned_size;
+            // Output.
+            allocations.push(memory);
         }
 
-        Ok(Self {
-            memory: device_memory,
-        })
+        Ok(Self { allocations })
     }
 
     pub unsafe fn free(self, device: &Device) {
-        device.free_memory(self.memory);
+        let mut allocator = device.allocator();
+        for allocation in self.allocations {
+            allocator.free(device, allocation);
+        }
     }
-}
-
-//
-// Utilities
-//
 
-fn memory_type_index(
-    memory: &vk::PhysicalDeviceMemoryProperties,
-    property_flags: impl Into<vk::MemoryPropertyFlags> + Copy,
-    memory_type_bits: u32,
-) -> u32 {
-    for memory_type_index in 0..memory.memory_type_count {
-        let memory_type = memory.memory_types[memory_type_index as usize];
-        let type_matches = (1 << memory_type_index) & memory_type_bits != 0;
-        let property_matches = memory_type.property_flags.contains(property_flags);
-        if type_matches && property_matches {
-            debug!(
-                "index={}, type={:?}, heap={:?}",
-                memory_type_index,
-                &memory.memory_types[memory_type_index as usize].property_flags,
-                &memory.memory_heaps[memory_type.heap_index as usize].flags
-            );
-            return memory_type_index;
-        }
+    #[must_use]
+    pub fn allocations(&self) -> &[MemoryAllocation] {
+        &self.allocations
     }
-    panic!("Unable to find suitable memory type for the buffer, memory_type_bits=0b{memory_type_bits:b}");
 }
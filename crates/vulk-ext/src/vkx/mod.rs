@@ -4,14 +4,18 @@ use super::*;
 // Modules
 //
 
+mod allocator;
 mod buffer;
 mod command_buffer;
 mod debug_utils;
+mod deletion;
 mod descriptor;
 mod device;
+mod frame;
 mod image;
 mod instance;
 mod memory;
+mod owned;
 mod physical_device;
 mod query;
 mod queue;
@@ -26,23 +30,35 @@ mod transfer;
 // Re-exports
 //
 
+pub use allocator::{
+    AllocationTiling, MemoryAllocation, MemoryAllocator, MemoryHeapReport, MemoryHeapStatistics,
+    MEMORY_BLOCK_SIZE, MEMORY_BUDGET_WARNING_FRACTION,
+};
 pub use buffer::{
     BufferCreator, BufferDedicatedResource, BufferDedicatedTransfer, BufferOps, BufferResource,
     BufferResourceOps, BufferShaderBindingTable,
 };
 pub use command_buffer::CommandBuffer;
+pub use deletion::DeletionQueue;
 pub use descriptor::{Descriptor, DescriptorBinding, DescriptorCreateInfo, DescriptorStorage};
-pub use device::Device;
+pub use device::{Device, DeviceFeatureRequest, DeviceFeatures, FeatureRequirement};
+pub use frame::{
+    Frame, FrameRing, TransientAllocation, TransientAllocator, TRANSIENT_MIN_ALIGNMENT,
+};
 pub use image::{ImageCreator, ImageDedicatedResource, ImageOps, ImageResource, ImageViewCreator};
 pub use instance::{Instance, InstanceCreateInfo};
 pub use memory::{BufferAllocation, BufferAllocations, ImageAllocations};
-pub use physical_device::PhysicalDevice;
+pub use owned::{DeviceResource, Owned, SharedDevice};
+pub use physical_device::{
+    DeviceFeatureGroup, MemoryHeapBudget, PhysicalDevice, PhysicalDeviceFeatureRequirement,
+    PhysicalDeviceFeatures, PhysicalDeviceSelector, PHYSICAL_DEVICE_INDEX_ENV_VAR,
+};
 pub use query::{StatisticsQuery, TimestampQuery};
-pub use queue::queue_submit;
+pub use queue::{queue_submit, queue_submit_to, Queue};
 pub use sampler::{SamplerCreator, SamplerResource};
 pub use semaphore::{BinarySemaphore, SemaphoreOps, TimelineSemaphore};
 pub use shader::{Shader, ShaderBinary, ShaderCompiler, ShaderCreateInfo, ShaderType};
-pub use surface::Surface;
+pub use surface::{Surface, SurfaceCreateInfo};
 pub use swapchain::Swapchain;
 pub use transfer::transfer_resources;
 
@@ -51,6 +67,10 @@ pub(crate) use debug_utils::DebugUtils;
 pub mod prelude {
     use super::*;
 
+    pub use allocator::{
+        AllocationTiling, MemoryAllocation, MemoryAllocator, MemoryHeapStatistics,
+        MEMORY_BLOCK_SIZE,
+    };
     pub use buffer::{BufferOps, BufferResourceOps};
     pub use image::ImageOps;
     pub use semaphore::SemaphoreOps;
@@ -0,0 +1,403 @@
+use std::collections::HashSet;
+use std::mem::ManuallyDrop;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use super::*;
+
+//
+// Device
+//
+
+/// Reference counted [`Device`] that destroys itself when the last reference
+/// goes away. Every [`Owned`] resource holds a reference, so the device always
+/// outlives its children. The [`Instance`] must still outlive the device.
+///
+/// Submissions are recorded with [`SharedDevice::submitted`]. A dropped
+/// [`Owned`] resource is destroyed once every submission recorded before the
+/// drop has completed, either by [`SharedDevice::poll`] or when the device
+/// goes away.
+pub struct SharedDevice {
+    device: ManuallyDrop<Device>,
+    deferred: Mutex<DeferredDestruction>,
+}
+
+impl SharedDevice {
+    #[must_use]
+    pub fn new(device: Device) -> Arc<Self> {
+        Arc::new(Self {
+            device: ManuallyDrop::new(device),
+            deferred: Mutex::default(),
+        })
+    }
+
+    /// Records a submission that signals `semaphore` with `value`, so that
+    /// resources dropped from now on wait for it.
+    pub fn submitted(&self, semaphore: &TimelineSemaphore, value: u64) {
+        self.deferred().submit(semaphore.handle(), value);
+    }
+
+    /// Destroys every dropped resource whose submissions have completed and
+    /// returns how many were destroyed. Typically called once per frame.
+    ///
+    /// # Safety
+    ///
+    /// Every semaphore passed to [`SharedDevice::submitted`] must stay alive
+    /// until it reaches the recorded value.
+    pub unsafe fn poll(&self) -> Result<usize> {
+        let retired = {
+            let mut deferred = self.deferred();
+            let mut counter_values = HashMap::new();
+            for semaphore in deferred.semaphores() {
+                counter_values.insert(semaphore, self.get_semaphore_counter_value(semaphore)?);
+            }
+            deferred.retire(&counter_values)
+        };
+        // Destroyed without holding the lock, since destroying a resource may
+        // drop other owned resources.
+        let retired_count = retired.len();
+        retired.destroy_now(self);
+        Ok(retired_count)
+    }
+
+    fn deferred(&self) -> MutexGuard<'_, DeferredDestruction> {
+        self.deferred.lock().unwrap()
+    }
+
+    unsafe fn destroy_or_defer<T: DeviceResource>(&self, resource: T) {
+        let mut deferred = self.deferred();
+        if deferred.submitted.is_empty() {
+            drop(deferred);
+            resource.destroy_resource(self);
+        } else {
+            let waits = deferred.submitted.iter().map(|(&s, &v)| (s, v)).collect();
+            deferred.queue.push_waits(resource, waits);
+        }
+    }
+}
+
+impl std::fmt::Debug for SharedDevice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SharedDevice")
+            .field("device", &*self.device)
+            .field("deferred", &self.deferred().queue.len())
+            .finish()
+    }
+}
+
+impl std::ops::Deref for SharedDevice {
+    type Target = Device;
+
+    fn deref(&self) -> &Self::Target {
+        &self.device
+    }
+}
+
+impl Drop for SharedDevice {
+    fn drop(&mut self) {
+        unsafe {
+            let deferred = std::mem::take(self.deferred.get_mut().unwrap());
+            if !deferred.queue.is_empty() {
+                // A lost device has nothing left in flight either.
+                self.device.device_wait_idle().ok();
+                deferred.queue.destroy_now(&self.device);
+            }
+            ManuallyDrop::take(&mut self.device).destroy();
+        }
+    }
+}
+
+/// Dropped resources of a [`SharedDevice`] and the latest value submitted on
+/// each timeline semaphore.
+#[derive(Default)]
+struct DeferredDestruction {
+    queue: DeletionQueue,
+    submitted: HashMap<vk::Semaphore, u64>,
+}
+
+// Queued resources are Vulkan handles and mapped pointers, which may be
+// destroyed from any thread while the mutex synchronizes access to them.
+unsafe impl Send for DeferredDestruction {}
+
+impl DeferredDestruction {
+    fn submit(&mut self, semaphore: vk::Semaphore, value: u64) {
+        let submitted = self.submitted.entry(semaphore).or_insert(value);
+        *submitted = (*submitted).max(value);
+    }
+
+    /// Semaphores whose counter is needed to retire submissions and queued
+    /// resources.
+    fn semaphores(&self) -> HashSet<vk::Semaphore> {
+        let mut semaphores = self
+            .queue
+            .pending_values()
+            .into_keys()
+            .collect::<HashSet<_>>();
+        semaphores.extend(self.submitted.keys());
+        semaphores
+    }
+
+    /// Forgets completed submissions, so that later drops no longer wait for
+    /// them, and removes the queued resources that can be destroyed.
+    fn retire(&mut self, counter_values: &HashMap<vk::Semaphore, u64>) -> DeletionQueue {
+        self.submitted.retain(|semaphore, value| {
+            counter_values
+                .get(semaphore)
+                .is_none_or(|counter_value| counter_value < value)
+        });
+        self.queue.retire(counter_values)
+    }
+}
+
+//
+// Resources
+//
+
+/// Resources destroyed through the [`Device`] that created them.
+///
+/// A dropped [`Owned`] resource is destroyed on whichever thread polls its
+/// [`SharedDevice`], so resources must not hold thread bound state.
+pub trait DeviceResource: 'static {
+    unsafe fn destroy_resource(self, device: &Device);
+}
+
+macro_rules! impl_device_resource {
+    ($fn:ident: $($ty:ty),+ $(,)?) => {
+        $(
+            impl DeviceResource for $ty {
+                unsafe fn destroy_resource(self, device: &Device) {
+                    self.$fn(device);
+                }
+            }
+        )+
+    };
+}
+
+impl_device_resource!(
+    destroy:
+    BufferResource,
+    BufferDedicatedResource,
+    BufferDedicatedTransfer,
+    BufferShaderBindingTable,
+    CommandBuffer,
+    DescriptorStorage,
+    ImageResource,
+    ImageDedicatedResource,
+    SamplerResource,
+    TimestampQuery,
+    StatisticsQuery,
+    TimelineSemaphore,
+    BinarySemaphore,
+    Shader,
+    Swapchain,
+    TransientAllocator,
+);
+impl_device_resource!(free: BufferAllocations, ImageAllocations);
+
+impl<T: DeviceResource> DeviceResource for Vec<T> {
+    unsafe fn destroy_resource(self, device: &Device) {
+        for resource in self {
+            resource.destroy_resource(device);
+        }
+    }
+}
+
+/// A resource that keeps its [`Device`] alive and destroys itself when
+/// dropped.
+///
+/// Dropping waits for every submission recorded with
+/// [`SharedDevice::submitted`], while [`Owned::destroy`] destroys the resource
+/// right away.
+pub struct Owned<T: DeviceResource> {
+    device: Arc<SharedDevice>,
+    resource: ManuallyDrop<T>,
+}
+
+impl<T: DeviceResource> Owned<T> {
+    /// Creates a resource with `create` and takes ownership of it.
+    ///
+    /// # Safety
+    ///
+    /// `create` must create the resource from the [`Device`] it is given, and
+    /// the resource must not be destroyed by anyone else.
+    pub unsafe fn create<F>(device: &Arc<SharedDevice>, create: F) -> Result<Self>
+    where
+        F: FnOnce(&Device) -> Result<T>,
+    {
+        let resource = create(device)?;
+        Ok(Self {
+            device: device.clone(),
+            resource: ManuallyDrop::new(resource),
+        })
+    }
+
+    /// Takes ownership of an existing resource.
+    ///
+    /// # Safety
+    ///
+    /// `resource` must have been created from `device` and must not be
+    /// destroyed by anyone else.
+    pub unsafe fn from_resource(device: &Arc<SharedDevice>, resource: T) -> Self {
+        Self {
+            device: device.clone(),
+            resource: ManuallyDrop::new(resource),
+        }
+    }
+
+    /// Gives up ownership, the caller becomes responsible for destroying the
+    /// resource.
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        let mut this = ManuallyDrop::new(self);
+        unsafe {
+            let resource = ManuallyDrop::take(&mut this.resource);
+            std::ptr::drop_in_place(&raw mut this.device);
+            resource
+        }
+    }
+
+    /// Destroys the resource and releases its reference to the device.
+    ///
+    /// # Safety
+    ///
+    /// The resource must not be in use by any pending submission.
+    pub unsafe fn destroy(self) {
+        let device = self.device.clone();
+        self.into_inner().destroy_resource(&device);
+    }
+
+    #[must_use]
+    pub fn device(&self) -> &Arc<SharedDevice> {
+        &self.device
+    }
+}
+
+impl<T: DeviceResource> DeviceResource for Owned<T> {
+    unsafe fn destroy_resource(self, _device: &Device) {
+        self.destroy();
+    }
+}
+
+impl<T: DeviceResource + std::fmt::Debug> std::fmt::Debug for Owned<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Owned").field(&*self.resource).finish()
+    }
+}
+
+impl<T: DeviceResource> std::ops::Deref for Owned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+impl<T: DeviceResource> std::ops::DerefMut for Owned<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.resource
+    }
+}
+
+impl<T: DeviceResource> Drop for Owned<T> {
+    fn drop(&mut self) {
+        unsafe {
+            let resource = ManuallyDrop::take(&mut self.resource);
+            self.device.destroy_or_defer(resource);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+    use vk::Handle;
+
+    struct TestResource(Arc<AtomicU32>);
+
+    impl DeviceResource for TestResource {
+        unsafe fn destroy_resource(self, _device: &Device) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    // The device has no loaded commands and is never destroyed.
+    fn shared_device() -> ManuallyDrop<Arc<SharedDevice>> {
+        ManuallyDrop::new(SharedDevice::new(unsafe { Device::null() }))
+    }
+
+    fn destroyed(counter: &AtomicU32) -> u32 {
+        counter.load(Ordering::Relaxed)
+    }
+
+    #[test]
+    fn owned_device_references() {
+        let device = shared_device();
+        let counter = Arc::new(AtomicU32::new(0));
+
+        let first =
+            unsafe { Owned::create(&device, |_| Ok(TestResource(counter.clone()))) }.unwrap();
+        let second = unsafe { Owned::from_resource(&device, TestResource(counter.clone())) };
+        assert_eq!(Arc::strong_count(&device), 3);
+        assert!(Arc::ptr_eq(first.device(), &device));
+
+        unsafe { first.destroy() };
+        assert_eq!(destroyed(&counter), 1);
+        assert_eq!(Arc::strong_count(&device), 2);
+        drop(second);
+        assert_eq!(destroyed(&counter), 2);
+        assert_eq!(Arc::strong_count(&device), 1);
+    }
+
+    #[test]
+    fn owned_into_inner() {
+        let device = shared_device();
+        let counter = Arc::new(AtomicU32::new(0));
+
+        let owned = unsafe { Owned::from_resource(&device, TestResource(counter.clone())) };
+        assert_eq!(Arc::strong_count(&device), 2);
+        let resource = owned.into_inner();
+        assert_eq!(Arc::strong_count(&device), 1);
+        assert_eq!(destroyed(&counter), 0);
+
+        let owned = unsafe { Owned::from_resource(&device, resource) };
+        assert_eq!(Arc::strong_count(&device), 2);
+        drop(owned);
+        assert_eq!(destroyed(&counter), 1);
+        assert_eq!(Arc::strong_count(&device), 1);
+    }
+
+    #[test]
+    fn owned_drop_after_submission() {
+        let device = shared_device();
+        let counter = Arc::new(AtomicU32::new(0));
+        let first = vk::Semaphore::from_raw(1);
+        let second = vk::Semaphore::from_raw(2);
+
+        device.deferred().submit(first, 2);
+        device.deferred().submit(first, 1);
+        drop(unsafe { Owned::from_resource(&device, TestResource(counter.clone())) });
+        device.deferred().submit(second, 1);
+        drop(unsafe { Owned::from_resource(&device, TestResource(counter.clone())) });
+        assert_eq!(destroyed(&counter), 0);
+        assert_eq!(Arc::strong_count(&device), 1);
+
+        let mut deferred = device.deferred();
+        assert_eq!(deferred.queue.len(), 2);
+        assert_eq!(deferred.semaphores(), HashSet::from([first, second]));
+
+        let retired = deferred.retire(&HashMap::from([(first, 2), (second, 0)]));
+        assert_eq!(retired.len(), 1);
+        assert_eq!(deferred.submitted, HashMap::from([(second, 1)]));
+        assert_eq!(deferred.semaphores(), HashSet::from([first, second]));
+
+        let retired = deferred.retire(&HashMap::from([(first, 2), (second, 1)]));
+        assert_eq!(retired.len(), 1);
+        assert!(deferred.submitted.is_empty());
+        assert!(deferred.queue.is_empty());
+        drop(deferred);
+
+        drop(unsafe { Owned::from_resource(&device, TestResource(counter.clone())) });
+        assert_eq!(destroyed(&counter), 1);
+    }
+}
@@ -1,9 +1,15 @@
 use super::*;
 
+/// Default environment variable used to force a physical device by its
+/// enumeration index.
+pub const PHYSICAL_DEVICE_INDEX_ENV_VAR: &str = "VULK_PHYSICAL_DEVICE_INDEX";
+
 #[derive(Debug)]
 pub struct PhysicalDevice {
     physical_device: vk::PhysicalDevice,
     pub properties: vk::PhysicalDeviceProperties,
+    pub features: PhysicalDeviceFeatures,
+    pub extension_properties: Vec<vk::ExtensionProperties>,
     pub queue_family_properties: Vec<vk::QueueFamilyProperties>,
     pub memory_properties: vk::PhysicalDeviceMemoryProperties,
     pub descriptor_buffer_properties_ext: vk::PhysicalDeviceDescriptorBufferPropertiesEXT,
@@ -14,60 +20,30 @@ pub struct PhysicalDevice {
 }
 
 impl PhysicalDevice {
-    pub unsafe fn create(instance: &Instance) -> Result<Self> {
-        // Find physical devices.
-        let physical_devices = vulk::read_to_vec(
-            |count, ptr| instance.enumerate_physical_devices(count, ptr),
-            None,
-        )?;
-
+    pub unsafe fn create(instance: &Instance, selector: &PhysicalDeviceSelector) -> Result<Self> {
         // Pick a physical device.
-        let physical_device = physical_devices
-            .into_iter()
-            .find(|&physical_device| {
-                let mut props2: vk::PhysicalDeviceProperties2 = zeroed();
-                props2.s_type = vk::StructureType::PhysicalDeviceProperties2;
-                instance.get_physical_device_properties2(physical_device, &raw mut props2);
-                let props = props2.properties;
-                let device_name = std::ffi::CStr::from_ptr(props.device_name.as_ptr());
-                let device_name = device_name.to_string_lossy();
-                let discrete_gpu = props.device_type == vk::PhysicalDeviceType::DiscreteGpu;
-                let nvidia_gpu = device_name.contains("NVIDIA");
-                discrete_gpu == nvidia_gpu
-            })
-            .context("Failed to find compatible physical device")?;
+        let physical_device = selector.select(instance)?;
+        let candidate = PhysicalDeviceCandidate::query(instance, physical_device)?;
 
         // Physical device properties.
-        let mut as_props: vk::PhysicalDeviceAccelerationStructurePropertiesKHR = zeroed();
-        as_props.s_type = vk::StructureType::PhysicalDeviceAccelerationStructurePropertiesKHR;
-        let mut rtp_props: vk::PhysicalDeviceRayTracingPipelinePropertiesKHR = zeroed();
-        rtp_props.s_type = vk::StructureType::PhysicalDeviceRayTracingPipelinePropertiesKHR;
-        rtp_props.p_next = addr_of_mut!(as_props).cast();
-        let mut sg_props: vk::PhysicalDeviceSubgroupProperties = zeroed();
-        sg_props.s_type = vk::StructureType::PhysicalDeviceSubgroupProperties;
-        sg_props.p_next = addr_of_mut!(rtp_props).cast();
-        let mut ms_props: vk::PhysicalDeviceMeshShaderPropertiesEXT = zeroed();
-        ms_props.s_type = vk::StructureType::PhysicalDeviceMeshShaderPropertiesEXT;
-        ms_props.p_next = addr_of_mut!(sg_props).cast();
-        let mut db_props: vk::PhysicalDeviceDescriptorBufferPropertiesEXT = zeroed();
-        db_props.s_type = vk::StructureType::PhysicalDeviceDescriptorBufferPropertiesEXT;
-        db_props.p_next = addr_of_mut!(ms_props).cast();
-        let mut props2 = vk::PhysicalDeviceProperties2 {
-            s_type: vk::StructureType::PhysicalDeviceProperties2,
-            p_next: addr_of_mut!(db_props).cast(),
-            properties: zeroed(),
-        };
+        let mut as_props = vk::PhysicalDeviceAccelerationStructurePropertiesKHR::default();
+        let mut rtp_props = vk::PhysicalDeviceRayTracingPipelinePropertiesKHR::default();
+        let mut sg_props = vk::PhysicalDeviceSubgroupProperties::default();
+        let mut ms_props = vk::PhysicalDeviceMeshShaderPropertiesEXT::default();
+        let mut db_props = vk::PhysicalDeviceDescriptorBufferPropertiesEXT::default();
+        let mut props2 = vk::PhysicalDeviceProperties2::default();
+        props2
+            .push_next(&mut as_props)
+            .push_next(&mut rtp_props)
+            .push_next(&mut sg_props)
+            .push_next(&mut ms_props)
+            .push_next(&mut db_props);
         instance.get_physical_device_properties2(physical_device, &raw mut props2);
 
         // Queue family properties.
         let qf_props = {
-            let qf_props2 = vulk::read_to_vec(
-                |a, b| {
-                    instance.get_physical_device_queue_family_properties2(physical_device, a, b);
-                    Ok(())
-                },
-                Some(vk::StructureType::QueueFamilyProperties2),
-            )?;
+            let qf_props2 =
+                instance.get_physical_device_queue_family_properties2_to_vec(physical_device);
             qf_props2
                 .into_iter()
                 .map(|qf_prop| qf_prop.queue_family_properties)
@@ -86,11 +62,17 @@ impl PhysicalDevice {
         };
 
         // Post-validation.
-        descriptor::validate_descriptor_sizes(&db_props)?;
+        if DeviceFeatureGroup::DescriptorBuffer
+            .is_supported(&candidate.features, &candidate.extension_properties)
+        {
+            descriptor::validate_descriptor_sizes(&db_props)?;
+        }
 
         Ok(Self {
             physical_device,
             properties: props2.properties,
+            features: candidate.features,
+            extension_properties: candidate.extension_properties,
             queue_family_properties: qf_props,
             memory_properties: mem_props,
             descriptor_buffer_properties_ext: db_props,
@@ -105,4 +87,537 @@ impl PhysicalDevice {
     pub fn handle(&self) -> vk::PhysicalDevice {
         self.physical_device
     }
+
+    #[must_use]
+    pub fn device_name(&self) -> String {
+        device_name(&self.properties)
+    }
+
+    #[must_use]
+    pub fn supports_extension(&self, extension_name: &CStr) -> bool {
+        has_extension(&self.extension_properties, extension_name)
+    }
+
+    /// Properties of a supported extension, including its spec version.
+    #[must_use]
+    pub fn extension(&self, extension_name: &CStr) -> Option<&vk::ExtensionProperties> {
+        self.extension_properties.iter().find(|props| unsafe {
+            CStr::from_ptr(props.extension_name.as_ptr()) == extension_name
+        })
+    }
+
+    /// Names of the extensions in `extension_names` this device does not
+    /// support.
+    #[must_use]
+    pub fn missing_extensions(&self, extension_names: &[&CStr]) -> Vec<String> {
+        extension_names
+            .iter()
+            .filter(|&&name| !self.supports_extension(name))
+            .map(|name| name.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    #[must_use]
+    pub fn supports_memory_budget(&self) -> bool {
+        self.supports_extension(c"VK_EXT_memory_budget")
+    }
+
+    /// Current per-heap budget and process usage, requires
+    /// `VK_EXT_memory_budget`. The values are estimates and change as other
+    /// processes allocate memory.
+    pub unsafe fn memory_budget(&self, instance: &Instance) -> Result<Vec<MemoryHeapBudget>> {
+        ensure!(
+            self.supports_memory_budget(),
+            "{} does not support VK_EXT_memory_budget",
+            self.device_name()
+        );
+        let mut budget_props = vk::PhysicalDeviceMemoryBudgetPropertiesEXT::default();
+        let mut mem_props2 = vk::PhysicalDeviceMemoryProperties2::default();
+        mem_props2.push_next(&mut budget_props);
+        instance.get_physical_device_memory_properties2(self.physical_device, &raw mut mem_props2);
+        let mem_props = mem_props2.memory_properties;
+        Ok((0..mem_props.memory_heap_count as usize)
+            .map(|heap_index| MemoryHeapBudget {
+                flags: mem_props.memory_heaps[heap_index].flags,
+                size: mem_props.memory_heaps[heap_index].size,
+                budget: budget_props.heap_budget[heap_index],
+                usage: budget_props.heap_usage[heap_index],
+            })
+            .collect())
+    }
+
+    #[must_use]
+    pub fn supports_feature_group(&self, group: DeviceFeatureGroup) -> bool {
+        group.is_supported(&self.features, &self.extension_properties)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct MemoryHeapBudget {
+    pub flags: vk::MemoryHeapFlags,
+    pub size: vk::DeviceSize,
+    /// How much the process can allocate from the heap before allocations may
+    /// fail or cause performance degradation.
+    pub budget: vk::DeviceSize,
+    /// How much the process is currently using from the heap.
+    pub usage: vk::DeviceSize,
+}
+
+impl MemoryHeapBudget {
+    #[must_use]
+    pub fn usage_fraction(&self) -> f64 {
+        if self.budget == 0 {
+            return 0.0;
+        }
+        self.usage as f64 / self.budget as f64
+    }
+}
+
+//
+// Features
+//
+
+/// Feature structures queried with `vkGetPhysicalDeviceFeatures2`. The
+/// `p_next` pointers are cleared after the query.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PhysicalDeviceFeatures {
+    pub features: vk::PhysicalDeviceFeatures,
+    pub vulkan11: vk::PhysicalDeviceVulkan11Features,
+    pub vulkan12: vk::PhysicalDeviceVulkan12Features,
+    pub vulkan13: vk::PhysicalDeviceVulkan13Features,
+    pub acceleration_structure: vk::PhysicalDeviceAccelerationStructureFeaturesKHR,
+    pub ray_tracing_pipeline: vk::PhysicalDeviceRayTracingPipelineFeaturesKHR,
+    pub ray_query: vk::PhysicalDeviceRayQueryFeaturesKHR,
+    pub ray_tracing_maintenance1: vk::PhysicalDeviceRayTracingMaintenance1FeaturesKHR,
+    pub mesh_shader: vk::PhysicalDeviceMeshShaderFeaturesEXT,
+    pub descriptor_buffer: vk::PhysicalDeviceDescriptorBufferFeaturesEXT,
+    pub shader_object: vk::PhysicalDeviceShaderObjectFeaturesEXT,
+}
+
+impl PhysicalDeviceFeatures {
+    #[must_use]
+    pub unsafe fn query(instance: &Instance, physical_device: vk::PhysicalDevice) -> Self {
+        let mut f = Self::default();
+        let mut features2 = vk::PhysicalDeviceFeatures2::default();
+        features2
+            .push_next(&mut f.vulkan11)
+            .push_next(&mut f.vulkan12)
+            .push_next(&mut f.vulkan13)
+            .push_next(&mut f.acceleration_structure)
+            .push_next(&mut f.ray_tracing_pipeline)
+            .push_next(&mut f.ray_query)
+            .push_next(&mut f.ray_tracing_maintenance1)
+            .push_next(&mut f.mesh_shader)
+            .push_next(&mut f.descriptor_buffer)
+            .push_next(&mut f.shader_object);
+        instance.get_physical_device_features2(physical_device, &raw mut features2);
+        f.features = features2.features;
+
+        // Don't leave pointers into the stack behind.
+        f.vulkan11.p_next = null_mut();
+        f.vulkan12.p_next = null_mut();
+        f.vulkan13.p_next = null_mut();
+        f.acceleration_structure.p_next = null_mut();
+        f.ray_tracing_pipeline.p_next = null_mut();
+        f.ray_query.p_next = null_mut();
+        f.ray_tracing_maintenance1.p_next = null_mut();
+        f.mesh_shader.p_next = null_mut();
+        f.descriptor_buffer.p_next = null_mut();
+        f.shader_object.p_next = null_mut();
+        f
+    }
+}
+
+//
+// Feature groups
+//
+
+macro_rules! feature {
+    ($group:ident . $field:ident) => {
+        PhysicalDeviceFeatureRequirement {
+            name: concat!(stringify!($group), ".", stringify!($field)),
+            predicate: |f| f.$group.$field == vk::TRUE,
+        }
+    };
+}
+
+/// Optional device functionality, see [`DeviceFeatureRequest`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeviceFeatureGroup {
+    RayTracing,
+    MeshShader,
+    DescriptorBuffer,
+    ShaderObject,
+}
+
+impl DeviceFeatureGroup {
+    pub const ALL: [Self; 4] = [
+        Self::RayTracing,
+        Self::MeshShader,
+        Self::DescriptorBuffer,
+        Self::ShaderObject,
+    ];
+
+    #[must_use]
+    pub fn extensions(self) -> &'static [&'static CStr] {
+        match self {
+            Self::RayTracing => &[
+                c"VK_KHR_acceleration_structure",
+                c"VK_KHR_ray_tracing_pipeline",
+                c"VK_KHR_ray_query",
+                c"VK_KHR_deferred_host_operations",
+                c"VK_KHR_pipeline_library",
+                c"VK_KHR_ray_tracing_maintenance1",
+            ],
+            Self::MeshShader => &[c"VK_EXT_mesh_shader"],
+            Self::DescriptorBuffer => &[c"VK_EXT_descriptor_buffer"],
+            Self::ShaderObject => &[c"VK_EXT_shader_object"],
+        }
+    }
+
+    #[must_use]
+    pub fn feature_requirements(self) -> Vec<PhysicalDeviceFeatureRequirement> {
+        match self {
+            Self::RayTracing => vec![
+                feature!(acceleration_structure.acceleration_structure),
+                feature!(ray_tracing_pipeline.ray_tracing_pipeline),
+                feature!(ray_tracing_pipeline.ray_tracing_pipeline_trace_rays_indirect),
+                feature!(ray_tracing_pipeline.ray_traversal_primitive_culling),
+                feature!(ray_query.ray_query),
+                feature!(ray_tracing_maintenance1.ray_tracing_maintenance1),
+                feature!(ray_tracing_maintenance1.ray_tracing_pipeline_trace_rays_indirect2),
+            ],
+            Self::MeshShader => vec![
+                feature!(mesh_shader.task_shader),
+                feature!(mesh_shader.mesh_shader),
+                feature!(mesh_shader.mesh_shader_queries),
+            ],
+            Self::DescriptorBuffer => vec![feature!(descriptor_buffer.descriptor_buffer)],
+            Self::ShaderObject => vec![feature!(shader_object.shader_object)],
+        }
+    }
+
+    /// The group an extension belongs to, if any.
+    #[must_use]
+    pub fn of_extension(extension_name: &CStr) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|group| group.extensions().contains(&extension_name))
+    }
+
+    #[must_use]
+    pub fn is_supported(
+        self,
+        features: &PhysicalDeviceFeatures,
+        extension_properties: &[vk::ExtensionProperties],
+    ) -> bool {
+        self.extensions()
+            .iter()
+            .all(|name| has_extension(extension_properties, name))
+            && self
+                .feature_requirements()
+                .iter()
+                .all(|requirement| (requirement.predicate)(features))
+    }
+}
+
+//
+// Selector
+//
+
+/// Named predicate over [`PhysicalDeviceFeatures`]. The name is used in
+/// rejection diagnostics.
+#[derive(Clone, Copy, Debug)]
+pub struct PhysicalDeviceFeatureRequirement {
+    pub name: &'static str,
+    pub predicate: fn(&PhysicalDeviceFeatures) -> bool,
+}
+
+/// Picks a physical device out of everything the instance enumerates.
+///
+/// Every candidate is checked against the hard criteria (device type, vendor
+/// ID, name substring, extensions, features). The surviving candidates are
+/// ranked by the position of their device type in the preference list, with
+/// device-local memory size as a tie-breaker. If the index environment
+/// variable is set, only that device is considered.
+#[derive(Clone, Debug)]
+pub struct PhysicalDeviceSelector {
+    device_types: Vec<vk::PhysicalDeviceType>,
+    vendor_id: Option<u32>,
+    device_name: Option<String>,
+    required_extensions: Vec<CString>,
+    required_features: Vec<PhysicalDeviceFeatureRequirement>,
+    index_env_var: Option<String>,
+}
+
+impl Default for PhysicalDeviceSelector {
+    fn default() -> Self {
+        Self::for_device_features(&DeviceFeatureRequest::default())
+    }
+}
+
+impl PhysicalDeviceSelector {
+    /// Requires everything [`Device::create`] needs to satisfy `request`.
+    #[must_use]
+    pub fn for_device_features(request: &DeviceFeatureRequest) -> Self {
+        let mut required_extensions = vec![];
+        let mut required_features = base_required_features();
+        for &name in &vulk::REQUIRED_DEVICE_EXTENSIONS {
+            let name = unsafe { CStr::from_ptr(name) };
+            if DeviceFeatureGroup::of_extension(name).is_none() && !request.is_dropped(name) {
+                required_extensions.push(name.to_owned());
+            }
+        }
+        required_extensions.extend(request.extra_extensions.iter().cloned());
+        for group in DeviceFeatureGroup::ALL {
+            if request.requirement(group) == FeatureRequirement::Required {
+                required_extensions.extend(group.extensions().iter().map(|&name| name.to_owned()));
+                required_features.extend(group.feature_requirements());
+            }
+        }
+        Self {
+            device_types: vec![
+                vk::PhysicalDeviceType::DiscreteGpu,
+                vk::PhysicalDeviceType::IntegratedGpu,
+                vk::PhysicalDeviceType::VirtualGpu,
+                vk::PhysicalDeviceType::Cpu,
+                vk::PhysicalDeviceType::Other,
+            ],
+            vendor_id: None,
+            device_name: None,
+            required_extensions,
+            required_features,
+            index_env_var: Some(PHYSICAL_DEVICE_INDEX_ENV_VAR.to_owned()),
+        }
+    }
+
+    /// Accepted device types, most preferred first. Types not in the list are
+    /// rejected.
+    #[must_use]
+    pub fn device_types(mut self, device_types: &[vk::PhysicalDeviceType]) -> Self {
+        self.device_types = device_types.to_vec();
+        self
+    }
+
+    #[must_use]
+    pub fn vendor_id(mut self, vendor_id: u32) -> Self {
+        self.vendor_id = Some(vendor_id);
+        self
+    }
+
+    /// Case-insensitive substring of the device name.
+    #[must_use]
+    pub fn device_name(mut self, device_name: &str) -> Self {
+        self.device_name = Some(device_name.to_owned());
+        self
+    }
+
+    #[must_use]
+    pub fn required_extension(mut self, extension_name: &CStr) -> Self {
+        self.required_extensions.push(extension_name.to_owned());
+        self
+    }
+
+    #[must_use]
+    pub fn required_feature(
+        mut self,
+        name: &'static str,
+        predicate: fn(&PhysicalDeviceFeatures) -> bool,
+    ) -> Self {
+        self.required_features
+            .push(PhysicalDeviceFeatureRequirement { name, predicate });
+        self
+    }
+
+    /// Environment variable holding a device index override, or `None` to
+    /// ignore the environment.
+    #[must_use]
+    pub fn index_env_var(mut self, index_env_var: Option<&str>) -> Self {
+        self.index_env_var = index_env_var.map(str::to_owned);
+        self
+    }
+
+    pub unsafe fn select(&self, instance: &Instance) -> Result<vk::PhysicalDevice> {
+        // Find physical devices.
+        let physical_devices = instance.enumerate_physical_devices_to_vec()?;
+        ensure!(!physical_devices.is_empty(), "No physical devices found");
+
+        // Index override.
+        let index_override = self.index_override()?;
+        if let Some(index) = index_override {
+            ensure!(
+                index < physical_devices.len(),
+                "Physical device index override {index} is out of range, found {} devices",
+                physical_devices.len()
+            );
+        }
+
+        // Evaluate candidates.
+        let mut best: Option<(u64, vk::PhysicalDevice)> = None;
+        let mut rejections = vec![];
+        for (index, &physical_device) in physical_devices.iter().enumerate() {
+            let candidate = PhysicalDeviceCandidate::query(instance, physical_device)?;
+            let name = device_name(&candidate.properties);
+            if index_override.is_some_and(|i| i != index) {
+                rejections.push(format!("[{index}] {name}: not the overridden index"));
+                continue;
+            }
+            match self.score(&candidate) {
+                Ok(score) => {
+                    debug!("Physical device [{index}] {name} accepted with score {score}");
+                    if best.is_none_or(|(best_score, _)| score > best_score) {
+                        best = Some((score, physical_device));
+                    }
+                }
+                Err(reasons) => {
+                    rejections.push(format!("[{index}] {name}: {}", reasons.join(", ")));
+                }
+            }
+        }
+
+        match best {
+            Some((_, physical_device)) => Ok(physical_device),
+            None => bail!(
+                "Failed to find compatible physical device:\n{}",
+                rejections.join("\n")
+            ),
+        }
+    }
+
+    fn index_override(&self) -> Result<Option<usize>> {
+        let Some(env_var) = &self.index_env_var else {
+            return Ok(None);
+        };
+        let Ok(value) = std::env::var(env_var) else {
+            return Ok(None);
+        };
+        let index = value
+            .trim()
+            .parse()
+            .with_context(|| format!("Parsing {env_var}={value}"))?;
+        Ok(Some(index))
+    }
+
+    fn score(&self, candidate: &PhysicalDeviceCandidate) -> Result<u64, Vec<String>> {
+        let props = &candidate.properties;
+        let mut reasons = vec![];
+
+        // Device type.
+        let type_rank = self
+            .device_types
+            .iter()
+            .position(|&device_type| device_type == props.device_type);
+        if type_rank.is_none() {
+            reasons.push(format!(
+                "device type {:?} is not accepted",
+                props.device_type
+            ));
+        }
+
+        // Vendor.
+        if let Some(vendor_id) = self.vendor_id {
+            if props.vendor_id != vendor_id {
+                reasons.push(format!(
+                    "vendor ID 0x{:04x} is not 0x{vendor_id:04x}",
+                    props.vendor_id
+                ));
+            }
+        }
+
+        // Name.
+        if let Some(substring) = &self.device_name {
+            let name = device_name(props).to_lowercase();
+            if !name.contains(&substring.to_lowercase()) {
+                reasons.push(format!("name does not contain {substring:?}"));
+            }
+        }
+
+        // Extensions.
+        for extension_name in &self.required_extensions {
+            if !has_extension(&candidate.extension_properties, extension_name) {
+                reasons.push(format!(
+                    "missing extension {}",
+                    extension_name.to_string_lossy()
+                ));
+            }
+        }
+
+        // Features.
+        for requirement in &self.required_features {
+            if !(requirement.predicate)(&candidate.features) {
+                reasons.push(format!("missing feature {}", requirement.name));
+            }
+        }
+
+        if !reasons.is_empty() {
+            return Err(reasons);
+        }
+
+        // Rank by device type first, then by device-local memory in MiB.
+        let type_score = (self.device_types.len() - type_rank.unwrap()) as u64;
+        let memory = &candidate.memory_properties;
+        let device_local_mib = memory.memory_heaps[..memory.memory_heap_count as usize]
+            .iter()
+            .filter(|heap| heap.flags.contains(vk::MemoryHeapFlagBits::DeviceLocal))
+            .map(|heap| heap.size >> 20)
+            .sum::<u64>();
+        Ok((type_score << 32) | device_local_mib.min(u64::from(u32::MAX)))
+    }
+}
+
+fn base_required_features() -> Vec<PhysicalDeviceFeatureRequirement> {
+    vec![
+        feature!(features.pipeline_statistics_query),
+        feature!(features.shader_int64),
+        feature!(features.shader_int16),
+        feature!(vulkan11.storage_buffer16_bit_access),
+        feature!(vulkan11.uniform_and_storage_buffer16_bit_access),
+        feature!(vulkan11.storage_push_constant16),
+        feature!(vulkan12.shader_buffer_int64_atomics),
+        feature!(vulkan12.descriptor_indexing),
+        feature!(vulkan12.scalar_block_layout),
+        feature!(vulkan12.host_query_reset),
+        feature!(vulkan12.timeline_semaphore),
+        feature!(vulkan12.buffer_device_address),
+        feature!(vulkan13.synchronization2),
+        feature!(vulkan13.dynamic_rendering),
+    ]
+}
+
+/// Everything the selector needs to judge a physical device.
+struct PhysicalDeviceCandidate {
+    properties: vk::PhysicalDeviceProperties,
+    features: PhysicalDeviceFeatures,
+    extension_properties: Vec<vk::ExtensionProperties>,
+    memory_properties: vk::PhysicalDeviceMemoryProperties,
+}
+
+impl PhysicalDeviceCandidate {
+    unsafe fn query(instance: &Instance, physical_device: vk::PhysicalDevice) -> Result<Self> {
+        let mut props2 = vk::PhysicalDeviceProperties2::default();
+        instance.get_physical_device_properties2(physical_device, &raw mut props2);
+        let features = PhysicalDeviceFeatures::query(instance, physical_device);
+        let extension_properties =
+            instance.enumerate_device_extension_properties_to_vec(physical_device, null())?;
+        let mut mem_props2 = vk::PhysicalDeviceMemoryProperties2::default();
+        instance.get_physical_device_memory_properties2(physical_device, &raw mut mem_props2);
+        Ok(Self {
+            properties: props2.properties,
+            features,
+            extension_properties,
+            memory_properties: mem_props2.memory_properties,
+        })
+    }
+}
+
+fn device_name(properties: &vk::PhysicalDeviceProperties) -> String {
+    unsafe { CStr::from_ptr(properties.device_name.as_ptr()) }
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn has_extension(extension_properties: &[vk::ExtensionProperties], extension_name: &CStr) -> bool {
+    extension_properties
+        .iter()
+        .any(|props| unsafe { CStr::from_ptr(props.extension_name.as_ptr()) } == extension_name)
 }
@@ -14,6 +14,10 @@ impl TimestampQuery {
         query_count: u32,
     ) -> Result<Self> {
         ensure!(query_count >= 2);
+        ensure!(
+            device.supports_timestamp_calibration(),
+            "Timestamp queries require VK_EXT_calibrated_timestamps"
+        );
         let query_pool = device.create_query_pool(&vk::QueryPoolCreateInfo {
             s_type: vk::StructureType::QueryPoolCreateInfo,
             p_next: null(),
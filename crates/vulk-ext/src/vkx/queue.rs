@@ -1,13 +1,91 @@
 use super::*;
 
+/// A device queue together with the command pool used to allocate its
+/// command buffers.
+#[derive(Clone, Copy, Debug)]
+pub struct Queue {
+    queue: vk::Queue,
+    family_index: u32,
+    family_properties: vk::QueueFamilyProperties,
+    command_pool: vk::CommandPool,
+}
+
+impl Queue {
+    pub(crate) unsafe fn create(
+        device: &vulk::Device,
+        family_index: u32,
+        family_properties: vk::QueueFamilyProperties,
+    ) -> Result<Self> {
+        let queue = device.get_device_queue2(&vk::DeviceQueueInfo2 {
+            s_type: vk::StructureType::DeviceQueueInfo2,
+            p_next: null(),
+            flags: vk::DeviceQueueCreateFlags::empty(),
+            queue_family_index: family_index,
+            queue_index: 0,
+        });
+        let command_pool = device.create_command_pool(&vk::CommandPoolCreateInfo {
+            s_type: vk::StructureType::CommandPoolCreateInfo,
+            p_next: null(),
+            flags: vk::CommandPoolCreateFlagBits::ResetCommandBuffer.into(),
+            queue_family_index: family_index,
+        })?;
+        Ok(Self {
+            queue,
+            family_index,
+            family_properties,
+            command_pool,
+        })
+    }
+
+    pub(crate) unsafe fn destroy(&self, device: &vulk::Device) {
+        device.destroy_command_pool(self.command_pool);
+    }
+
+    #[must_use]
+    pub fn handle(&self) -> vk::Queue {
+        self.queue
+    }
+
+    #[must_use]
+    pub fn family_index(&self) -> u32 {
+        self.family_index
+    }
+
+    #[must_use]
+    pub fn family_properties(&self) -> vk::QueueFamilyProperties {
+        self.family_properties
+    }
+
+    #[must_use]
+    pub fn command_pool(&self) -> vk::CommandPool {
+        self.command_pool
+    }
+}
+
 pub unsafe fn queue_submit(
     device: &Device,
     command_buffer: &CommandBuffer,
     wait_semaphores: &[vk::SemaphoreSubmitInfo],
     signal_semaphores: &[vk::SemaphoreSubmitInfo],
+) -> Result<()> {
+    queue_submit_to(
+        device,
+        device.queue(),
+        command_buffer,
+        wait_semaphores,
+        signal_semaphores,
+    )
+}
+
+pub unsafe fn queue_submit_to(
+    device: &Device,
+    queue: &Queue,
+    command_buffer: &CommandBuffer,
+    wait_semaphores: &[vk::SemaphoreSubmitInfo],
+    signal_semaphores: &[vk::SemaphoreSubmitInfo],
 ) -> Result<()> {
     device.queue_submit2(
-        device.queue_handle(),
+        queue.handle(),
         1,
         &vk::SubmitInfo2 {
             s_type: vk::StructureType::SubmitInfo2,
@@ -45,8 +45,10 @@ impl TimelineSemaphore {
         device.destroy_semaphore(self.semaphore);
     }
 
-    pub unsafe fn wait(&self, device: &Device, value: u64, timeout: u64) -> Result<()> {
-        device.wait_semaphores(
+    /// Returns `false` if `timeout` nanoseconds elapsed before the semaphore
+    /// reached `value`.
+    pub unsafe fn wait(&self, device: &Device, value: u64, timeout: u64) -> Result<bool> {
+        let result = device.wait_semaphores(
             &vk::SemaphoreWaitInfo {
                 s_type: vk::StructureType::SemaphoreWaitInfo,
                 p_next: null(),
@@ -57,7 +59,7 @@ impl TimelineSemaphore {
             },
             timeout,
         )?;
-        Ok(())
+        Ok(result == vk::Result::Success)
     }
 }
 
@@ -205,13 +205,8 @@ impl Shader {
                 },
             })
             .collect::<Vec<_>>();
-        let mut shaders = Vec::with_capacity(create_info.shader_binaries.len());
-        device.create_shaders_ext(
-            create_infos.len() as _,
-            create_infos.as_ptr(),
-            shaders.as_mut_ptr(),
-        )?;
-        shaders.set_len(create_info.shader_binaries.len());
+        let mut shaders = vec![vk::ShaderEXT::null(); create_infos.len()];
+        device.create_shaders_ext_slice(&create_infos, &mut shaders)?;
 
         let stages = create_info
             .shader_binaries
@@ -231,11 +226,6 @@ impl Shader {
 
 impl Shader {
     pub unsafe fn bind(&self, device: &Device, cmd: vk::CommandBuffer) {
-        device.cmd_bind_shaders_ext(
-            cmd,
-            self.stages.len() as _,
-            self.stages.as_ptr(),
-            self.shaders.as_ptr(),
-        );
+        device.cmd_bind_shaders_ext_slice(cmd, &self.stages, &self.shaders);
     }
 }
@@ -2,19 +2,28 @@ use super::*;
 
 use raw_window_handle::{HasRawDisplayHandle, HasRawWindowHandle};
 
+#[derive(Clone, Copy, Debug)]
+pub enum SurfaceCreateInfo {
+    Win32(vk::Win32SurfaceCreateInfoKHR),
+    Wayland(vk::WaylandSurfaceCreateInfoKHR),
+    Xlib(vk::XlibSurfaceCreateInfoKHR),
+    Xcb(vk::XcbSurfaceCreateInfoKHR),
+    Headless(vk::HeadlessSurfaceCreateInfoEXT),
+}
+
 #[derive(Debug)]
 pub struct Surface {
     surface: vk::SurfaceKHR,
-    pub create_info: vk::Win32SurfaceCreateInfoKHR,
+    pub create_info: SurfaceCreateInfo,
     pub surface_capabilities: vk::SurfaceCapabilitiesKHR,
     pub surface_formats: Vec<vk::SurfaceFormatKHR>,
     pub surface_format: vk::SurfaceFormatKHR,
     pub present_modes: Vec<vk::PresentModeKHR>,
     pub present_mode: vk::PresentModeKHR,
+    pub image_extent: vk::Extent2D,
 }
 
 impl Surface {
-    #[cfg(target_family = "windows")]
     pub unsafe fn create<Window>(
         instance: &Instance,
         physical_device: &PhysicalDevice,
@@ -29,59 +38,140 @@ impl Surface {
         let display_handle = window.raw_display_handle();
         let window_handle = window.raw_window_handle();
         let create_info = match (display_handle, window_handle) {
+            #[cfg(target_family = "windows")]
             (RawDisplayHandle::Windows(_), RawWindowHandle::Win32(window)) => {
-                vk::Win32SurfaceCreateInfoKHR {
+                SurfaceCreateInfo::Win32(vk::Win32SurfaceCreateInfoKHR {
                     s_type: vk::StructureType::Win32SurfaceCreateInfoKHR,
                     p_next: null(),
                     flags: vk::Win32SurfaceCreateFlagsKHR::empty(),
                     hinstance: window.hinstance,
                     hwnd: window.hwnd,
-                }
+                })
+            }
+            #[cfg(target_family = "unix")]
+            (RawDisplayHandle::Wayland(display), RawWindowHandle::Wayland(window)) => {
+                SurfaceCreateInfo::Wayland(vk::WaylandSurfaceCreateInfoKHR {
+                    s_type: vk::StructureType::WaylandSurfaceCreateInfoKHR,
+                    p_next: null(),
+                    flags: vk::WaylandSurfaceCreateFlagsKHR::empty(),
+                    display: display.display,
+                    surface: window.surface,
+                })
+            }
+            #[cfg(target_family = "unix")]
+            (RawDisplayHandle::Xlib(display), RawWindowHandle::Xlib(window)) => {
+                SurfaceCreateInfo::Xlib(vk::XlibSurfaceCreateInfoKHR {
+                    s_type: vk::StructureType::XlibSurfaceCreateInfoKHR,
+                    p_next: null(),
+                    flags: vk::XlibSurfaceCreateFlagsKHR::empty(),
+                    dpy: display.display,
+                    window: window.window,
+                })
+            }
+            #[cfg(target_family = "unix")]
+            (RawDisplayHandle::Xcb(display), RawWindowHandle::Xcb(window)) => {
+                SurfaceCreateInfo::Xcb(vk::XcbSurfaceCreateInfoKHR {
+                    s_type: vk::StructureType::XcbSurfaceCreateInfoKHR,
+                    p_next: null(),
+                    flags: vk::XcbSurfaceCreateFlagsKHR::empty(),
+                    connection: display.connection,
+                    window: window.window,
+                })
             }
             _ => {
                 bail!("Unsupported platform: display_handle={display_handle:?}, window_handle={window_handle:?}");
             }
         };
-        let surface = instance.create_win32_surface_khr(&raw const create_info)?;
+        let surface = match &create_info {
+            SurfaceCreateInfo::Win32(create_info) => {
+                instance.create_win32_surface_khr(create_info)?
+            }
+            SurfaceCreateInfo::Wayland(create_info) => {
+                instance.create_wayland_surface_khr(create_info)?
+            }
+            SurfaceCreateInfo::Xlib(create_info) => {
+                instance.create_xlib_surface_khr(create_info)?
+            }
+            SurfaceCreateInfo::Xcb(create_info) => instance.create_xcb_surface_khr(create_info)?,
+            SurfaceCreateInfo::Headless(create_info) => {
+                instance.create_headless_surface_ext(create_info)?
+            }
+        };
+
+        Self::query(instance, physical_device, surface, create_info, None)
+    }
 
+    /// Headless surfaces have no window, so the swapchain extent comes from
+    /// `extent` instead.
+    pub unsafe fn create_headless(
+        instance: &Instance,
+        physical_device: &PhysicalDevice,
+        extent: vk::Extent2D,
+    ) -> Result<Self> {
+        ensure!(
+            instance.headless_surface(),
+            "Headless surfaces require InstanceCreateInfo::headless_surface"
+        );
+
+        // Surface.
+        let create_info = vk::HeadlessSurfaceCreateInfoEXT {
+            s_type: vk::StructureType::HeadlessSurfaceCreateInfoEXT,
+            p_next: null(),
+            flags: vk::HeadlessSurfaceCreateFlagsEXT::empty(),
+        };
+        let surface = instance.create_headless_surface_ext(&raw const create_info)?;
+        let create_info = SurfaceCreateInfo::Headless(create_info);
+
+        Self::query(
+            instance,
+            physical_device,
+            surface,
+            create_info,
+            Some(extent),
+        )
+    }
+
+    unsafe fn query(
+        instance: &Instance,
+        physical_device: &PhysicalDevice,
+        surface: vk::SurfaceKHR,
+        create_info: SurfaceCreateInfo,
+        extent: Option<vk::Extent2D>,
+    ) -> Result<Self> {
         // Surface capabilities.
         let surface_capabilities = instance
             .get_physical_device_surface_capabilities_khr(physical_device.handle(), surface)?;
 
         // Surface formats.
-        let surface_formats = vulk::read_to_vec(
-            |count, ptr| {
-                instance.get_physical_device_surface_formats_khr(
-                    physical_device.handle(),
-                    surface,
-                    count,
-                    ptr,
-                )
-            },
-            None,
-        )?;
+        let surface_formats = instance
+            .get_physical_device_surface_formats_khr_to_vec(physical_device.handle(), surface)?;
         let surface_format = *surface_formats
             .iter()
             .find(|f| f.format == vk::Format::B8g8r8a8Unorm)
             .context("Finding surface format")?;
 
         // Present modes.
-        let present_modes = vulk::read_to_vec(
-            |count, ptr| {
-                instance.get_physical_device_surface_present_modes_khr(
-                    physical_device.handle(),
-                    surface,
-                    count,
-                    ptr,
-                )
-            },
-            None,
+        let present_modes = instance.get_physical_device_surface_present_modes_khr_to_vec(
+            physical_device.handle(),
+            surface,
         )?;
         let present_mode = *present_modes
             .iter()
             .find(|&&p| p == vk::PresentModeKHR::FifoKHR)
             .context("Finding present mode")?;
 
+        // Image extent.
+        let image_extent = if let Some(extent) = extent {
+            let min = surface_capabilities.min_image_extent;
+            let max = surface_capabilities.max_image_extent;
+            vk::Extent2D {
+                width: extent.width.clamp(min.width, max.width),
+                height: extent.height.clamp(min.height, max.height),
+            }
+        } else {
+            surface_capabilities.min_image_extent
+        };
+
         Ok(Self {
             surface,
             create_info,
@@ -90,21 +180,10 @@ impl Surface {
             surface_format,
             present_modes,
             present_mode,
+            image_extent,
         })
     }
 
-    #[cfg(not(target_family = "windows"))]
-    pub unsafe fn create<Window>(
-        _instance: &Instance,
-        _physical_device: &PhysicalDevice,
-        _window: &Window,
-    ) -> Result<Self>
-    where
-        Window: HasRawDisplayHandle + HasRawWindowHandle,
-    {
-        todo!("Unsupported platform");
-    }
-
     pub unsafe fn destroy(self, instance: &Instance) {
         instance.destroy_surface_khr(self.surface);
     }
@@ -18,10 +18,7 @@ impl Swapchain {
             min_image_count: surface.surface_capabilities.min_image_count,
             image_format: surface.surface_format.format,
             image_color_space: surface.surface_format.color_space,
-            image_extent: vk::Extent2D {
-                width: surface.surface_capabilities.min_image_extent.width,
-                height: surface.surface_capabilities.min_image_extent.height,
-            },
+            image_extent: surface.image_extent,
             image_array_layers: 1,
             image_usage: vk::ImageUsageFlagBits::ColorAttachment.into(),
             image_sharing_mode: vk::SharingMode::Exclusive,
@@ -39,10 +36,7 @@ impl Swapchain {
 
         // Swapchain images.
         let mut images = vec![];
-        for image in vulk::read_to_vec(
-            |count, ptr| device.get_swapchain_images_khr(swapchain, count, ptr),
-            None,
-        )? {
+        for image in device.get_swapchain_images_khr_to_vec(swapchain)? {
             let (image_view, _) =
                 ImageViewCreator::new_2d(image, surface.surface_format.format).create(device)?;
             images.push((image, image_view));
@@ -1,7 +1,6 @@
 use super::*;
 
 pub unsafe fn transfer_resources(
-    physical_device: &PhysicalDevice,
     device: &Device,
     buffers: &[BufferResource],
     buffers_bytes: &[&[u8]],
@@ -27,7 +26,6 @@ pub unsafe fn transfer_resources(
 
     // Staging buffer.
     let mut staging_buffer = vkx::BufferDedicatedTransfer::create(
-        physical_device,
         device,
         vkx::BufferCreator::new(
             staging_buffer_byte_size,
@@ -55,7 +53,7 @@ pub unsafe fn transfer_resources(
         let command_buffer_allocate_info = vk::CommandBufferAllocateInfo {
             s_type: vk::StructureType::CommandBufferAllocateInfo,
             p_next: null(),
-            command_pool: device.command_pool,
+            command_pool: device.queue().command_pool(),
             level: vk::CommandBufferLevel::Primary,
             command_buffer_count: 1,
         };
@@ -1,48 +1,175 @@
 use super::*;
 
-const TEMPLATE: &str = r#"
-{{vk_flags_attr}}
-pub struct {{rs_flags_ident}}({{rs_type}});
+const TEMPLATE_PARSE_ERROR: &str = r#"#[doc = "Error returned when parsing flags that contain an unknown flag bit."]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseFlagsError(String);
 
-impl {{rs_flags_ident}} {
+impl std::fmt::Display for ParseFlagsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown flag bit {:?}", self.0)
+    }
+}
+
+impl std::error::Error for ParseFlagsError {}
+"#;
+
+const TEMPLATE_FLAGS_API: &str = r#"
     #[must_use]
     pub const fn empty() -> Self {
         Self(0)
     }
 
+    #[doc = "All known flag bits."]
+    #[must_use]
+    pub const fn all() -> Self {
+        Self({{rs_all_bits}})
+    }
+
+    #[doc = "Returns `None` if `bits` contains unknown bits."]
+    #[must_use]
+    pub const fn from_bits(bits: {{rs_type}}) -> Option<Self> {
+        if bits & !Self::all().0 == 0 {
+            Some(Self(bits))
+        } else {
+            None
+        }
+    }
+
+    #[doc = "Drops unknown bits."]
+    #[must_use]
+    pub const fn from_bits_truncate(bits: {{rs_type}}) -> Self {
+        Self(bits & Self::all().0)
+    }
+
+    #[must_use]
+    pub const fn bits(self) -> {{rs_type}} {
+        self.0
+    }
+
+    #[must_use]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
     #[must_use]
     pub fn contains(self, rhs: impl Into<Self> + Copy) -> bool {
         let rhs = rhs.into();
         self.0 & rhs.0 == rhs.0
     }
+
+    #[must_use]
+    pub fn intersects(self, rhs: impl Into<Self>) -> bool {
+        self.0 & rhs.into().0 != 0
+    }
+
+    pub fn insert(&mut self, rhs: impl Into<Self>) {
+        self.0 |= rhs.into().0;
+    }
+
+    pub fn remove(&mut self, rhs: impl Into<Self>) {
+        self.0 &= !rhs.into().0;
+    }
+
+    pub fn toggle(&mut self, rhs: impl Into<Self>) {
+        self.0 ^= rhs.into().0;
+    }
+
+    pub fn set(&mut self, rhs: impl Into<Self>, value: bool) {
+        if value {
+            self.insert(rhs);
+        } else {
+            self.remove(rhs);
+        }
+    }
+"#;
+
+const TEMPLATE_FLAGS_OPS: &str = r#"
+impl<Rhs: Into<Self>> std::ops::BitAnd<Rhs> for {{rs_flags_ident}} {
+    type Output = Self;
+    fn bitand(self, rhs: Rhs) -> Self::Output {
+        Self(self.0 & rhs.into().0)
+    }
 }
 
-impl std::ops::BitAnd for {{rs_flags_ident}} {
+impl<Rhs: Into<Self>> std::ops::BitOr<Rhs> for {{rs_flags_ident}} {
     type Output = Self;
-    fn bitand(self, rhs: Self) -> Self::Output {
-        Self(self.0 & rhs.0)
+    fn bitor(self, rhs: Rhs) -> Self::Output {
+        Self(self.0 | rhs.into().0)
     }
 }
 
-impl From<{{rs_flag_bits_ident}}> for {{rs_flags_ident}} {
-    fn from(flag_bits: {{rs_flag_bits_ident}}) -> Self {
-        Self(flag_bits as {{rs_type}})
+impl<Rhs: Into<Self>> std::ops::BitXor<Rhs> for {{rs_flags_ident}} {
+    type Output = Self;
+    fn bitxor(self, rhs: Rhs) -> Self::Output {
+        Self(self.0 ^ rhs.into().0)
     }
 }
 
-impl std::ops::BitOr<{{rs_flag_bits_ident}}> for {{rs_flags_ident}} {
-    type Output = {{rs_flags_ident}};
-    fn bitor(self, rhs: {{rs_flag_bits_ident}}) -> Self::Output {
-        Self(self.0 | rhs as {{rs_type}})
+impl<Rhs: Into<Self>> std::ops::BitAndAssign<Rhs> for {{rs_flags_ident}} {
+    fn bitand_assign(&mut self, rhs: Rhs) {
+        self.0 &= rhs.into().0;
+    }
+}
+
+impl<Rhs: Into<Self>> std::ops::BitOrAssign<Rhs> for {{rs_flags_ident}} {
+    fn bitor_assign(&mut self, rhs: Rhs) {
+        self.0 |= rhs.into().0;
+    }
+}
+
+impl<Rhs: Into<Self>> std::ops::BitXorAssign<Rhs> for {{rs_flags_ident}} {
+    fn bitxor_assign(&mut self, rhs: Rhs) {
+        self.0 ^= rhs.into().0;
+    }
+}
+
+#[doc = "Flips every known bit, unknown bits are cleared."]
+impl std::ops::Not for {{rs_flags_ident}} {
+    type Output = Self;
+    fn not(self) -> Self::Output {
+        Self(!self.0 & Self::all().0)
+    }
+}
+"#;
+
+const TEMPLATE: &str = r#"
+{{vk_flags_attr}}
+pub struct {{rs_flags_ident}}({{rs_type}});
+
+impl {{rs_flags_ident}} {
+    #[allow(deprecated)]
+    const FLAG_BITS: &'static [{{rs_flag_bits_ident}}] = &[
+        {{rs_flag_bits_idents}}
+    ];
+
+{{rs_flags_api}}
+    #[doc = "Iterates over the flag bits whose bits are all set, in declaration order."]
+    pub fn iter(self) -> impl Iterator<Item = {{rs_flag_bits_ident}}> {
+        Self::FLAG_BITS.iter().copied().filter(move |&flag_bit| {
+            let flag_bit = flag_bit.0;
+            flag_bit != 0 && self.0 & flag_bit == flag_bit
+        })
+    }
+}
+
+impl From<{{rs_flag_bits_ident}}> for {{rs_flags_ident}} {
+    fn from(flag_bits: {{rs_flag_bits_ident}}) -> Self {
+        Self(flag_bits.0)
     }
 }
 
+{{rs_flags_ops}}
 impl std::fmt::Display for {{rs_flags_ident}} {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        #[allow(deprecated)]
-        display_flag_bits_{{rs_type}}(f, self.0, &[
-            {{rs_flag_bits_idents}}
-        ])
+        display_flag_bits_{{rs_type}}(f, self.0, Self::FLAG_BITS)
+    }
+}
+
+#[doc = "Parses the `A | B` format produced by [`Display`](std::fmt::Display), including unknown bits written as `0b...`."]
+impl std::str::FromStr for {{rs_flags_ident}} {
+    type Err = ParseFlagsError;
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        parse_flag_bits_{{rs_type}}(s, Self::FLAG_BITS).map(Self)
     }
 }
 
@@ -55,30 +182,47 @@ impl std::fmt::Debug for {{rs_flags_ident}} {
 }
 
 {{vk_flag_bits_attr}}
-pub enum {{rs_flag_bits_ident}} {
+pub struct {{rs_flag_bits_ident}}({{rs_type}});
+
+#[allow(non_upper_case_globals)]
+impl {{rs_flag_bits_ident}} {
     {{rs_flag_bits_members}}
 }
 
+impl {{rs_flag_bits_ident}} {
+    #[must_use]
+    pub const fn from_raw(value: {{rs_type}}) -> Self {
+        Self(value)
+    }
+
+    #[must_use]
+    pub const fn as_raw(self) -> {{rs_type}} {
+        self.0
+    }
+}
+
 impl From<{{rs_flag_bits_ident}}> for {{rs_type}} {
     fn from(flag_bits: {{rs_flag_bits_ident}}) -> Self {
-        flag_bits as {{rs_type}}
+        flag_bits.0
     }
 }
 
 impl std::ops::BitOr for {{rs_flag_bits_ident}} {
     type Output = {{rs_flags_ident}};
     fn bitor(self, rhs: Self) -> Self::Output {
-        {{rs_flags_ident}}(self as {{rs_type}} | rhs as {{rs_type}})
+        {{rs_flags_ident}}(self.0 | rhs.0)
     }
 }
 
 impl std::ops::BitOr<{{rs_flags_ident}}> for {{rs_flag_bits_ident}} {
     type Output = {{rs_flags_ident}};
     fn bitor(self, rhs: {{rs_flags_ident}}) -> Self::Output {
-        {{rs_flags_ident}}(self as {{rs_type}} | rhs.0)
+        {{rs_flags_ident}}(self.0 | rhs.0)
     }
 }
 
+{{rs_flag_bits_debug}}
+
 impl std::fmt::Display for {{rs_flag_bits_ident}} {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{self:?}")
@@ -86,20 +230,47 @@ impl std::fmt::Display for {{rs_flag_bits_ident}} {
 }
 "#;
 
+const TEMPLATE_FLAG_BITS_MEMBER: &str =
+    r#"pub const {{rs_member_ident}}: Self = Self({{rs_member_value}});"#;
+
+const TEMPLATE_FLAG_BITS_DEBUG: &str = r#"
+impl std::fmt::Debug for {{rs_flag_bits_ident}} {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        #[allow(deprecated)]
+        let name = match *self {
+            {{rs_flag_bits_debug_matches}}
+            _ => return write!(f, "{{rs_flag_bits_ident}}(0b{:b})", self.0),
+        };
+        f.write_str(name)
+    }
+}
+"#;
+
+const TEMPLATE_FLAG_BITS_DEBUG_RAW: &str = r#"
+impl std::fmt::Debug for {{rs_flag_bits_ident}} {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{rs_flag_bits_ident}}(0b{:b})", self.0)
+    }
+}
+"#;
+
+const TEMPLATE_FLAG_BITS_DEBUG_MATCH: &str =
+    r#"Self::{{rs_member_ident}} => "{{rs_member_ident}}","#;
+
 const TEMPLATE_NO_FLAG_BITS: &str = r#"
 {{vk_flags_attr}}
 pub struct {{rs_flags_ident}}({{rs_type}});
 
 impl {{rs_flags_ident}} {
-    #[must_use]
-    pub const fn empty() -> Self {
-        Self(0)
-    }
+{{rs_flags_api}}
 }
+
+{{rs_flags_ops}}
 "#;
 
 pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
     let mut str = String::new();
+    writeln!(str, "{TEMPLATE_PARSE_ERROR}")?;
 
     let bitvalues_map = {
         let mut map = HashMap::new();
@@ -154,8 +325,8 @@ pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
         if let Some(bitvalues) = bitvalues {
             let vk_flag_bits_ident = bitvalues;
             let vk_flag_bits_attr = attributes::Builder::new()
-                .repr(rs_type)
-                .derive("Clone, Copy, PartialEq, Eq, Debug")
+                .repr("transparent")
+                .derive("Clone, Copy, PartialEq, Eq, Hash")
                 .doc_chapter(ctx.vkspec.type_chapter(vk_flag_bits_ident))
                 .doc_br()
                 .doc_desc(ctx.vkspec.type_desc(vk_flag_bits_ident))
@@ -194,18 +365,23 @@ pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
 
             let mut rs_flag_bits_members = String::new();
             let mut rs_flag_bits_idents = String::new();
+            let mut rs_flag_bits_debug_matches = String::new();
+            let mut all_bits = 0_u64;
             for (member, rs_member_ident) in members.iter().zip(&member_idents) {
                 let vk_member_ident = &member.name;
 
                 let rs_member_value = if let Some(bitpos) = &member.bitpos {
                     let bitpos: u64 = bitpos.parse()?;
+                    all_bits |= 1_u64 << bitpos;
                     format!("0b{:0b}", 1_u64 << bitpos)
                 } else if let Some(value) = &member.value {
                     if let Some(value) = value.strip_prefix("0x") {
                         let value = u64::from_str_radix(value, 16)?;
+                        all_bits |= value;
                         format!("0x{value:0x}")
                     } else {
                         let value: u64 = value.parse()?;
+                        all_bits |= value;
                         format!("{value}")
                     }
                 } else {
@@ -224,34 +400,49 @@ pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
                 }
                 writeln!(
                     rs_flag_bits_members,
-                    "{rs_member_ident} = {rs_member_value},"
+                    "{}",
+                    TEMPLATE_FLAG_BITS_MEMBER
+                        .replace("{{rs_member_ident}}", rs_member_ident)
+                        .replace("{{rs_member_value}}", &rs_member_value)
+                )?;
+                writeln!(
+                    rs_flag_bits_debug_matches,
+                    "{}",
+                    TEMPLATE_FLAG_BITS_DEBUG_MATCH.replace("{{rs_member_ident}}", rs_member_ident)
                 )?;
                 writeln!(
                     rs_flag_bits_idents,
                     "{rs_flag_bits_ident}::{rs_member_ident},",
                 )?;
             }
-            let mut rs_flag_bits_members = rs_flag_bits_members.trim_end().to_string();
-            let mut rs_flag_bits_idents = rs_flag_bits_idents.trim_end().to_string();
-
-            // Special: Rust enums must have at least one variant.
-            if member_idents.is_empty() {
-                use std::fmt::Write as _;
-                write!(rs_flag_bits_members, "Placeholder = 0b0,")?;
-                write!(rs_flag_bits_idents, "{rs_flag_bits_ident}::Placeholder,",)?;
-            }
+            let rs_flag_bits_members = rs_flag_bits_members.trim_end();
+            let rs_flag_bits_idents = rs_flag_bits_idents.trim_end();
+
+            // Special: flag bits without members have no names to match.
+            let rs_flag_bits_debug = if member_idents.is_empty() {
+                TEMPLATE_FLAG_BITS_DEBUG_RAW.to_string()
+            } else {
+                TEMPLATE_FLAG_BITS_DEBUG.replace(
+                    "{{rs_flag_bits_debug_matches}}",
+                    &rs_flag_bits_debug_matches,
+                )
+            };
 
             writeln!(
                 str,
                 "{}",
                 TEMPLATE
+                    .replace("{{rs_flags_api}}", TEMPLATE_FLAGS_API)
+                    .replace("{{rs_flags_ops}}", TEMPLATE_FLAGS_OPS)
+                    .replace("{{rs_flag_bits_debug}}", &rs_flag_bits_debug)
                     .replace("{{vk_flag_bits_attr}}", &vk_flag_bits_attr)
                     .replace("{{vk_flags_attr}}", &vk_flags_attr)
                     .replace("{{rs_type}}", rs_type)
                     .replace("{{rs_flag_bits_ident}}", &rs_flag_bits_ident)
                     .replace("{{rs_flags_ident}}", &rs_flags_ident)
-                    .replace("{{rs_flag_bits_members}}", &rs_flag_bits_members)
-                    .replace("{{rs_flag_bits_idents}}", &rs_flag_bits_idents)
+                    .replace("{{rs_flag_bits_members}}", rs_flag_bits_members)
+                    .replace("{{rs_flag_bits_idents}}", rs_flag_bits_idents)
+                    .replace("{{rs_all_bits}}", &format!("0b{all_bits:b}"))
             )?;
         } else {
             let vk_flags_attr = attributes::Builder::new()
@@ -270,9 +461,12 @@ pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
                 str,
                 "{}",
                 TEMPLATE_NO_FLAG_BITS
+                    .replace("{{rs_flags_api}}", TEMPLATE_FLAGS_API)
+                    .replace("{{rs_flags_ops}}", TEMPLATE_FLAGS_OPS)
                     .replace("{{vk_flags_attr}}", &vk_flags_attr)
                     .replace("{{rs_flags_ident}}", &rs_flags_ident)
                     .replace("{{rs_type}}", rs_type)
+                    .replace("{{rs_all_bits}}", "0")
             )?;
         }
     }
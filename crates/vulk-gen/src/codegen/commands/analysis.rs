@@ -140,3 +140,18 @@ pub fn wrapper_type(
 
     Ok(wrapper_type)
 }
+
+/// Commands ending in a `uint32_t*` count and an array whose `len` refers to
+/// that count follow the two-call enumeration idiom.
+pub fn enumerate_params(
+    command: &registry::Command,
+) -> Option<(&registry::CommandParam, &registry::CommandParam)> {
+    let [.., count_param, array_param] = command.params.as_slice() else {
+        return None;
+    };
+    let is_count = count_param.ty == "uint32_t" && count_param.text.as_deref() == Some("*");
+    let is_array = array_param.ty != "void"
+        && array_param.text.as_deref() == Some("*")
+        && array_param.len.as_deref() == Some(count_param.name.as_str());
+    (is_count && is_array).then_some((count_param, array_param))
+}
@@ -3,6 +3,8 @@ use super::*;
 const TEMPLATE_STRUCT_MEMBER: &str = r#"pub {{rs_ident}}: Option<vk::{{rs_type}}>,"#;
 const TEMPLATE_LOADER: &str =
     r#"{{rs_ident}}: load(c"{{vk_ident}}").map(|f| std::mem::transmute(f)),"#;
+const TEMPLATE_IS_LOADED: &str = r#""{{vk_ident}}" => self.fns.{{rs_ident}}.is_some(),"#;
+const TEMPLATE_LOADED: &str = r#"("{{vk_ident}}", self.fns.{{rs_ident}}.is_some()),"#;
 
 pub struct Rendered {
     pub init_struct_members: String,
@@ -11,6 +13,12 @@ pub struct Rendered {
     pub init_loaders: String,
     pub instance_loaders: String,
     pub device_loaders: String,
+    pub init_is_loaded: String,
+    pub instance_is_loaded: String,
+    pub device_is_loaded: String,
+    pub init_loaded: String,
+    pub instance_loaded: String,
+    pub device_loaded: String,
 }
 
 pub fn generate(ctx: &GeneratorContext<'_>, groups: &analysis::CommandGroups) -> Result<Rendered> {
@@ -20,6 +28,12 @@ pub fn generate(ctx: &GeneratorContext<'_>, groups: &analysis::CommandGroups) ->
     let init_loaders = generate_loaders(ctx, &groups.init)?;
     let instance_loaders = generate_loaders(ctx, &groups.instance)?;
     let device_loaders = generate_loaders(ctx, &groups.device)?;
+    let init_is_loaded = generate_queries(ctx, &groups.init, TEMPLATE_IS_LOADED)?;
+    let instance_is_loaded = generate_queries(ctx, &groups.instance, TEMPLATE_IS_LOADED)?;
+    let device_is_loaded = generate_queries(ctx, &groups.device, TEMPLATE_IS_LOADED)?;
+    let init_loaded = generate_queries(ctx, &groups.init, TEMPLATE_LOADED)?;
+    let instance_loaded = generate_queries(ctx, &groups.instance, TEMPLATE_LOADED)?;
+    let device_loaded = generate_queries(ctx, &groups.device, TEMPLATE_LOADED)?;
     Ok(Rendered {
         init_struct_members,
         instance_struct_members,
@@ -27,6 +41,12 @@ pub fn generate(ctx: &GeneratorContext<'_>, groups: &analysis::CommandGroups) ->
         init_loaders,
         instance_loaders,
         device_loaders,
+        init_is_loaded,
+        instance_is_loaded,
+        device_is_loaded,
+        init_loaded,
+        instance_loaded,
+        device_loaded,
     })
 }
 
@@ -74,3 +94,26 @@ fn generate_loaders(
 
     Ok(str)
 }
+
+fn generate_queries(
+    _ctx: &GeneratorContext<'_>,
+    commands: &[&registry::Command],
+    template: &str,
+) -> Result<String> {
+    let mut str = String::new();
+
+    for command in commands {
+        let vk_ident = &command.name;
+        let rs_ident = translation::vk_simple_function(vk_ident)?;
+        let rs_ident = translation::vk_simple_ident(&rs_ident)?;
+        writeln!(
+            str,
+            "{}",
+            template
+                .replace("{{rs_ident}}", &rs_ident)
+                .replace("{{vk_ident}}", vk_ident)
+        )?;
+    }
+
+    Ok(str)
+}
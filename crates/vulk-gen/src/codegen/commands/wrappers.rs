@@ -9,38 +9,92 @@ const TEMPLATE_PARAM: &str = r#"{{rs_param_ident}}: {{rs_param_type}}"#;
 const TEMPLATE_PARAM_IDENT: &str = r#"{{rs_param_ident}}"#;
 const TEMPLATE_IDENTITY: &str = r#"{{vk_attr}}
 pub unsafe fn {{rs_ident}}(&self, {{rs_params}}) -> {{rs_return_type}} {
-    (self.fns.{{rs_ident}}.unwrap_unchecked())({{rs_params_idents}})
+    {{rs_checks}}(load_fn(self.fns.{{rs_ident}}, "{{vk_ident}}"))({{rs_params_idents}})
 }
 "#;
 const TEMPLATE_IDENTITY_VOID: &str = r#"{{vk_attr}}
 pub unsafe fn {{rs_ident}}(&self, {{rs_params}}) {
-    (self.fns.{{rs_ident}}.unwrap_unchecked())({{rs_params_idents}});
+    {{rs_checks}}(load_fn(self.fns.{{rs_ident}}, "{{vk_ident}}"))({{rs_params_idents}});
 }
 "#;
 const TEMPLATE_UNIT_RESULT: &str = r#"{{vk_attr}}
 pub unsafe fn {{rs_ident}}(&self, {{rs_params}}) -> Result<(), Error> {
-    match (self.fns.{{rs_ident}}.unwrap_unchecked())({{rs_params_idents}}) {
+    {{rs_checks}}match (try_load_fn(self.fns.{{rs_ident}}, "{{vk_ident}}")?)({{rs_params_idents}}) {
         vk::Result::Success => Ok(()),
-        result => Err(Error::Vulkan(result)),
+        result => Err(Error::Vulkan { result, command: "{{vk_ident}}", create_info: {{vk_create_info}} }),
     }
 }
 "#;
 const TEMPLATE_OUTPUT_RESULT: &str = r#"{{vk_attr}}
 pub unsafe fn {{rs_ident}}(&self, {{rs_params}}) -> Result<{{rs_output_type}}, Error> {
-    let mut {{rs_output_ident}} = std::mem::MaybeUninit::uninit();
-    match (self.fns.{{rs_ident}}.unwrap_unchecked())({{rs_params_idents}}, {{rs_output_ident}}.as_mut_ptr()) {
+    {{rs_checks}}let mut {{rs_output_ident}} = std::mem::MaybeUninit::uninit();
+    match (try_load_fn(self.fns.{{rs_ident}}, "{{vk_ident}}")?)({{rs_params_idents}}{{rs_output_ident}}.as_mut_ptr()) {
         vk::Result::Success => Ok({{rs_output_ident}}.assume_init()),
-        result => Err(Error::Vulkan(result)),
+        result => Err(Error::Vulkan { result, command: "{{vk_ident}}", create_info: {{vk_create_info}} }),
+    }
+}
+"#;
+const TEMPLATE_UNIT_RESULT_CODES: &str = r#"{{vk_attr}}
+pub unsafe fn {{rs_ident}}(&self, {{rs_params}}) -> Result<vk::Result, Error> {
+    {{rs_checks}}match (try_load_fn(self.fns.{{rs_ident}}, "{{vk_ident}}")?)({{rs_params_idents}}) {
+        result @ ({{rs_success_codes}}) => Ok(result),
+        result => Err(Error::Vulkan { result, command: "{{vk_ident}}", create_info: {{vk_create_info}} }),
+    }
+}
+"#;
+const TEMPLATE_OUTPUT_RESULT_CODES: &str = r#"{{vk_attr}}
+pub unsafe fn {{rs_ident}}(&self, {{rs_params}}) -> Result<({{rs_output_type}}, vk::Result), Error> {
+    {{rs_checks}}let mut {{rs_output_ident}} = std::mem::MaybeUninit::zeroed();
+    match (try_load_fn(self.fns.{{rs_ident}}, "{{vk_ident}}")?)({{rs_params_idents}}{{rs_output_ident}}.as_mut_ptr()) {
+        result @ ({{rs_success_codes}}) => Ok(({{rs_output_ident}}.assume_init(), result)),
+        result => Err(Error::Vulkan { result, command: "{{vk_ident}}", create_info: {{vk_create_info}} }),
     }
 }
 "#;
 const TEMPLATE_OUTPUT: &str = r#"{{vk_attr}}
 pub unsafe fn {{rs_ident}}(&self, {{rs_params}}) -> {{rs_output_type}} {
-    let mut {{rs_output_ident}} = std::mem::MaybeUninit::uninit();
-    (self.fns.{{rs_ident}}.unwrap_unchecked())({{rs_params_idents}}, {{rs_output_ident}}.as_mut_ptr());
+    {{rs_checks}}let mut {{rs_output_ident}} = std::mem::MaybeUninit::uninit();
+    (load_fn(self.fns.{{rs_ident}}, "{{vk_ident}}"))({{rs_params_idents}}{{rs_output_ident}}.as_mut_ptr());
     {{rs_output_ident}}.assume_init()
 }
 "#;
+const TEMPLATE_ENUMERATE_RESULT: &str = r#"{{vk_attr}}
+pub unsafe fn {{rs_ident}}_to_vec(&self, {{rs_params}}) -> Result<Vec<{{rs_element_type}}>, Error> {
+    loop {
+        let mut {{rs_count_ident}} = 0;
+        match (try_load_fn(self.fns.{{rs_ident}}, "{{vk_ident}}")?)({{rs_params_idents_query}}) {
+            vk::Result::Success => {}
+            result => return Err(Error::Vulkan { result, command: "{{vk_ident}}", create_info: {{vk_create_info}} }),
+        }
+        let mut {{rs_array_ident}} = Vec::with_capacity({{rs_count_ident}} as usize);{{rs_fill}}
+        match (try_load_fn(self.fns.{{rs_ident}}, "{{vk_ident}}")?)({{rs_params_idents_read}}) {
+            vk::Result::Success => {
+                {{rs_array_ident}}.set_len({{rs_count_ident}} as usize);
+                return Ok({{rs_array_ident}});
+            }
+            vk::Result::Incomplete => {}
+            result => return Err(Error::Vulkan { result, command: "{{vk_ident}}", create_info: {{vk_create_info}} }),
+        }
+    }
+}
+"#;
+const TEMPLATE_ENUMERATE: &str = r#"{{vk_attr}}
+pub unsafe fn {{rs_ident}}_to_vec(&self, {{rs_params}}) -> Vec<{{rs_element_type}}> {
+    let mut {{rs_count_ident}} = 0;
+    (load_fn(self.fns.{{rs_ident}}, "{{vk_ident}}"))({{rs_params_idents_query}});
+    let mut {{rs_array_ident}} = Vec::with_capacity({{rs_count_ident}} as usize);{{rs_fill}}
+    (load_fn(self.fns.{{rs_ident}}, "{{vk_ident}}"))({{rs_params_idents_read}});
+    {{rs_array_ident}}.set_len({{rs_count_ident}} as usize);
+    {{rs_array_ident}}
+}
+"#;
+const TEMPLATE_ENUMERATE_FILL: &str = r#"
+{{rs_array_ident}}.resize_with({{rs_count_ident}} as usize, {{rs_element_type}}::new);"#;
+const TEMPLATE_SLICE: &str = r#"{{vk_attr}}
+pub unsafe fn {{rs_ident}}_slice(&self, {{rs_params}}){{rs_return_type}} {
+    {{rs_asserts}}self.{{rs_ident}}({{rs_args}}){{rs_terminator}}
+}
+"#;
 
 pub struct Rendered {
     pub init_wrappers: String,
@@ -73,8 +127,35 @@ pub fn generate(ctx: &GeneratorContext<'_>, groups: &analysis::CommandGroups) ->
         }
     }
 
+    // Map every `VkResult` member, including aliases, to its Rust constant.
+    let mut result_map = HashMap::new();
+    for registry_enum in &ctx.registry.enums {
+        if registry_enum.name != "VkResult" {
+            continue;
+        }
+        let vk_member_idents = registry_enum
+            .members
+            .iter()
+            .map(|member| member.name.as_str())
+            .collect::<Vec<_>>();
+        let rs_member_idents = translation::vk_enum(&registry_enum.name, &vk_member_idents)?;
+        for (member, rs_member_ident) in registry_enum.members.iter().zip(rs_member_idents) {
+            result_map.insert(member.name.as_str(), rs_member_ident);
+        }
+        for member in &registry_enum.members {
+            if let Some(alias) = &member.alias {
+                let rs_member_ident = result_map
+                    .get(alias.as_str())
+                    .with_context(|| format!("Unknown alias={alias}"))?
+                    .clone();
+                result_map.insert(member.name.as_str(), rs_member_ident);
+            }
+        }
+    }
+
     let init_wrappers = generate_wrappers(
         ctx,
+        &result_map,
         &base_type_map,
         &handle_map,
         &s_type_map,
@@ -83,6 +164,7 @@ pub fn generate(ctx: &GeneratorContext<'_>, groups: &analysis::CommandGroups) ->
     )?;
     let instance_wrappers = generate_wrappers(
         ctx,
+        &result_map,
         &base_type_map,
         &handle_map,
         &s_type_map,
@@ -91,6 +173,7 @@ pub fn generate(ctx: &GeneratorContext<'_>, groups: &analysis::CommandGroups) ->
     )?;
     let device_wrappers = generate_wrappers(
         ctx,
+        &result_map,
         &base_type_map,
         &handle_map,
         &s_type_map,
@@ -107,12 +190,14 @@ pub fn generate(ctx: &GeneratorContext<'_>, groups: &analysis::CommandGroups) ->
 
 fn generate_wrappers(
     ctx: &GeneratorContext<'_>,
+    result_map: &HashMap<&str, String>,
     base_type_map: &HashSet<&str>,
     handle_map: &HashSet<&str>,
     s_type_map: &HashSet<&str>,
     commands: &[&registry::Command],
     can_inline_handles: bool,
 ) -> Result<String> {
+    let valid_usage = valid_usage::ValidUsage::new(ctx)?;
     let mut str = String::new();
 
     for command in commands {
@@ -133,6 +218,33 @@ fn generate_wrappers(
         let rs_return_type =
             translation::vk_complex_type(ctx.c_type_map, vk_return_type, None, None, true)?;
 
+        // Success codes other than `VK_SUCCESS` are returned to the caller
+        // instead of being treated as errors.
+        let rs_success_codes = if command.successcodes.iter().any(|code| code != "VK_SUCCESS") {
+            let rs_success_codes = command
+                .successcodes
+                .iter()
+                .map(|code| {
+                    result_map
+                        .get(code.as_str())
+                        .map(|rs_code| format!("vk::Result::{rs_code}"))
+                        .with_context(|| format!("Unknown success code={code}"))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Some(rs_success_codes.join(" | "))
+        } else {
+            None
+        };
+
+        // Create and allocate commands also report their create info type.
+        let vk_create_info = command
+            .params
+            .iter()
+            .find(|param| param.ty.contains("CreateInfo") || param.ty.contains("AllocateInfo"))
+            .map_or("None".to_string(), |param| {
+                format!(r#"Some("{}")"#, param.ty)
+            });
+
         let inline_handles = {
             let first_type = &command.params[0].ty;
             let function = &command.name;
@@ -150,10 +262,11 @@ fn generate_wrappers(
             }
         };
 
-        let (rs_params, vk_params_types, rs_params_idents) = {
+        let (rs_params, vk_params_types, rs_params_idents, visible_params) = {
             let mut rs_params = vec![];
             let mut vk_params_types = vec![];
             let mut rs_params_idents = vec![];
+            let mut visible_params = vec![];
             for param in &command.params {
                 let vk_param_ident = &param.name;
                 let rs_param_ident = translation::vk_simple_ident(vk_param_ident)?;
@@ -180,6 +293,7 @@ fn generate_wrappers(
                     );
                     rs_params_idents
                         .push(TEMPLATE_PARAM_IDENT.replace("{{rs_param_ident}}", &rs_param_ident));
+                    visible_params.push(param);
                 }
             }
             if inline_handles {
@@ -187,8 +301,82 @@ fn generate_wrappers(
                 vk_params_types.remove(0);
                 rs_params_idents.remove(0);
                 rs_params_idents.insert(0, "self.handle".to_string());
+                visible_params.remove(0);
             }
-            (rs_params, vk_params_types, rs_params_idents)
+            (rs_params, vk_params_types, rs_params_idents, visible_params)
+        };
+
+        // Debug builds check the implicit valid usage of the parameters.
+        let rs_checks = valid_usage.command(command, &visible_params)?;
+
+        // Enumerations hide the count and array parameters.
+        let rs_enumerate = if let Some((count_param, array_param)) =
+            analysis::enumerate_params(command)
+        {
+            let rs_params = rs_params[..rs_params.len() - 2].join(",");
+            let rs_params_idents = &rs_params_idents[..rs_params_idents.len() - 2];
+            let rs_count_ident = translation::vk_simple_ident(&count_param.name)?;
+            let rs_count_ident = rs_count_ident
+                .strip_prefix("p_")
+                .unwrap_or(&rs_count_ident)
+                .to_string();
+            let rs_array_ident = translation::vk_simple_ident(&array_param.name)?;
+            let rs_array_ident = rs_array_ident
+                .strip_prefix("p_")
+                .unwrap_or(&rs_array_ident)
+                .to_string();
+            let rs_params_idents_query = rs_params_idents
+                .iter()
+                .cloned()
+                .chain([
+                    format!("&raw mut {rs_count_ident}"),
+                    "std::ptr::null_mut()".to_string(),
+                ])
+                .collect::<Vec<_>>()
+                .join(",");
+            let rs_params_idents_read = rs_params_idents
+                .iter()
+                .cloned()
+                .chain([
+                    format!("&raw mut {rs_count_ident}"),
+                    format!("{rs_array_ident}.as_mut_ptr()"),
+                ])
+                .collect::<Vec<_>>()
+                .join(",");
+            let rs_element_type =
+                translation::vk_complex_type(ctx.c_type_map, &array_param.ty, None, None, true)?;
+
+            // Output structures must have their `sType` set before the call.
+            let rs_fill = if s_type_map.contains(array_param.ty.as_str()) {
+                TEMPLATE_ENUMERATE_FILL.to_string()
+            } else {
+                String::new()
+            };
+
+            let (template, vk_attr) = match command.return_type.as_str() {
+                "VkResult" => (TEMPLATE_ENUMERATE_RESULT, vk_attr.clone()),
+                "void" => (
+                    TEMPLATE_ENUMERATE,
+                    attributes::Builder::new().must_use().raw(&vk_attr).build(),
+                ),
+                return_type => bail!("Unsupported enumerate return type={return_type}"),
+            };
+            Some(
+                template
+                    .replace("{{rs_fill}}", &rs_fill)
+                    .replace("{{vk_ident}}", vk_ident)
+                    .replace("{{vk_create_info}}", &vk_create_info)
+                    .replace("{{vk_attr}}", &vk_attr)
+                    .replace("{{rs_ident}}", &rs_ident)
+                    .replace("{{rs_params}}", &rs_params)
+                    .replace("{{rs_params_idents_query}}", &rs_params_idents_query)
+                    .replace("{{rs_params_idents_read}}", &rs_params_idents_read)
+                    .replace("{{rs_element_type}}", &rs_element_type)
+                    .replace("{{rs_count_ident}}", &rs_count_ident)
+                    .replace("{{rs_array_ident}}", &rs_array_ident),
+            )
+        } else {
+            None
         };
 
         let rs_params_lhs = if let Some((_, rs_params_lhs)) = rs_params.split_last() {
@@ -198,7 +386,12 @@ fn generate_wrappers(
         };
         let rs_params = rs_params.join(",");
         let (rs_params_idents_last, rs_params_idents_lhs) = rs_params_idents.split_last().unwrap();
-        let rs_params_idents_lhs = rs_params_idents_lhs.join(",");
+        // Output parameters may be the only parameter, so every leading ident
+        // carries its own separator.
+        let rs_params_idents_lhs = rs_params_idents_lhs
+            .iter()
+            .flat_map(|ident| [ident.as_str(), ", "])
+            .collect::<String>();
         let rs_params_idents = rs_params_idents.join(",");
         let vk_params_type_last =
             if let Some((vk_params_type_last, _)) = vk_params_types.split_last() {
@@ -207,21 +400,73 @@ fn generate_wrappers(
                 None
             };
 
-        match analysis::wrapper_type(
+        let wrapper_type = analysis::wrapper_type(
             ctx.c_type_map,
             base_type_map,
             handle_map,
             s_type_map,
             command,
-        )? {
+        )?;
+
+        // Slice wrappers forward to the wrapper generated below, so they share
+        // its visible parameters and return type.
+        let rs_slice = {
+            let (rs_return_type, must_use) = match wrapper_type {
+                analysis::WrapperType::Identity => (Some(rs_return_type.clone()), true),
+                analysis::WrapperType::IdentityVoid => (None, false),
+                analysis::WrapperType::UnitResult => {
+                    if rs_success_codes.is_some() {
+                        (Some("Result<vk::Result, Error>".to_string()), false)
+                    } else {
+                        (Some("Result<(), Error>".to_string()), false)
+                    }
+                }
+                analysis::WrapperType::OutputResult => {
+                    let rs_output_type = output_type(ctx, vk_params_type_last.unwrap())?;
+                    if rs_success_codes.is_some() {
+                        (
+                            Some(format!("Result<({rs_output_type}, vk::Result), Error>")),
+                            false,
+                        )
+                    } else {
+                        (Some(format!("Result<{rs_output_type}, Error>")), false)
+                    }
+                }
+                analysis::WrapperType::Output => {
+                    (Some(output_type(ctx, vk_params_type_last.unwrap())?), true)
+                }
+            };
+            let visible_params = match wrapper_type {
+                analysis::WrapperType::OutputResult | analysis::WrapperType::Output => {
+                    &visible_params[..visible_params.len() - 1]
+                }
+                _ => &visible_params[..],
+            };
+            let vk_attr = if must_use {
+                attributes::Builder::new().must_use().raw(&vk_attr).build()
+            } else {
+                vk_attr.clone()
+            };
+            generate_slice_wrapper(
+                ctx,
+                &vk_attr,
+                &rs_ident,
+                visible_params,
+                rs_return_type.as_deref(),
+            )?
+        };
+
+        match wrapper_type {
             analysis::WrapperType::Identity => {
                 let vk_attr = attributes::Builder::new().must_use().raw(vk_attr).build();
                 writeln!(
                     str,
                     "{}",
                     TEMPLATE_IDENTITY
+                        .replace("{{rs_checks}}", &rs_checks)
                         .replace("{{vk_attr}}", &vk_attr)
                         .replace("{{vk_ident}}", vk_ident)
+                        .replace("{{vk_create_info}}", &vk_create_info)
                         .replace("{{rs_ident}}", &rs_ident)
                         .replace("{{rs_params}}", &rs_params)
                         .replace("{{rs_params_idents}}", &rs_params_idents)
@@ -233,67 +478,93 @@ fn generate_wrappers(
                     str,
                     "{}",
                     TEMPLATE_IDENTITY_VOID
+                        .replace("{{rs_checks}}", &rs_checks)
                         .replace("{{vk_attr}}", &vk_attr)
                         .replace("{{vk_ident}}", vk_ident)
+                        .replace("{{vk_create_info}}", &vk_create_info)
                         .replace("{{rs_ident}}", &rs_ident)
                         .replace("{{rs_params}}", &rs_params)
                         .replace("{{rs_params_idents}}", &rs_params_idents)
                 )?;
             }
             analysis::WrapperType::UnitResult => {
-                writeln!(
-                    str,
-                    "{}",
-                    TEMPLATE_UNIT_RESULT
-                        .replace("{{vk_attr}}", &vk_attr)
-                        .replace("{{vk_ident}}", vk_ident)
-                        .replace("{{rs_ident}}", &rs_ident)
-                        .replace("{{rs_params}}", &rs_params)
-                        .replace("{{rs_params_idents}}", &rs_params_idents)
-                )?;
+                if let Some(rs_success_codes) = &rs_success_codes {
+                    writeln!(
+                        str,
+                        "{}",
+                        TEMPLATE_UNIT_RESULT_CODES
+                            .replace("{{rs_checks}}", &rs_checks)
+                            .replace("{{vk_attr}}", &vk_attr)
+                            .replace("{{vk_ident}}", vk_ident)
+                            .replace("{{vk_create_info}}", &vk_create_info)
+                            .replace("{{rs_ident}}", &rs_ident)
+                            .replace("{{rs_params}}", &rs_params)
+                            .replace("{{rs_params_idents}}", &rs_params_idents)
+                            .replace("{{rs_success_codes}}", rs_success_codes)
+                    )?;
+                } else {
+                    writeln!(
+                        str,
+                        "{}",
+                        TEMPLATE_UNIT_RESULT
+                            .replace("{{rs_checks}}", &rs_checks)
+                            .replace("{{vk_attr}}", &vk_attr)
+                            .replace("{{vk_ident}}", vk_ident)
+                            .replace("{{vk_create_info}}", &vk_create_info)
+                            .replace("{{rs_ident}}", &rs_ident)
+                            .replace("{{rs_params}}", &rs_params)
+                            .replace("{{rs_params_idents}}", &rs_params_idents)
+                    )?;
+                }
             }
             analysis::WrapperType::OutputResult => {
-                let (vk_type, vk_text) = vk_params_type_last.unwrap();
-                let vk_text = vk_text_dereference_mut_ptr(vk_text.as_ref())?;
-                let rs_output_type = translation::vk_complex_type(
-                    ctx.c_type_map,
-                    vk_type,
-                    vk_text.as_ref(),
-                    None,
-                    true,
-                )?;
+                let rs_output_type = output_type(ctx, vk_params_type_last.unwrap())?;
                 let rs_output_ident = rs_params_idents_last;
-                writeln!(
-                    str,
-                    "{}",
-                    TEMPLATE_OUTPUT_RESULT
-                        .replace("{{vk_attr}}", &vk_attr)
-                        .replace("{{vk_ident}}", vk_ident)
-                        .replace("{{rs_ident}}", &rs_ident)
-                        .replace("{{rs_params}}", &rs_params_lhs.unwrap())
-                        .replace("{{rs_params_idents}}", &rs_params_idents_lhs)
-                        .replace("{{rs_output_type}}", &rs_output_type)
-                        .replace("{{rs_output_ident}}", rs_output_ident)
-                )?;
+                if let Some(rs_success_codes) = &rs_success_codes {
+                    writeln!(
+                        str,
+                        "{}",
+                        TEMPLATE_OUTPUT_RESULT_CODES
+                            .replace("{{rs_checks}}", &rs_checks)
+                            .replace("{{vk_attr}}", &vk_attr)
+                            .replace("{{vk_ident}}", vk_ident)
+                            .replace("{{vk_create_info}}", &vk_create_info)
+                            .replace("{{rs_ident}}", &rs_ident)
+                            .replace("{{rs_params}}", rs_params_lhs.as_ref().unwrap())
+                            .replace("{{rs_params_idents}}", &rs_params_idents_lhs)
+                            .replace("{{rs_output_type}}", &rs_output_type)
+                            .replace("{{rs_output_ident}}", rs_output_ident)
+                            .replace("{{rs_success_codes}}", rs_success_codes)
+                    )?;
+                } else {
+                    writeln!(
+                        str,
+                        "{}",
+                        TEMPLATE_OUTPUT_RESULT
+                            .replace("{{rs_checks}}", &rs_checks)
+                            .replace("{{vk_attr}}", &vk_attr)
+                            .replace("{{vk_ident}}", vk_ident)
+                            .replace("{{vk_create_info}}", &vk_create_info)
+                            .replace("{{rs_ident}}", &rs_ident)
+                            .replace("{{rs_params}}", &rs_params_lhs.unwrap())
+                            .replace("{{rs_params_idents}}", &rs_params_idents_lhs)
+                            .replace("{{rs_output_type}}", &rs_output_type)
+                            .replace("{{rs_output_ident}}", rs_output_ident)
+                    )?;
+                }
             }
             analysis::WrapperType::Output => {
                 let vk_attr = attributes::Builder::new().must_use().raw(vk_attr).build();
-                let (vk_type, vk_text) = vk_params_type_last.unwrap();
-                let vk_text = vk_text_dereference_mut_ptr(vk_text.as_ref())?;
-                let rs_output_type = translation::vk_complex_type(
-                    ctx.c_type_map,
-                    vk_type,
-                    vk_text.as_ref(),
-                    None,
-                    true,
-                )?;
+                let rs_output_type = output_type(ctx, vk_params_type_last.unwrap())?;
                 let rs_output_ident = rs_params_idents_last;
                 writeln!(
                     str,
                     "{}",
                     TEMPLATE_OUTPUT
+                        .replace("{{rs_checks}}", &rs_checks)
                         .replace("{{vk_attr}}", &vk_attr)
                         .replace("{{vk_ident}}", vk_ident)
+                        .replace("{{vk_create_info}}", &vk_create_info)
                         .replace("{{rs_ident}}", &rs_ident)
                         .replace("{{rs_params}}", &rs_params_lhs.unwrap())
                         .replace("{{rs_params_idents}}", &rs_params_idents_lhs)
@@ -302,11 +573,128 @@ fn generate_wrappers(
                 )?;
             }
         }
+
+        if let Some(rs_slice) = rs_slice {
+            writeln!(str, "{rs_slice}")?;
+        }
+        if let Some(rs_enumerate) = rs_enumerate {
+            writeln!(str, "{rs_enumerate}")?;
+        }
     }
 
     Ok(str)
 }
 
+fn generate_slice_wrapper(
+    ctx: &GeneratorContext<'_>,
+    vk_attr: &str,
+    rs_ident: &str,
+    params: &[&registry::CommandParam],
+    rs_return_type: Option<&str>,
+) -> Result<Option<String>> {
+    // Arrays whose `len` names a plain `uint32_t` count parameter.
+    let is_count = |name: &str| {
+        params
+            .iter()
+            .any(|param| param.name == name && param.ty == "uint32_t" && param.text.is_none())
+    };
+    let arrays = params
+        .iter()
+        .filter(|param| param.ty != "void")
+        .filter(|param| matches!(param.text.as_deref(), Some("const*" | "*")))
+        .filter(|param| param.len.as_deref().is_some_and(is_count))
+        .collect::<Vec<_>>();
+    if arrays.is_empty() {
+        return Ok(None);
+    }
+
+    let mut rs_params = vec![];
+    let mut rs_args = vec![];
+    let mut rs_asserts = String::new();
+    for param in params {
+        // Count parameters are derived from the first array using them.
+        if let Some(array) = arrays
+            .iter()
+            .find(|array| array.len.as_deref() == Some(param.name.as_str()))
+        {
+            let rs_array_ident = slice_ident(&array.name)?;
+            rs_args.push(format!("u32::try_from({rs_array_ident}.len()).unwrap()"));
+            continue;
+        }
+
+        // Arrays become slices, arrays sharing a count must have equal lengths.
+        if arrays.iter().any(|array| array.name == param.name) {
+            let rs_slice_ident = slice_ident(&param.name)?;
+            let rs_element_type =
+                translation::vk_complex_type(ctx.c_type_map, &param.ty, None, None, true)?;
+            if param.text.as_deref() == Some("const*") {
+                rs_params.push(format!("{rs_slice_ident}: &[{rs_element_type}]"));
+                rs_args.push(format!("{rs_slice_ident}.as_ptr()"));
+            } else {
+                rs_params.push(format!("{rs_slice_ident}: &mut [{rs_element_type}]"));
+                rs_args.push(format!("{rs_slice_ident}.as_mut_ptr()"));
+            }
+            let first_array = arrays.iter().find(|array| array.len == param.len).unwrap();
+            if first_array.name != param.name {
+                let rs_first_ident = slice_ident(&first_array.name)?;
+                writeln!(
+                    rs_asserts,
+                    "assert_eq!({rs_first_ident}.len(), {rs_slice_ident}.len());"
+                )?;
+            }
+            continue;
+        }
+
+        // Everything else is forwarded as is.
+        let rs_param_ident = translation::vk_simple_ident(&param.name)?;
+        let rs_param_type = translation::vk_complex_type(
+            ctx.c_type_map,
+            &param.ty,
+            param.text.as_ref(),
+            None,
+            true,
+        )?;
+        rs_params.push(
+            TEMPLATE_PARAM
+                .replace("{{rs_param_ident}}", &rs_param_ident)
+                .replace("{{rs_param_type}}", &rs_param_type),
+        );
+        rs_args.push(rs_param_ident);
+    }
+
+    let (rs_return_type, rs_terminator) = match rs_return_type {
+        Some(rs_return_type) => (format!(" -> {rs_return_type}"), ""),
+        None => (String::new(), ";"),
+    };
+    Ok(Some(
+        TEMPLATE_SLICE
+            .replace("{{vk_attr}}", vk_attr)
+            .replace("{{rs_ident}}", rs_ident)
+            .replace("{{rs_params}}", &rs_params.join(","))
+            .replace("{{rs_return_type}}", &rs_return_type)
+            .replace("{{rs_asserts}}", &rs_asserts)
+            .replace("{{rs_terminator}}", rs_terminator)
+            .replace("{{rs_args}}", &rs_args.join(",")),
+    ))
+}
+
+fn slice_ident(vk_param_ident: &str) -> Result<String> {
+    let rs_param_ident = translation::vk_simple_ident(vk_param_ident)?;
+    Ok(rs_param_ident
+        .strip_prefix("p_")
+        .unwrap_or(&rs_param_ident)
+        .to_string())
+}
+
+fn output_type(
+    ctx: &GeneratorContext<'_>,
+    vk_param_type: &(String, Option<String>),
+) -> Result<String> {
+    let (vk_type, vk_text) = vk_param_type;
+    let vk_text = vk_text_dereference_mut_ptr(vk_text.as_ref())?;
+    translation::vk_complex_type(ctx.c_type_map, vk_type, vk_text.as_ref(), None, true)
+}
+
 fn vk_text_dereference_mut_ptr(vk_text: Option<&String>) -> Result<Option<String>> {
     let vk_text = if let Some(vk_text) = vk_text {
         ensure!(vk_text.chars().all(|c| c == '*'));
@@ -1,10 +1,36 @@
 use super::*;
 
 const TEMPLATE: &str = r#"{{vk_attr}}
-pub enum {{rs_ident}} {
+pub struct {{rs_ident}}(i32);
+
+#[allow(non_upper_case_globals)]
+impl {{rs_ident}} {
     {{rs_members}}
 }
 
+impl {{rs_ident}} {
+    #[must_use]
+    pub const fn from_raw(value: i32) -> Self {
+        Self(value)
+    }
+
+    #[must_use]
+    pub const fn as_raw(self) -> i32 {
+        self.0
+    }
+}
+
+impl std::fmt::Debug for {{rs_ident}} {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        #[allow(deprecated)]
+        let name = match *self {
+            {{rs_debug_matches}}
+            _ => return write!(f, "{{rs_ident}}({})", self.0),
+        };
+        f.write_str(name)
+    }
+}
+
 impl std::fmt::Display for {{rs_ident}} {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{self:?}")
@@ -12,15 +38,18 @@ impl std::fmt::Display for {{rs_ident}} {
 }
 "#;
 
-const TEMPLATE_MEMBER: &str = r#"{{rs_member_ident}} = {{rs_member_value}},"#;
+const TEMPLATE_MEMBER: &str = r#"pub const {{rs_member_ident}}: Self = Self({{rs_member_value}});"#;
+
+const TEMPLATE_DEBUG_MATCH: &str = r#"Self::{{rs_member_ident}} => "{{rs_member_ident}}","#;
 
-const TEMPLATE_FORMAT_ASPECT_MASK: &str = r#"
+const TEMPLATE_FORMAT: &str = r#"
 impl Format {
     #[must_use]
     pub fn aspect_mask(self) -> ImageAspectFlags {
         #[allow(clippy::match_same_arms)]
         match self {
             {{aspect_mask_matches}}
+            _ => ImageAspectFlags::empty(),
         }
     }
 
@@ -29,9 +58,143 @@ impl Format {
         #[allow(clippy::match_same_arms)]
         match self {
             {{block_size_matches}}
+            _ => 0,
+        }
+    }
+
+    #[doc = "Texels in one block, `1x1x1` for uncompressed formats."]
+    #[must_use]
+    pub const fn texel_block_extent(self) -> Extent3D {
+        #[allow(clippy::match_same_arms)]
+        match self {
+            {{texel_block_extent_matches}}
+            _ => Extent3D { width: 1, height: 1, depth: 1 },
+        }
+    }
+
+    #[doc = "Components in the order they are listed in the format name."]
+    #[must_use]
+    pub const fn components(self) -> &'static [FormatComponent] {
+        #[allow(clippy::match_same_arms)]
+        match self {
+            {{components_matches}}
+            _ => &[],
+        }
+    }
+
+    #[must_use]
+    pub const fn is_srgb(self) -> bool {
+        {{is_srgb_matches}}
+    }
+
+    #[must_use]
+    pub const fn is_depth(self) -> bool {
+        {{is_depth_matches}}
+    }
+
+    #[must_use]
+    pub const fn is_stencil(self) -> bool {
+        {{is_stencil_matches}}
+    }
+
+    #[must_use]
+    pub const fn is_compressed(self) -> bool {
+        self.compression_class().is_some()
+    }
+
+    #[must_use]
+    pub const fn compression_class(self) -> Option<FormatCompressionClass> {
+        #[allow(clippy::match_same_arms)]
+        match self {
+            {{compression_class_matches}}
+            _ => None,
+        }
+    }
+
+    #[doc = "Number of memory planes, 1 for formats that are not multi-planar."]
+    #[must_use]
+    pub const fn plane_count(self) -> u32 {
+        #[allow(clippy::match_same_arms)]
+        match self {
+            {{plane_count_matches}}
+            _ => 1,
+        }
+    }
+
+    #[doc = "Format compatible with `plane` of a multi-planar format, e.g. for creating per-plane image views."]
+    #[must_use]
+    pub const fn plane_format(self, plane: u32) -> Option<Self> {
+        #[allow(clippy::match_same_arms)]
+        match (self, plane) {
+            {{plane_format_matches}}
+            _ => None,
+        }
+    }
+
+    #[doc = "sRGB counterpart with the same layout, `self` if already sRGB."]
+    #[must_use]
+    pub const fn to_srgb(self) -> Option<Self> {
+        match self {
+            {{to_srgb_matches}}
+            _ if self.is_srgb() => Some(self),
+            _ => None,
+        }
+    }
+
+    #[doc = "Linear (UNORM) counterpart of an sRGB format, `self` if not sRGB."]
+    #[must_use]
+    pub const fn to_linear(self) -> Self {
+        match self {
+            {{to_linear_matches}}
+            _ => self,
         }
     }
 }
+
+#[doc = "Component of a [`Format`], see [`Format::components`]."]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct FormatComponent {
+    pub name: FormatComponentName,
+    #[doc = "`None` for block-compressed formats."]
+    pub bits: Option<u32>,
+    pub numeric_type: FormatNumericType,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum FormatComponentName {
+    R,
+    G,
+    B,
+    A,
+    D,
+    S,
+}
+
+#[doc = "Interpretation of a [`FormatComponent`], e.g. `UNORM` or `SFLOAT`."]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum FormatNumericType {
+    Unorm,
+    Snorm,
+    Uscaled,
+    Sscaled,
+    Uint,
+    Sint,
+    Ufloat,
+    Sfloat,
+    Srgb,
+    Sfixed5,
+}
+
+#[doc = "Compression scheme of a block-compressed [`Format`]."]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum FormatCompressionClass {
+    Bc,
+    Etc2,
+    Eac,
+    AstcLdr,
+    AstcHdr,
+    Pvrtc,
+}
 "#;
 
 const TEMPLATE_FORMAT_MATCH: &str = r#"Format::{{match_lhs}} => {{match_rhs}},"#;
@@ -99,8 +262,8 @@ pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
 
         let vk_ident = &registry_enum.name;
         let vk_attr = attributes::Builder::new()
-            .repr("i32")
-            .derive("Clone, Copy, PartialEq, Eq, Debug")
+            .repr("transparent")
+            .derive("Clone, Copy, PartialEq, Eq, Hash")
             .doc_chapter(ctx.vkspec.type_chapter(vk_ident))
             .doc_br()
             .doc_desc(ctx.vkspec.type_desc(vk_ident))
@@ -118,6 +281,7 @@ pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
             .collect::<Vec<_>>();
         let rs_member_idents = translation::vk_enum(vk_ident, &vk_member_idents)?;
         let mut rs_members = String::new();
+        let mut rs_debug_matches = String::new();
         for (member, rs_member_ident) in registry_enum.members.iter().zip(&rs_member_idents) {
             if member.alias.is_some() {
                 continue;
@@ -142,6 +306,11 @@ pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
                     .replace("{{rs_member_ident}}", rs_member_ident)
                     .replace("{{rs_member_value}}", rs_member_value)
             )?;
+            writeln!(
+                rs_debug_matches,
+                "{}",
+                TEMPLATE_DEBUG_MATCH.replace("{{rs_member_ident}}", rs_member_ident)
+            )?;
         }
 
         writeln!(
@@ -152,18 +321,34 @@ pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
                 .replace("{{vk_ident}}", vk_ident)
                 .replace("{{rs_ident}}", &rs_ident)
                 .replace("{{rs_members}}", &rs_members)
+                .replace("{{rs_debug_matches}}", &rs_debug_matches)
         )?;
 
         // Special: VkFormat implementations.
         if vk_ident == "VkFormat" {
+            let rs_format_map = vk_member_idents
+                .iter()
+                .copied()
+                .zip(rs_member_idents.iter().map(String::as_str))
+                .collect::<HashMap<_, _>>();
             let mut aspect_mask_matches = String::new();
             let mut block_size_matches = String::new();
+            let mut texel_block_extent_matches = String::new();
+            let mut components_matches = String::new();
+            let mut srgb_formats = vec![];
+            let mut depth_formats = vec![];
+            let mut stencil_formats = vec![];
+            let mut compression_class_matches = String::new();
+            let mut plane_count_matches = String::new();
+            let mut plane_format_matches = String::new();
+            let mut to_srgb_matches = String::new();
+            let mut to_linear_matches = String::new();
             for (vk_member_ident, rs_member_ident) in vk_member_idents.iter().zip(&rs_member_idents)
             {
                 let aspect_flags = if let Some(&format) = format_map.get(vk_member_ident) {
                     let color_aspect = format.components.iter().any(|c| {
                         matches!(
-                            c,
+                            c.name,
                             registry::Component::R
                                 | registry::Component::G
                                 | registry::Component::B
@@ -173,23 +358,23 @@ pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
                     let depth_aspect = format
                         .components
                         .iter()
-                        .any(|c| matches!(c, registry::Component::D));
+                        .any(|c| matches!(c.name, registry::Component::D));
                     let stencil_aspect = format
                         .components
                         .iter()
-                        .any(|c| matches!(c, registry::Component::S));
+                        .any(|c| matches!(c.name, registry::Component::S));
                     let plane_0_aspect = format
                         .planes
                         .iter()
-                        .any(|c| matches!(c, registry::Plane::P0));
+                        .any(|c| matches!(c.index, registry::Plane::P0));
                     let plane_1_aspect = format
                         .planes
                         .iter()
-                        .any(|c| matches!(c, registry::Plane::P1));
+                        .any(|c| matches!(c.index, registry::Plane::P1));
                     let plane_2_aspect = format
                         .planes
                         .iter()
-                        .any(|c| matches!(c, registry::Plane::P2));
+                        .any(|c| matches!(c.index, registry::Plane::P2));
 
                     let mut flags = String::new();
                     macro_rules! push {
@@ -240,14 +425,154 @@ pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
                         .replace("{{match_lhs}}", rs_member_ident)
                         .replace("{{match_rhs}}", &block_size)
                 )?;
+
+                // Special: sRGB formats pair up with the UNORM format of the same name.
+                if vk_member_ident.contains("_SRGB") {
+                    let vk_linear_ident = vk_member_ident.replace("_SRGB", "_UNORM");
+                    if let Some(rs_linear_ident) = rs_format_map.get(vk_linear_ident.as_str()) {
+                        writeln!(
+                            to_srgb_matches,
+                            "Format::{rs_linear_ident} => Some(Format::{rs_member_ident}),"
+                        )?;
+                        writeln!(
+                            to_linear_matches,
+                            "Format::{rs_member_ident} => Format::{rs_linear_ident},"
+                        )?;
+                    }
+                }
+
+                let Some(&format) = format_map.get(vk_member_ident) else {
+                    continue;
+                };
+
+                if let Some([width, height, depth]) = format.block_extent {
+                    writeln!(
+                        texel_block_extent_matches,
+                        "Format::{rs_member_ident} => Extent3D {{ width: {width}, height: {height}, depth: {depth} }},"
+                    )?;
+                }
+
+                let mut components = vec![];
+                for component in &format.components {
+                    let name = match component.name {
+                        registry::Component::R => "R",
+                        registry::Component::G => "G",
+                        registry::Component::B => "B",
+                        registry::Component::A => "A",
+                        registry::Component::D => "D",
+                        registry::Component::S => "S",
+                    };
+                    let bits = component
+                        .bits
+                        .map_or("None".to_string(), |bits| format!("Some({bits})"));
+                    let numeric_type = match component.numeric_format.as_str() {
+                        "UNORM" => "Unorm",
+                        "SNORM" => "Snorm",
+                        "USCALED" => "Uscaled",
+                        "SSCALED" => "Sscaled",
+                        "UINT" => "Uint",
+                        "SINT" => "Sint",
+                        "UFLOAT" => "Ufloat",
+                        "SFLOAT" => "Sfloat",
+                        "SRGB" => "Srgb",
+                        "SFIXED5" => "Sfixed5",
+                        numeric_format => bail!("Unknown numericFormat={numeric_format}"),
+                    };
+                    components.push(format!(
+                        "FormatComponent {{ name: FormatComponentName::{name}, bits: {bits}, numeric_type: FormatNumericType::{numeric_type} }}"
+                    ));
+                    match component.name {
+                        registry::Component::D => {
+                            depth_formats.push(format!("Format::{rs_member_ident}"));
+                        }
+                        registry::Component::S => {
+                            stencil_formats.push(format!("Format::{rs_member_ident}"));
+                        }
+                        _ => {}
+                    }
+                }
+                if !components.is_empty() {
+                    writeln!(
+                        components_matches,
+                        "Format::{rs_member_ident} => &[{}],",
+                        components.join(", ")
+                    )?;
+                }
+                if format
+                    .components
+                    .iter()
+                    .any(|component| component.numeric_format == "SRGB")
+                {
+                    srgb_formats.push(format!("Format::{rs_member_ident}"));
+                }
+
+                if let Some(compressed) = &format.compressed {
+                    let compression_class = match compressed.as_str() {
+                        "BC" => "Bc",
+                        "ETC2" => "Etc2",
+                        "EAC" => "Eac",
+                        "ASTC LDR" => "AstcLdr",
+                        "ASTC HDR" => "AstcHdr",
+                        "PVRTC" => "Pvrtc",
+                        compressed => bail!("Unknown compressed={compressed}"),
+                    };
+                    writeln!(
+                        compression_class_matches,
+                        "Format::{rs_member_ident} => Some(FormatCompressionClass::{compression_class}),"
+                    )?;
+                }
+
+                if !format.planes.is_empty() {
+                    writeln!(
+                        plane_count_matches,
+                        "Format::{rs_member_ident} => {},",
+                        format.planes.len()
+                    )?;
+                }
+                for plane in &format.planes {
+                    let index = match plane.index {
+                        registry::Plane::P0 => 0,
+                        registry::Plane::P1 => 1,
+                        registry::Plane::P2 => 2,
+                    };
+                    let rs_compatible_ident = rs_format_map
+                        .get(plane.compatible.as_str())
+                        .with_context(|| format!("Missing plane format {}", plane.compatible))?;
+                    writeln!(
+                        plane_format_matches,
+                        "(Format::{rs_member_ident}, {index}) => Some(Format::{rs_compatible_ident}),"
+                    )?;
+                }
             }
 
+            // Membership tests, `false` if no format qualifies.
+            let matches_any = |formats: &[String]| {
+                if formats.is_empty() {
+                    "false".to_string()
+                } else {
+                    format!("matches!(self, {})", formats.join(" | "))
+                }
+            };
+
             writeln!(
                 str,
                 "{}",
-                TEMPLATE_FORMAT_ASPECT_MASK
+                TEMPLATE_FORMAT
                     .replace("{{aspect_mask_matches}}", &aspect_mask_matches)
                     .replace("{{block_size_matches}}", &block_size_matches)
+                    .replace(
+                        "{{texel_block_extent_matches}}",
+                        &texel_block_extent_matches
+                    )
+                    .replace("{{components_matches}}", &components_matches)
+                    .replace("{{is_srgb_matches}}", &matches_any(&srgb_formats))
+                    .replace("{{is_depth_matches}}", &matches_any(&depth_formats))
+                    .replace("{{is_stencil_matches}}", &matches_any(&stencil_formats))
+                    .replace("{{compression_class_matches}}", &compression_class_matches)
+                    .replace("{{plane_count_matches}}", &plane_count_matches)
+                    .replace("{{plane_format_matches}}", &plane_format_matches)
+                    .replace("{{to_srgb_matches}}", &to_srgb_matches)
+                    .replace("{{to_linear_matches}}", &to_linear_matches)
             )?;
         }
     }
@@ -3,7 +3,13 @@ use super::*;
 const TEMPLATE: &str = r#"
 {{required_extensions}}
 {{win32_extensions}}
+{{wayland_extensions}}
+{{xlib_extensions}}
+{{xcb_extensions}}
+{{headless_extensions}}
+{{memory_budget_extensions}}
 {{debugging_extensions}}
+{{spec_versions}}
 "#;
 
 const TEMPLATE_EXTENSIONS: &str = r#"
@@ -20,14 +26,41 @@ pub const {{array_name}}_DEVICE_EXTENSIONS: [*const std::ffi::c_char; {{device_c
 
 const TEMPLATE_EXTENSION_STRING: &str = r#"c"{{name}}".as_ptr().cast(),"#;
 
+const TEMPLATE_SPEC_VERSIONS: &str = r#"
+{{spec_version_constants}}
+/// Spec version of an extension in the manifest, `None` for any other
+/// extension.
+#[must_use]
+pub fn extension_spec_version(extension_name: &std::ffi::CStr) -> Option<u32> {
+    match extension_name.to_bytes() {
+        {{spec_version_matches}}
+        _ => None,
+    }
+}
+"#;
+
+const TEMPLATE_SPEC_VERSION: &str = r#"{{c_attr}}
+pub const {{rs_ident}}: u32 = {{rs_value}};
+"#;
+
+const TEMPLATE_SPEC_VERSION_MATCH: &str = r#"b"{{name}}" => Some({{rs_ident}}),"#;
+
 pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
-    let test_win32_extension = |ext: &registry::Extension| -> bool {
+    let test_platform_extension = |ext: &registry::Extension, name: &str| -> bool {
         if let Some(platform) = &ext.platform {
-            platform == "win32"
+            platform == name
         } else {
             false
         }
     };
+    let test_win32_extension = |ext: &registry::Extension| test_platform_extension(ext, "win32");
+    let test_wayland_extension =
+        |ext: &registry::Extension| test_platform_extension(ext, "wayland");
+    let test_xlib_extension = |ext: &registry::Extension| test_platform_extension(ext, "xlib");
+    let test_xcb_extension = |ext: &registry::Extension| test_platform_extension(ext, "xcb");
+    let test_headless_extension = |ext: &registry::Extension| ext.name == "VK_EXT_headless_surface";
+    let test_memory_budget_extension =
+        |ext: &registry::Extension| ext.name == "VK_EXT_memory_budget";
     let test_debugging_extension = |ext: &registry::Extension| -> bool {
         let None = &ext.platform else {
             return false;
@@ -47,7 +80,13 @@ pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
             .replace(
                 "{{required_extensions}}",
                 &generate_extension_arrays(ctx, "REQUIRED", |ext| {
-                    if test_win32_extension(ext) {
+                    if ext.platform.is_some() {
+                        return false;
+                    }
+                    if test_headless_extension(ext) {
+                        return false;
+                    }
+                    if test_memory_budget_extension(ext) {
                         return false;
                     }
                     if test_debugging_extension(ext) {
@@ -60,14 +99,90 @@ pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
                 "{{win32_extensions}}",
                 &generate_extension_arrays(ctx, "WIN32", test_win32_extension)?
             )
+            .replace(
+                "{{wayland_extensions}}",
+                &generate_extension_arrays(ctx, "WAYLAND", test_wayland_extension)?
+            )
+            .replace(
+                "{{xlib_extensions}}",
+                &generate_extension_arrays(ctx, "XLIB", test_xlib_extension)?
+            )
+            .replace(
+                "{{xcb_extensions}}",
+                &generate_extension_arrays(ctx, "XCB", test_xcb_extension)?
+            )
+            .replace(
+                "{{headless_extensions}}",
+                &generate_extension_arrays(ctx, "HEADLESS", test_headless_extension)?
+            )
+            .replace(
+                "{{memory_budget_extensions}}",
+                &generate_extension_arrays(ctx, "MEMORY_BUDGET", test_memory_budget_extension)?
+            )
             .replace(
                 "{{debugging_extensions}}",
                 &generate_extension_arrays(ctx, "DEBUGGING", test_debugging_extension)?
             )
+            .replace("{{spec_versions}}", &generate_spec_versions(ctx)?)
     )?;
     Ok(str)
 }
 
+fn generate_spec_versions(ctx: &GeneratorContext<'_>) -> Result<String> {
+    let mut spec_version_constants = String::new();
+    let mut spec_version_matches = String::new();
+    let mut extensions = ctx
+        .registry
+        .extensions
+        .iter()
+        .filter(|extension| ctx.manifest.extensions.contains(&extension.name))
+        .collect::<Vec<_>>();
+    extensions.sort_by(|a, b| a.name.cmp(&b.name));
+    for extension in extensions {
+        let name = &extension.name;
+        let (c_ident, value) = extension
+            .requires
+            .iter()
+            .flat_map(|require| &require.entries)
+            .find_map(|entry| match entry {
+                registry::RequireEntry::Enum {
+                    name,
+                    value: Some(value),
+                    ..
+                } if name.ends_with("_SPEC_VERSION") => Some((name, value)),
+                _ => None,
+            })
+            .with_context(|| format!("Missing spec version of extension={name}"))?;
+
+        let c_attr = attributes::Builder::new()
+            .doc_provided(name)
+            .doc_br()
+            .doc_translated(c_ident)
+            .build();
+        let rs_ident = translation::c_define(c_ident)?;
+        let rs_value = translation::c_value(value)?;
+        writeln!(
+            spec_version_constants,
+            "{}",
+            TEMPLATE_SPEC_VERSION
+                .replace("{{c_attr}}", &c_attr)
+                .replace("{{rs_ident}}", &rs_ident)
+                .replace("{{rs_value}}", &rs_value)
+        )?;
+        writeln!(
+            spec_version_matches,
+            "{}",
+            TEMPLATE_SPEC_VERSION_MATCH
+                .replace("{{name}}", name)
+                .replace("{{rs_ident}}", &rs_ident)
+        )?;
+    }
+
+    Ok(TEMPLATE_SPEC_VERSIONS
+        .replace("{{spec_version_constants}}", &spec_version_constants)
+        .replace("{{spec_version_matches}}", &spec_version_matches))
+}
+
 fn generate_extension_arrays<F>(
     ctx: &GeneratorContext<'_>,
     array_name: &str,
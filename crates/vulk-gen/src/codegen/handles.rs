@@ -1,5 +1,26 @@
 use super::*;
 
+const TEMPLATE_TRAIT: &str = r#"#[doc = "Implemented by every handle, for code that works on any handle, such as debug naming or leak tracking."]
+pub trait Handle: Copy + Eq + std::hash::Hash + std::fmt::Debug {
+    #[doc = "Object type used to identify the handle, e.g. in [`DebugUtilsObjectNameInfoEXT`]."]
+    const OBJECT_TYPE: ObjectType;
+
+    #[doc = "Handle this handle is created from, `()` if it has no parent."]
+    type Parent;
+
+    #[must_use]
+    fn as_raw(self) -> u64;
+
+    #[must_use]
+    fn from_raw(raw: u64) -> Self;
+
+    #[must_use]
+    fn is_null(self) -> bool {
+        self.as_raw() == 0
+    }
+}
+"#;
+
 const TEMPLATE: &str = r#"{{vk_attr}}
 pub struct {{rs_ident}}(u64);
 
@@ -21,20 +42,60 @@ impl std::fmt::Debug for {{rs_ident}} {
         f.debug_tuple("{{rs_ident}}").field(&format_args!("{self}")).finish()
     }
 }
+
+impl Handle for {{rs_ident}} {
+    const OBJECT_TYPE: ObjectType = ObjectType::{{rs_object_type}};
+
+    type Parent = {{rs_parent}};
+
+    fn as_raw(self) -> u64 {
+        self.0
+    }
+
+    fn from_raw(raw: u64) -> Self {
+        Self(raw)
+    }
+}
 "#;
 
 pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
     let mut str = String::new();
+    writeln!(str, "{TEMPLATE_TRAIT}")?;
+
+    // Map object type members to their Rust constants.
+    let object_type_map = {
+        let registry_enum = ctx
+            .registry
+            .enums
+            .iter()
+            .find(|registry_enum| registry_enum.name == "VkObjectType")
+            .context("Missing VkObjectType")?;
+        let vk_member_idents = registry_enum
+            .members
+            .iter()
+            .map(|member| member.name.as_str())
+            .collect::<Vec<_>>();
+        let rs_member_idents = translation::vk_enum(&registry_enum.name, &vk_member_idents)?;
+        vk_member_idents
+            .into_iter()
+            .zip(rs_member_idents)
+            .collect::<HashMap<_, _>>()
+    };
 
     for registry_type in &ctx.registry.types {
-        let registry::TypeCategory::Handle { .. } = &registry_type.category else {
+        let registry::TypeCategory::Handle {
+            parent,
+            objtypeenum,
+            ..
+        } = &registry_type.category
+        else {
             continue;
         };
 
         let vk_ident = &registry_type.name;
         let vk_attr = attributes::Builder::new()
             .repr("transparent")
-            .derive("Clone, Copy")
+            .derive("Clone, Copy, PartialEq, Eq, Hash")
             .doc_chapter(ctx.vkspec.type_chapter(vk_ident))
             .doc_br()
             .doc_desc(ctx.vkspec.type_desc(vk_ident))
@@ -44,6 +105,14 @@ pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
             .doc_ref(vk_ident)
             .build();
         let rs_ident = translation::vk_simple_type(vk_ident)?;
+        let rs_object_type = object_type_map.get(objtypeenum.as_str()).with_context(|| {
+            format!("Missing object type, handle={vk_ident}, objtypeenum={objtypeenum}")
+        })?;
+        let rs_parent = if let Some(parent) = parent {
+            translation::vk_simple_type(parent)?
+        } else {
+            "()".to_string()
+        };
         writeln!(
             str,
             "{}",
@@ -51,6 +120,8 @@ pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
                 .replace("{{vk_attr}}", &vk_attr)
                 .replace("{{vk_ident}}", vk_ident)
                 .replace("{{rs_ident}}", &rs_ident)
+                .replace("{{rs_object_type}}", rs_object_type)
+                .replace("{{rs_parent}}", &rs_parent)
         )?;
     }
 
@@ -0,0 +1,150 @@
+use super::*;
+
+/// Builds `const` initializer expressions for structure and union members.
+/// Every member is zeroed or nulled, except `sType` which is set to the
+/// structure's own value. Enumerations and flag bits use their zero member if
+/// they have one, otherwise `from_raw(0)`.
+pub struct Initializers<'a> {
+    types: HashMap<&'a str, &'a registry::TypeCategory>,
+    enums: HashMap<&'a str, String>,
+}
+
+impl<'a> Initializers<'a> {
+    pub fn new(ctx: &GeneratorContext<'a>) -> Result<Self> {
+        let types = ctx
+            .registry
+            .types
+            .iter()
+            .map(|ty| (ty.name.as_str(), &ty.category))
+            .collect();
+
+        let mut enums = HashMap::new();
+        for registry_enum in &ctx.registry.enums {
+            let vk_ident = registry_enum.name.as_str();
+            let (vk_member_idents, rs_member_idents) = match registry_enum.ty {
+                registry::EnumType::Constants => continue,
+                registry::EnumType::Enum => {
+                    let vk_member_idents = registry_enum
+                        .members
+                        .iter()
+                        .map(|member| member.name.as_str())
+                        .collect::<Vec<_>>();
+                    let rs_member_idents = translation::vk_enum(vk_ident, &vk_member_idents)?;
+                    (vk_member_idents, rs_member_idents)
+                }
+                registry::EnumType::Bitmask => {
+                    let vk_member_idents = registry_enum
+                        .members
+                        .iter()
+                        .filter(|member| member.alias.is_none())
+                        .map(|member| member.name.as_str())
+                        .collect::<Vec<_>>();
+                    let rs_member_idents = translation::vk_bitmask(vk_ident, &vk_member_idents)?;
+                    (vk_member_idents, rs_member_idents)
+                }
+            };
+            let members = registry_enum
+                .members
+                .iter()
+                .filter(|member| member.alias.is_none())
+                .filter_map(|member| {
+                    let index = vk_member_idents
+                        .iter()
+                        .position(|&ident| ident == member.name)?;
+                    Some((member, rs_member_idents[index].as_str()))
+                })
+                .collect::<Vec<_>>();
+            let zero_member = members
+                .iter()
+                .find(|(member, _)| member.value.as_deref() == Some("0"));
+            let rs_initializer =
+                zero_member.map_or("from_raw(0)", |&(_, rs_member_ident)| rs_member_ident);
+            enums.insert(vk_ident, rs_initializer.to_string());
+        }
+
+        Ok(Self { types, enums })
+    }
+
+    pub fn member(
+        &self,
+        ctx: &GeneratorContext<'_>,
+        rs_ident: &str,
+        member: &registry::TypeMember,
+    ) -> Result<String> {
+        // Special: sType always matches the structure.
+        if member.name == "sType" {
+            return Ok(format!("StructureType::{rs_ident}"));
+        }
+
+        let vk_type = &member.ty;
+        let rs_type = translation::vk_complex_type(
+            ctx.c_type_map,
+            vk_type,
+            member.text.as_ref(),
+            member.en.as_ref(),
+            false,
+        )?;
+
+        // Pointers.
+        if rs_type.starts_with("*const") {
+            return Ok("std::ptr::null()".to_string());
+        }
+        if rs_type.starts_with("*mut") {
+            return Ok("std::ptr::null_mut()".to_string());
+        }
+
+        // Arrays repeat the element initializer.
+        let rs_element_type =
+            translation::vk_complex_type(ctx.c_type_map, vk_type, None, None, false)?;
+        let rs_element = self.element(ctx, vk_type, &rs_element_type)?;
+        if rs_type.starts_with('[') {
+            return Ok(rs_type.replacen(
+                &format!("[{rs_element_type}; "),
+                &format!("[{rs_element}; "),
+                1,
+            ));
+        }
+
+        Ok(rs_element)
+    }
+
+    fn element(&self, ctx: &GeneratorContext<'_>, vk_type: &str, rs_type: &str) -> Result<String> {
+        // Built-in types.
+        if ctx.c_type_map.contains_key(vk_type) {
+            return Ok(match rs_type {
+                "f32" | "f64" => "0.0".to_string(),
+                rs_type if rs_type.starts_with("*const") => "std::ptr::null()".to_string(),
+                rs_type if rs_type.starts_with("*mut") => "std::ptr::null_mut()".to_string(),
+                _ => "0".to_string(),
+            });
+        }
+
+        // Vulkan types.
+        let category = self
+            .types
+            .get(vk_type)
+            .with_context(|| format!("Unknown type={vk_type}"))?;
+        let rs_element = match category {
+            registry::TypeCategory::Basetype { .. } => "0".to_string(),
+            registry::TypeCategory::Funcpointer { .. } => "std::ptr::null()".to_string(),
+            registry::TypeCategory::Bitmask { .. }
+            | registry::TypeCategory::BitmaskAlias { .. } => {
+                format!("{rs_type}::empty()")
+            }
+            registry::TypeCategory::Handle { .. } | registry::TypeCategory::HandleAlias { .. } => {
+                format!("{rs_type}::null()")
+            }
+            registry::TypeCategory::Enum {} | registry::TypeCategory::EnumAlias { .. } => {
+                let rs_initializer = self
+                    .enums
+                    .get(vk_type)
+                    .with_context(|| format!("Unknown enum={vk_type}"))?;
+                format!("{rs_type}::{rs_initializer}")
+            }
+            registry::TypeCategory::Struct { .. } | registry::TypeCategory::Union { .. } => {
+                format!("{rs_type}::new()")
+            }
+        };
+        Ok(rs_element)
+    }
+}
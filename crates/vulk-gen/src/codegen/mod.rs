@@ -9,11 +9,13 @@ mod enumerations;
 mod extensions;
 mod function_pointers;
 mod handles;
+mod initializers;
 mod outputs;
 mod structures;
 mod toc;
 mod translation;
 mod unions;
+mod valid_usage;
 
 use registry::CtypeMap;
 use std::fmt::Write;
@@ -88,7 +90,16 @@ pub fn generate(
         )
         .replace("{{init::loaders}}", &command_loaders.init_loaders)
         .replace("{{instance::loaders}}", &command_loaders.instance_loaders)
-        .replace("{{device::loaders}}", &command_loaders.device_loaders);
+        .replace("{{device::loaders}}", &command_loaders.device_loaders)
+        .replace("{{init::is_loaded}}", &command_loaders.init_is_loaded)
+        .replace(
+            "{{instance::is_loaded}}",
+            &command_loaders.instance_is_loaded,
+        )
+        .replace("{{device::is_loaded}}", &command_loaders.device_is_loaded)
+        .replace("{{init::loaded}}", &command_loaders.init_loaded)
+        .replace("{{instance::loaded}}", &command_loaders.instance_loaded)
+        .replace("{{device::loaded}}", &command_loaders.device_loaded);
     let vk_rs = outputs::header::TEMPLATE
         .replace("{{vk::command_types}}", &command_types)
         .replace("{{vk::api_constants}}", &api_constants)
@@ -3,7 +3,7 @@ pub const TEMPLATE: &str = r#"
 // Imports
 //
 
-use std::ffi::{c_char, c_void};
+use std::ffi::{c_char, c_ulong, c_void};
 
 //
 // Defines
@@ -96,6 +96,25 @@ fn display_flag_bits_u32<FlagBit>(f: &mut std::fmt::Formatter<'_>, flags: u32, f
     Ok(())
 }
 
+fn parse_flag_bits_u32<FlagBit>(s: &str, flag_bits: &[FlagBit]) -> std::result::Result<u32, ParseFlagsError>
+    where FlagBit: std::fmt::Debug + Into<u32> + Copy
+{
+    let mut flags = 0;
+    if s.trim().is_empty() {
+        return Ok(flags);
+    }
+    for part in s.split('|').map(str::trim) {
+        if let Some(bits) = part.strip_prefix("0b") {
+            flags |= u32::from_str_radix(bits, 2).map_err(|_| ParseFlagsError(part.to_string()))?;
+        } else if let Some(&flag_bit) = flag_bits.iter().find(|flag_bit| format!("{flag_bit:?}") == part) {
+            flags |= flag_bit.into();
+        } else {
+            return Err(ParseFlagsError(part.to_string()));
+        }
+    }
+    Ok(flags)
+}
+
 fn display_flag_bits_u64<FlagBit>(f: &mut std::fmt::Formatter<'_>, flags: u64, flag_bits: &[FlagBit]) -> std::fmt::Result
     where FlagBit: std::fmt::Debug + Into<u64> + Copy
 {
@@ -119,4 +138,85 @@ fn display_flag_bits_u64<FlagBit>(f: &mut std::fmt::Formatter<'_>, flags: u64, f
     }
     Ok(())
 }
+
+fn parse_flag_bits_u64<FlagBit>(s: &str, flag_bits: &[FlagBit]) -> std::result::Result<u64, ParseFlagsError>
+    where FlagBit: std::fmt::Debug + Into<u64> + Copy
+{
+    let mut flags = 0;
+    if s.trim().is_empty() {
+        return Ok(flags);
+    }
+    for part in s.split('|').map(str::trim) {
+        if let Some(bits) = part.strip_prefix("0b") {
+            flags |= u64::from_str_radix(bits, 2).map_err(|_| ParseFlagsError(part.to_string()))?;
+        } else if let Some(&flag_bit) = flag_bits.iter().find(|flag_bit| format!("{flag_bit:?}") == part) {
+            flags |= flag_bit.into();
+        } else {
+            return Err(ParseFlagsError(part.to_string()));
+        }
+    }
+    Ok(flags)
+}
+
+#[repr(C)]
+struct ChainLink {
+    _s_type: StructureType,
+    p_next: *mut ChainLink,
+}
+
+unsafe fn push_next(head: *mut c_void, next: *mut c_void) -> *mut c_void {
+    let mut last = next.cast::<ChainLink>();
+    while !(*last).p_next.is_null() {
+        last = (*last).p_next;
+    }
+    (*last).p_next = head.cast();
+    next
+}
+
+// Implicit valid usage, checked by the wrappers in debug builds.
+
+#[cfg(all(debug_assertions, feature = "valid-usage"))]
+pub(crate) trait ValidUsage {
+    unsafe fn check_valid_usage(&self, command: &str);
+}
+
+#[cfg(all(debug_assertions, feature = "valid-usage"))]
+fn check_s_type(command: &str, name: &str, s_type: StructureType, expected: StructureType) {
+    assert!(s_type == expected, "{command}: {name}::sType must be {expected:?}, got {s_type:?}");
+}
+
+#[cfg(all(debug_assertions, feature = "valid-usage"))]
+unsafe fn check_p_next(command: &str, name: &str, p_next: *const c_void, allowed: &[StructureType]) {
+    let mut link = p_next.cast::<ChainLink>();
+    while !link.is_null() {
+        let s_type = *link.cast::<StructureType>();
+        assert!(allowed.contains(&s_type), "{command}: {s_type:?} can not be chained to {name}");
+        link = (*link).p_next;
+    }
+}
+
+#[cfg(all(debug_assertions, feature = "valid-usage"))]
+pub(crate) fn check_count<T: Copy + PartialEq + Default>(command: &str, name: &str, count: T) {
+    assert!(count != T::default(), "{command}: {name} must be greater than 0");
+}
+
+#[cfg(all(debug_assertions, feature = "valid-usage"))]
+pub(crate) fn check_ptr<T>(command: &str, name: &str, ptr: *const T) {
+    assert!(!ptr.is_null(), "{command}: {name} must not be null");
+}
+
+#[cfg(all(debug_assertions, feature = "valid-usage"))]
+pub(crate) fn check_array<T>(command: &str, name: &str, ptr: *const T, count: usize) {
+    assert!(count == 0 || !ptr.is_null(), "{command}: {name} must not be null if its count is not 0");
+}
+
+#[cfg(all(debug_assertions, feature = "valid-usage"))]
+pub(crate) unsafe fn check_structs<T: ValidUsage>(command: &str, ptr: *const T, count: usize) {
+    if ptr.is_null() {
+        return;
+    }
+    for value in std::slice::from_raw_parts(ptr, count) {
+        value.check_valid_usage(command);
+    }
+}
 "#;
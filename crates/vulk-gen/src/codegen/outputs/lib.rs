@@ -17,6 +17,7 @@ pub const TEMPLATE: &str = r#"//! # `vulk`
     clippy::needless_raw_string_hashes,
     clippy::too_many_arguments,
     clippy::too_many_lines,
+    clippy::unnecessary_trailing_comma,
     clippy::unreadable_literal
 )]
 
@@ -43,6 +44,9 @@ pub use loader::Device;
 
 pub const REQUIRED_VULKAN_VERSION: u32 = vk::make_api_version(0, 1, 3, 0);
 
+/// Environment variable that overrides the library loaded by [`Init::load`].
+pub const LIBRARY_PATH_ENV_VAR: &str = "VULK_LIBRARY_PATH";
+
 //
 // Error
 //
@@ -53,8 +57,26 @@ pub enum Error {
     LibraryLoad(std::borrow::Cow<'static, str>),
     #[error("failed to load function {0}")]
     FunctionLoad(std::borrow::Cow<'static, str>),
-    #[error("vulkan error {0:?}")]
-    Vulkan(vk::Result),
+    #[error(
+        "{command}{} failed with {result:?}",
+        create_info.map_or(String::new(), |create_info| format!(" ({create_info})"))
+    )]
+    Vulkan {
+        result: vk::Result,
+        command: &'static str,
+        create_info: Option<&'static str>,
+    },
+}
+
+impl Error {
+    /// The result code of a failed Vulkan command.
+    #[must_use]
+    pub fn result(&self) -> Option<vk::Result> {
+        match self {
+            Self::Vulkan { result, .. } => Some(*result),
+            _ => None,
+        }
+    }
 }
 
 //
@@ -62,45 +84,4 @@ pub enum Error {
 //
 
 {{extensions}}
-
-//
-// Utilities
-//
-
-pub unsafe fn read_to_vec<F, T>(f: F, s_type: Option<vk::StructureType>) -> Result<Vec<T>, Error>
-where
-    F: Fn(*mut u32, *mut T) -> Result<(), Error>,
-{
-    use std::alloc::alloc_zeroed;
-    use std::alloc::Layout;
-    use std::mem::size_of;
-    use std::mem::size_of_val;
-    use std::ptr::addr_of;
-
-    // Query the number of elements.
-    let mut len_u32 = 0_u32;
-    f(&raw mut len_u32, std::ptr::null_mut())?;
-    let len = len_u32 as usize;
-
-    // Allocate.
-    let layout = Layout::from_size_align(len * size_of::<T>(), 16).unwrap();
-    let ptr = alloc_zeroed(layout);
-
-    // Note: if the output type contains `s_type`, we have to write the
-    // structure type on each of the output elements.
-    if let Some(s_type) = s_type {
-        for i in 0..len {
-            let dst = ptr.add(i * size_of::<T>());
-            dst.copy_from(addr_of!(s_type).cast(), size_of_val(&s_type));
-        }
-    }
-
-    // Query elements.
-    f(&raw mut len_u32, ptr.cast())?;
-
-    // Build the Vec.
-    let vec = Vec::from_raw_parts(ptr.cast::<T>(), len, len);
-
-    Ok(vec)
-}
 "#;
@@ -6,6 +6,41 @@ pub const TEMPLATE: &str = r#"
 use std::ffi::{c_char, c_void, CStr};
 use super::{vk, Error};
 
+//
+// Dispatch
+//
+
+#[cfg(not(feature = "unchecked"))]
+#[inline]
+#[track_caller]
+fn load_fn<T>(f: Option<T>, name: &'static str) -> T {
+    let Some(f) = f else {
+        panic!("{name} is not loaded");
+    };
+    f
+}
+
+#[cfg(not(feature = "unchecked"))]
+#[inline]
+fn try_load_fn<T>(f: Option<T>, name: &'static str) -> Result<T, Error> {
+    f.ok_or(Error::FunctionLoad(std::borrow::Cow::Borrowed(name)))
+}
+
+// Opt-out: calling a command that is not loaded is undefined behavior.
+
+#[cfg(feature = "unchecked")]
+#[inline]
+unsafe fn load_fn<T>(f: Option<T>, _name: &'static str) -> T {
+    f.unwrap_unchecked()
+}
+
+#[cfg(feature = "unchecked")]
+#[inline]
+#[allow(clippy::unnecessary_wraps)]
+unsafe fn try_load_fn<T>(f: Option<T>, _name: &'static str) -> Result<T, Error> {
+    Ok(f.unwrap_unchecked())
+}
+
 //
 // Init
 //
@@ -16,10 +51,13 @@ pub struct InitFunctions {
 
 pub struct Init {
     fns: InitFunctions,
-    _library: std::sync::Arc<libloading::Library>,
+    _library: Option<std::sync::Arc<libloading::Library>>,
 }
 
 impl Init {
+    /// Loads the system Vulkan loader, or the library named by the
+    /// [`LIBRARY_PATH_ENV_VAR`](crate::LIBRARY_PATH_ENV_VAR) environment
+    /// variable if it is set.
     pub unsafe fn load() -> Result<Self, Error> {
         // Only Windows and Linux are supported.
         #[cfg(windows)]
@@ -27,8 +65,20 @@ impl Init {
         #[cfg(unix)]
         const VULKAN_LIB_PATH: &str = "libvulkan.so.1";
 
+        // Environment override.
+        if let Some(path) = std::env::var_os(crate::LIBRARY_PATH_ENV_VAR) {
+            return Self::load_from_path(path);
+        }
+
+        Self::load_from_path(VULKAN_LIB_PATH)
+    }
+
+    /// Loads the Vulkan loader or driver library at `path`.
+    pub unsafe fn load_from_path(path: impl AsRef<std::ffi::OsStr>) -> Result<Self, Error> {
+        let path = path.as_ref();
+
         // Load library.
-        let library = libloading::Library::new(VULKAN_LIB_PATH).map_err(|_| Error::LibraryLoad(std::borrow::Cow::Borrowed(VULKAN_LIB_PATH))).map(std::sync::Arc::new)?;
+        let library = libloading::Library::new(path).map_err(|_| Error::LibraryLoad(std::borrow::Cow::Owned(path.to_string_lossy().into_owned()))).map(std::sync::Arc::new)?;
 
         // Load functions.
         let load = |name: &'static CStr| {
@@ -47,15 +97,61 @@ impl Init {
             fns: InitFunctions {
                 {{init::loaders}}
             },
-            _library: library,
+            _library: Some(library),
         })
     }
 
+    /// Loads the global commands through a `vkGetInstanceProcAddr` provided by
+    /// another library, such as an OpenXR runtime or a statically linked
+    /// driver. The caller must keep that library loaded while `Init` and
+    /// everything created from it are alive.
+    pub unsafe fn from_get_instance_proc_addr(get_instance_proc_addr: vk::GetInstanceProcAddr) -> Self {
+        let load = |name: &'static CStr| {
+            if name == c"vkGetInstanceProcAddr" {
+                return Some(get_instance_proc_addr as vk::PfnVoidFunction);
+            }
+            let pfn = get_instance_proc_addr(vk::Instance::null(), name.as_ptr());
+            if pfn as usize == 0 {
+                return None;
+            }
+            Some(pfn)
+        };
+
+        Self {
+            fns: InitFunctions {
+                {{init::loaders}}
+            },
+            _library: None,
+        }
+    }
+
     #[must_use]
     pub fn fns(&self) -> &InitFunctions {
         &self.fns
     }
 
+    /// Returns `true` if the command `name`, e.g. `"vkCreateBuffer"`, was
+    /// resolved when loading.
+    #[must_use]
+    pub fn is_loaded(&self, name: &str) -> bool {
+        match name {
+            {{init::is_loaded}}
+            _ => false,
+        }
+    }
+
+    /// Commands that failed to resolve when loading, typically because their
+    /// extension was not enabled.
+    #[must_use]
+    pub fn missing_commands(&self) -> Vec<&'static str> {
+        [
+            {{init::loaded}}
+        ]
+        .into_iter()
+        .filter_map(|(name, loaded)| (!loaded).then_some(name))
+        .collect()
+    }
+
     {{init::wrappers}}
 }
 
@@ -100,6 +196,28 @@ impl Instance {
         &self.fns
     }
 
+    /// Returns `true` if the command `name`, e.g. `"vkCreateBuffer"`, was
+    /// resolved when loading.
+    #[must_use]
+    pub fn is_loaded(&self, name: &str) -> bool {
+        match name {
+            {{instance::is_loaded}}
+            _ => false,
+        }
+    }
+
+    /// Commands that failed to resolve when loading, typically because their
+    /// extension was not enabled.
+    #[must_use]
+    pub fn missing_commands(&self) -> Vec<&'static str> {
+        [
+            {{instance::loaded}}
+        ]
+        .into_iter()
+        .filter_map(|(name, loaded)| (!loaded).then_some(name))
+        .collect()
+    }
+
     {{instance::wrappers}}
 }
 
@@ -144,6 +262,28 @@ impl Device {
         &self.fns
     }
 
+    /// Returns `true` if the command `name`, e.g. `"vkCreateBuffer"`, was
+    /// resolved when loading.
+    #[must_use]
+    pub fn is_loaded(&self, name: &str) -> bool {
+        match name {
+            {{device::is_loaded}}
+            _ => false,
+        }
+    }
+
+    /// Commands that failed to resolve when loading, typically because their
+    /// extension was not enabled.
+    #[must_use]
+    pub fn missing_commands(&self) -> Vec<&'static str> {
+        [
+            {{device::loaded}}
+        ]
+        .into_iter()
+        .filter_map(|(name, loaded)| (!loaded).then_some(name))
+        .collect()
+    }
+
     {{device::wrappers}}
 }
 "#;
@@ -6,6 +6,48 @@ fn enum_display() {
     assert_eq!(format!("{}", vk::StructureType::ApplicationInfo), "ApplicationInfo");
 }
 
+#[test]
+fn enum_unknown_value() {
+    let result = vk::Result::from_raw(-1_000_999_000);
+    assert_ne!(result, vk::Result::Success);
+    assert_eq!(format!("{result}"), "Result(-1000999000)");
+    assert_eq!(vk::Result::from_raw(0), vk::Result::Success);
+    assert_eq!(vk::Result::ErrorOutOfHostMemory.as_raw(), -1);
+}
+
+#[test]
+fn flag_bits_unknown_value() {
+    let transform = vk::SurfaceTransformFlagBitsKHR::from_raw(0b1000_0000_0000);
+    assert_ne!(transform, vk::SurfaceTransformFlagBitsKHR::IdentityKHR);
+    assert_eq!(format!("{transform}"), "SurfaceTransformFlagBitsKHR(0b100000000000)");
+    assert_eq!(format!("{}", vk::SurfaceTransformFlagBitsKHR::Rotate90KHR), "Rotate90KHR");
+    assert_eq!(vk::SurfaceTransformFlagsKHR::from(transform).to_string(), "0b100000000000");
+    assert_eq!(vk::CullModeFlagBits::Back.as_raw(), 0b10);
+}
+
+#[test]
+fn structure_initializers() {
+    const BUFFER_CREATE_INFO: vk::BufferCreateInfo = vk::BufferCreateInfo::new();
+    assert_eq!(BUFFER_CREATE_INFO.s_type, vk::StructureType::BufferCreateInfo);
+    assert!(BUFFER_CREATE_INFO.p_next.is_null());
+    assert!(BUFFER_CREATE_INFO.p_queue_family_indices.is_null());
+    assert_eq!(BUFFER_CREATE_INFO.size, 0);
+
+    let image_create_info = vk::ImageCreateInfo::default();
+    assert_eq!(image_create_info.s_type, vk::StructureType::ImageCreateInfo);
+    assert!(image_create_info.p_next.is_null());
+    assert_eq!(image_create_info.image_type, vk::ImageType::Type1d);
+    assert_eq!(image_create_info.initial_layout, vk::ImageLayout::Undefined);
+    assert_eq!(image_create_info.samples, vk::SampleCountFlagBits::from_raw(0));
+    assert!(image_create_info.usage.is_empty());
+}
+
+#[test]
+fn extension_spec_versions() {
+    assert_eq!(extension_spec_version(c"VK_KHR_swapchain"), Some(KHR_SWAPCHAIN_SPEC_VERSION));
+    assert_eq!(extension_spec_version(c"VK_KHR_not_an_extension"), None);
+}
+
 #[test]
 fn flag_bits_display() {
     assert_eq!(format!("{}", vk::AccessFlagBits2::DescriptorBufferReadEXT), "DescriptorBufferReadEXT");
@@ -26,4 +68,151 @@ fn flags_contains() {
     assert!(flags.contains(vk::MemoryPropertyFlagBits::HostCoherent));
     assert!(!flags.contains(vk::MemoryPropertyFlagBits::DeviceLocal));
 }
+
+#[test]
+fn flags_ops() {
+    let mut flags = vk::BufferUsageFlags::empty();
+    assert!(flags.is_empty());
+    flags.insert(vk::BufferUsageFlagBits::TransferSrc);
+    flags |= vk::BufferUsageFlagBits::TransferDst;
+    flags.set(vk::BufferUsageFlagBits::StorageBuffer, true);
+    assert!(flags.intersects(vk::BufferUsageFlagBits::TransferDst | vk::BufferUsageFlagBits::UniformBuffer));
+    flags.remove(vk::BufferUsageFlagBits::TransferSrc);
+    flags.toggle(vk::BufferUsageFlagBits::StorageBuffer);
+    assert_eq!(flags, vk::BufferUsageFlagBits::TransferDst.into());
+    assert_eq!(flags ^ flags, vk::BufferUsageFlags::empty());
+    assert!(!(!flags).contains(vk::BufferUsageFlagBits::TransferDst));
+    assert_eq!(!vk::CullModeFlags::from_bits_truncate(0b1), vk::CullModeFlagBits::Back.into());
+    assert_eq!((!vk::CullModeFlags::empty()).bits(), vk::CullModeFlags::all().bits());
+    assert!(vk::CullModeFlags::all().contains(vk::CullModeFlagBits::FrontAndBack));
+    assert_eq!(vk::CullModeFlags::from_bits(0b1), Some(vk::CullModeFlagBits::Front.into()));
+    assert_eq!(vk::CullModeFlags::from_bits(0b100), None);
+    assert_eq!(vk::CullModeFlags::from_bits_truncate(0b101).bits(), 0b1);
+    let bits = vk::CullModeFlags::all().iter().collect::<Vec<_>>();
+    assert_eq!(bits, [vk::CullModeFlagBits::Front, vk::CullModeFlagBits::Back, vk::CullModeFlagBits::FrontAndBack]);
+}
+
+#[test]
+fn flags_from_str() {
+    let flags = vk::BufferUsageFlagBits::TransferSrc | vk::BufferUsageFlagBits::StorageBuffer;
+    assert_eq!(flags.to_string().parse::<vk::BufferUsageFlags>(), Ok(flags));
+    let flags: vk::BufferUsageFlags = "TransferSrc | 0b1000000000".parse().unwrap();
+    assert_eq!(flags.to_string(), "TransferSrc | 0b1000000000");
+    assert_eq!("".parse::<vk::BufferUsageFlags>(), Ok(vk::BufferUsageFlags::empty()));
+    assert!("TransferSrc | Bogus".parse::<vk::BufferUsageFlags>().is_err());
+}
+
+#[test]
+fn flags_without_flag_bits() {
+    let mut flags = vk::DeviceCreateFlags::empty();
+    assert!(flags.is_empty());
+    assert_eq!(flags, vk::DeviceCreateFlags::all());
+    assert_eq!(vk::DeviceCreateFlags::from_bits(0), Some(flags));
+    assert_eq!(vk::DeviceCreateFlags::from_bits(0b1), None);
+    assert_eq!(vk::DeviceCreateFlags::from_bits_truncate(0b1).bits(), 0);
+    flags |= vk::DeviceCreateFlags::empty();
+    assert_eq!(flags & flags ^ flags, vk::DeviceCreateFlags::empty());
+    assert!(flags.contains(flags) && !flags.intersects(flags));
+}
+
+#[test]
+fn error_display() {
+    let error = Error::Vulkan {
+        result: vk::Result::ErrorOutOfDeviceMemory,
+        command: "vkCreateBuffer",
+        create_info: Some("VkBufferCreateInfo"),
+    };
+    assert_eq!(error.to_string(), "vkCreateBuffer (VkBufferCreateInfo) failed with ErrorOutOfDeviceMemory");
+    assert_eq!(error.result(), Some(vk::Result::ErrorOutOfDeviceMemory));
+    let error = Error::Vulkan {
+        result: vk::Result::ErrorDeviceLost,
+        command: "vkQueueSubmit2",
+        create_info: None,
+    };
+    assert_eq!(error.to_string(), "vkQueueSubmit2 failed with ErrorDeviceLost");
+}
+
+#[test]
+fn handle_trait() {
+    use vk::Handle;
+    assert_eq!(vk::Buffer::OBJECT_TYPE, vk::ObjectType::Buffer);
+    assert_eq!(vk::SwapchainKHR::OBJECT_TYPE, vk::ObjectType::SwapchainKHR);
+    assert!(vk::Image::null().is_null());
+    let buffer = vk::Buffer::from_raw(0x1234);
+    assert!(!buffer.is_null());
+    assert_eq!(buffer.as_raw(), 0x1234);
+    assert_eq!(buffer, vk::Buffer::from_raw(0x1234));
+    assert_eq!(std::any::type_name::<<vk::Buffer as Handle>::Parent>(), std::any::type_name::<vk::Device>());
+    assert_eq!(std::any::type_name::<<vk::Instance as Handle>::Parent>(), "()");
+}
+
+#[test]
+fn format_metadata() {
+    let format = vk::Format::Bc7SrgbBlock;
+    assert!(format.is_srgb() && format.is_compressed());
+    assert_eq!(format.compression_class(), Some(vk::FormatCompressionClass::Bc));
+    assert_eq!((format.texel_block_extent().width, format.texel_block_extent().height), (4, 4));
+    assert_eq!(format.to_linear(), vk::Format::Bc7UnormBlock);
+    assert_eq!(vk::Format::Bc7UnormBlock.to_srgb(), Some(format));
+    assert_eq!(vk::Format::R32Sfloat.to_srgb(), None);
+    let components = vk::Format::D24UnormS8Uint.components();
+    assert_eq!(components.len(), 2);
+    assert_eq!(components[0].name, vk::FormatComponentName::D);
+    assert_eq!(components[0].bits, Some(24));
+    assert_eq!(components[1].numeric_type, vk::FormatNumericType::Uint);
+    assert!(vk::Format::D24UnormS8Uint.is_depth() && vk::Format::D24UnormS8Uint.is_stencil());
+    assert!(!vk::Format::R8g8b8a8Unorm.is_depth() && !vk::Format::R8g8b8a8Unorm.is_compressed());
+    assert_eq!(vk::Format::G8B8r82plane420Unorm.plane_count(), 2);
+    assert_eq!(vk::Format::G8B8r82plane420Unorm.plane_format(1), Some(vk::Format::R8g8Unorm));
+    assert_eq!(vk::Format::G8B8r82plane420Unorm.plane_format(2), None);
+}
+
+#[cfg(all(debug_assertions, feature = "valid-usage"))]
+#[test]
+fn valid_usage() {
+    use vk::ValidUsage;
+    let queue_priorities = [1.0];
+    let queue_create_info = vk::DeviceQueueCreateInfo {
+        queue_count: 1,
+        p_queue_priorities: queue_priorities.as_ptr(),
+        ..vk::DeviceQueueCreateInfo::new()
+    };
+    let mut features = vk::PhysicalDeviceVulkan13Features::new();
+    let mut create_info = vk::DeviceCreateInfo {
+        queue_create_info_count: 1,
+        p_queue_create_infos: &raw const queue_create_info,
+        ..vk::DeviceCreateInfo::new()
+    };
+    unsafe {
+        create_info.push_next(&mut features);
+        create_info.check_valid_usage("vkCreateDevice");
+    }
+}
+
+#[cfg(all(debug_assertions, feature = "valid-usage"))]
+#[test]
+#[should_panic(expected = "vkCreateDevice: VkDeviceQueueCreateInfo::queueCount must be greater than 0")]
+fn valid_usage_count() {
+    use vk::ValidUsage;
+    let queue_create_info = vk::DeviceQueueCreateInfo::new();
+    let create_info = vk::DeviceCreateInfo {
+        queue_create_info_count: 1,
+        p_queue_create_infos: &raw const queue_create_info,
+        ..vk::DeviceCreateInfo::new()
+    };
+    unsafe { create_info.check_valid_usage("vkCreateDevice") };
+}
+
+#[cfg(all(debug_assertions, feature = "valid-usage"))]
+#[test]
+#[should_panic(expected = "vkCreateBuffer: PhysicalDeviceVulkan13Features can not be chained to VkBufferCreateInfo")]
+fn valid_usage_p_next() {
+    use vk::ValidUsage;
+    let features = vk::PhysicalDeviceVulkan13Features::new();
+    let create_info = vk::BufferCreateInfo {
+        p_next: (&raw const features).cast(),
+        ..vk::BufferCreateInfo::new()
+    };
+    unsafe { create_info.check_valid_usage("vkCreateBuffer") };
+}
 "#;
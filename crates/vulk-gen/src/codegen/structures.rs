@@ -8,6 +8,40 @@ pub struct {{rs_ident}} {
 }
 "#;
 
+const TEMPLATE_NEW: &str = r#"impl Default for {{rs_ident}} {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl {{rs_ident}} {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            {{rs_new_members}}
+        }
+    }
+}
+"#;
+
+const TEMPLATE_NEW_MEMBER: &str = r#"{{rs_member_ident}}: {{rs_member_value}},"#;
+
+const TEMPLATE_EXTENDS: &str = r#"#[doc = "Structures that can be chained into [`{{rs_ident}}`] with [`{{rs_ident}}::push_next`]."]
+pub unsafe trait Extends{{rs_ident}} {}
+
+impl {{rs_ident}} {
+    #[doc = "Prepends `next`, together with anything already chained behind it, to `p_next`."]
+    #[doc = "<br>"]
+    #[doc = "**Safety**: `next` must outlive every use of this structure."]
+    pub unsafe fn push_next<T: Extends{{rs_ident}}>(&mut self, next: &mut T) -> &mut Self {
+        self.p_next = {{rs_push_next}};
+        self
+    }
+}
+"#;
+
+const TEMPLATE_EXTENDS_IMPL: &str = r#"unsafe impl Extends{{rs_root_ident}} for {{rs_ident}} {}"#;
+
 const TEMPLATE_MEMBER: &str = r#"pub {{rs_member_ident}}: {{rs_member_type}},"#;
 
 const TEMPLATE_RAW_STRING: &str = "r#\"```{{}}```\"#";
@@ -24,10 +58,24 @@ const TEMPLATE_INIT_MEMBER: &str = r#"    {{rs_init_member_ident}}: {{rs_init_me
 
 pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
     let extend_map = extend_map(&ctx.registry.types);
+    let initializers = initializers::Initializers::new(ctx)?;
+    let valid_usage = valid_usage::ValidUsage::new(ctx)?;
+    let struct_set = ctx
+        .registry
+        .types
+        .iter()
+        .filter(|ty| matches!(ty.category, registry::TypeCategory::Struct { .. }))
+        .map(|ty| ty.name.as_str())
+        .collect::<HashSet<_>>();
 
     let mut str = String::new();
     for registry_type in &ctx.registry.types {
-        let registry::TypeCategory::Struct { members, .. } = &registry_type.category else {
+        let registry::TypeCategory::Struct {
+            members,
+            structextends,
+            ..
+        } = &registry_type.category
+        else {
             continue;
         };
 
@@ -150,6 +198,74 @@ pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
                 .replace("{{rs_ident}}", &rs_ident)
                 .replace("{{rs_members}}", &rs_members)
         )?;
+
+        // Constructors.
+        let mut rs_new_members = String::new();
+        for member in &members {
+            let rs_member_ident = translation::vk_simple_ident(&member.name)?;
+            let rs_member_value = initializers
+                .member(ctx, &rs_ident, member)
+                .with_context(|| format!("Initializing member={}", member.name))?;
+            writeln!(
+                rs_new_members,
+                "{}",
+                TEMPLATE_NEW_MEMBER
+                    .replace("{{rs_member_ident}}", &rs_member_ident)
+                    .replace("{{rs_member_value}}", &rs_member_value)
+            )?;
+        }
+        writeln!(
+            str,
+            "{}",
+            TEMPLATE_NEW
+                .replace("{{rs_ident}}", &rs_ident)
+                .replace("{{rs_new_members}}", &rs_new_members)
+        )?;
+
+        // Structures which can be extended get a marker trait and `push_next`.
+        if extend_map.contains_key(vk_ident.as_str()) {
+            let p_next = members
+                .iter()
+                .find(|member| member.name == "pNext")
+                .context("Extendable structure is missing pNext")?;
+            let vk_text = p_next.text.as_ref().context("Missing text")?;
+            let rs_push_next = match translation::c_specifier(vk_text)?.as_str() {
+                "*const" => "push_next(self.p_next.cast_mut(), std::ptr::from_mut(next).cast()).cast_const()",
+                "*mut" => "push_next(self.p_next, std::ptr::from_mut(next).cast())",
+                specifier => bail!("Unknown specifier {specifier}"),
+            };
+            writeln!(
+                str,
+                "{}",
+                TEMPLATE_EXTENDS
+                    .replace("{{rs_ident}}", &rs_ident)
+                    .replace("{{rs_push_next}}", rs_push_next)
+            )?;
+        }
+
+        // Structures which extend others implement their marker traits.
+        let mut rs_extends_impls = String::new();
+        for structextend in structextends {
+            if !struct_set.contains(structextend.as_str()) {
+                continue;
+            }
+            let rs_root_ident = translation::vk_simple_type(structextend)?;
+            writeln!(
+                rs_extends_impls,
+                "{}",
+                TEMPLATE_EXTENDS_IMPL
+                    .replace("{{rs_root_ident}}", &rs_root_ident)
+                    .replace("{{rs_ident}}", &rs_ident)
+            )?;
+        }
+        if !rs_extends_impls.is_empty() {
+            writeln!(str, "{rs_extends_impls}")?;
+        }
+
+        // Structures passed to commands check their implicit valid usage.
+        if let Some(rs_valid_usage) = valid_usage.structure(vk_ident, &rs_ident, &members)? {
+            writeln!(str, "{rs_valid_usage}")?;
+        }
     }
 
     Ok(str)
@@ -273,6 +389,8 @@ fn collapse_bitfields(
                         comment: None,
                         text: None,
                         en: None,
+                        len: None,
+                        noautovalidity: None,
                     });
                 }
 
@@ -28,7 +28,7 @@ pub fn c_value(c: &str) -> Result<String> {
 }
 
 pub fn c_specifier(c: &str) -> Result<String> {
-    let rs = if c == "*" {
+    let rs = if c == "*" || c == "struct*" {
         "*mut".to_string()
     } else if c == "const*" {
         "*const".to_string()
@@ -359,6 +359,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_c_specifier() {
+        #[rustfmt::skip]
+        let cases = [
+            ("*", "*mut"),
+            ("const*", "*const"),
+            ("**", "*mut *mut"),
+            ("const*const*", "*const *const"),
+            ("struct*", "*mut"),
+        ];
+        for (input, expect) in cases {
+            assert_eq!(c_specifier(input).unwrap(), expect);
+        }
+        assert!(c_specifier("&").is_err());
+    }
+
     #[test]
     fn test_vk_enum_digits() {
         let name = "VkImageViewType";
@@ -12,9 +12,27 @@ impl std::fmt::Debug for {{rs_ident}} {
 }
 "#;
 
+const TEMPLATE_NEW: &str = r#"impl Default for {{rs_ident}} {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl {{rs_ident}} {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            {{rs_member_ident}}: {{rs_member_value}},
+        }
+    }
+}
+"#;
+
 const TEMPLATE_MEMBER: &str = r#"pub {{rs_member_ident}}: {{rs_member_type}},"#;
 
 pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
+    let initializers = initializers::Initializers::new(ctx)?;
+
     let mut str = String::new();
 
     for registry_type in &ctx.registry.types {
@@ -64,6 +82,21 @@ pub fn generate(ctx: &GeneratorContext<'_>) -> Result<String> {
                 .replace("{{rs_ident}}", &rs_ident)
                 .replace("{{rs_members}}", &rs_members)
         )?;
+
+        // Constructors initialize the first member.
+        let first_member = members.first().context("Union has no members")?;
+        let rs_member_ident = translation::vk_simple_ident(&first_member.name)?;
+        let rs_member_value = initializers
+            .member(ctx, &rs_ident, first_member)
+            .with_context(|| format!("Initializing member={}", first_member.name))?;
+        writeln!(
+            str,
+            "{}",
+            TEMPLATE_NEW
+                .replace("{{rs_ident}}", &rs_ident)
+                .replace("{{rs_member_ident}}", &rs_member_ident)
+                .replace("{{rs_member_value}}", &rs_member_value)
+        )?;
     }
 
     Ok(str)
@@ -0,0 +1,367 @@
+use super::*;
+
+const TEMPLATE_IMPL: &str = r#"#[cfg(all(debug_assertions, feature = "valid-usage"))]
+impl ValidUsage for {{rs_ident}} {
+    unsafe fn check_valid_usage(&self, command: &str) {
+        {{rs_checks}}
+    }
+}
+"#;
+
+const TEMPLATE_WRAPPER: &str = r#"#[cfg(all(debug_assertions, feature = "valid-usage"))]
+{
+    {{rs_checks}}
+}
+"#;
+
+const TEMPLATE_S_TYPE: &str =
+    r#"check_s_type({{rs_command}}, "{{vk_ident}}", self.s_type, StructureType::{{rs_ident}});"#;
+const TEMPLATE_P_NEXT: &str =
+    r#"check_p_next({{rs_command}}, "{{vk_ident}}", {{rs_p_next}}, &[{{rs_allowed}}]);"#;
+const TEMPLATE_COUNT: &str =
+    r#"{{rs_vk}}check_count({{rs_command}}, "{{vk_name}}", {{rs_count}});"#;
+const TEMPLATE_ARRAY: &str =
+    r#"{{rs_vk}}check_array({{rs_command}}, "{{vk_name}}", {{rs_pointer}}, {{rs_count}});"#;
+const TEMPLATE_PTR: &str = r#"{{rs_vk}}check_ptr({{rs_command}}, "{{vk_name}}", {{rs_pointer}});"#;
+const TEMPLATE_STRUCTS: &str =
+    r#"{{rs_vk}}check_structs({{rs_command}}, {{rs_pointer}}, {{rs_count}});"#;
+
+/// Implicit valid usage encoded in `vk.xml`: `sType` values, `pNext` chains
+/// allowed by `structextends`, non-null pointers and non-zero counts that are
+/// not marked `optional`. Only structures reachable through `const` pointers
+/// from the generated commands are checked.
+pub struct ValidUsage<'a> {
+    extend_map: HashMap<&'a str, Vec<&'a str>>,
+    checked: HashSet<&'a str>,
+}
+
+impl<'a> ValidUsage<'a> {
+    pub fn new(ctx: &GeneratorContext<'a>) -> Result<Self> {
+        let types = ctx
+            .registry
+            .types
+            .iter()
+            .map(|ty| (ty.name.as_str(), &ty.category))
+            .collect::<HashMap<_, _>>();
+
+        let mut extend_map: HashMap<_, Vec<_>> = HashMap::new();
+        for ty in &ctx.registry.types {
+            let registry::TypeCategory::Struct { structextends, .. } = &ty.category else {
+                continue;
+            };
+            for structextend in structextends {
+                extend_map
+                    .entry(structextend.as_str())
+                    .or_default()
+                    .push(ty.name.as_str());
+            }
+        }
+
+        // Commands are the roots, structures are checked transitively.
+        let mut pending = vec![];
+        for command in &ctx.registry.commands {
+            for param in &command.params {
+                if is_const_pointer(param.text.as_deref())? {
+                    pending.push(param.ty.as_str());
+                }
+            }
+        }
+        let mut checked = HashSet::new();
+        while let Some(vk_ident) = pending.pop() {
+            let Some(registry::TypeCategory::Struct { members, .. }) = types.get(vk_ident) else {
+                continue;
+            };
+            if !members.iter().any(|member| member.name == "sType") {
+                continue;
+            }
+            if !checked.insert(vk_ident) {
+                continue;
+            }
+            for member in members {
+                if member.name != "pNext" && is_const_pointer(member.text.as_deref())? {
+                    pending.push(member.ty.as_str());
+                }
+            }
+        }
+
+        Ok(Self {
+            extend_map,
+            checked,
+        })
+    }
+
+    fn is_checked(&self, vk_ident: &str) -> bool {
+        self.checked.contains(vk_ident)
+    }
+
+    /// Renders the `ValidUsage` implementation of a checked structure.
+    pub fn structure(
+        &self,
+        vk_ident: &str,
+        rs_ident: &str,
+        members: &[registry::TypeMember],
+    ) -> Result<Option<String>> {
+        if !self.is_checked(vk_ident) {
+            return Ok(None);
+        }
+
+        let siblings = members
+            .iter()
+            .map(|member| {
+                let count = (
+                    member.ty.as_str(),
+                    member.optional.as_deref(),
+                    member.noautovalidity.is_some(),
+                );
+                (member.name.as_str(), count)
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut rs_checks = String::new();
+        for member in members {
+            let rs_member_ident = translation::vk_simple_ident(&member.name)?;
+            match member.name.as_str() {
+                "sType" => {
+                    writeln!(
+                        rs_checks,
+                        "{}",
+                        TEMPLATE_S_TYPE
+                            .replace("{{vk_ident}}", vk_ident)
+                            .replace("{{rs_ident}}", rs_ident)
+                    )?;
+                }
+                "pNext" => {
+                    let rs_p_next = match translation::c_specifier(
+                        member.text.as_deref().context("Missing text")?,
+                    )?
+                    .as_str()
+                    {
+                        "*const" => "self.p_next",
+                        "*mut" => "self.p_next.cast_const()",
+                        specifier => bail!("Unknown specifier {specifier}"),
+                    };
+                    let rs_allowed = self
+                        .extend_map
+                        .get(vk_ident)
+                        .into_iter()
+                        .flatten()
+                        .map(|vk_extend_ident| {
+                            Ok(format!(
+                                "StructureType::{}",
+                                translation::vk_simple_type(vk_extend_ident)?
+                            ))
+                        })
+                        .collect::<Result<Vec<_>>>()?
+                        .join(", ");
+                    writeln!(
+                        rs_checks,
+                        "{}",
+                        TEMPLATE_P_NEXT
+                            .replace("{{vk_ident}}", vk_ident)
+                            .replace("{{rs_p_next}}", rs_p_next)
+                            .replace("{{rs_allowed}}", &rs_allowed)
+                    )?;
+                }
+                _ => {
+                    let pointer = Pointer {
+                        name: &member.name,
+                        ty: &member.ty,
+                        text: member.text.as_deref(),
+                        optional: member.optional.as_deref(),
+                        len: member.len.as_deref(),
+                        noautovalidity: member.noautovalidity.is_some(),
+                    };
+                    self.pointer(
+                        &mut rs_checks,
+                        &pointer,
+                        &format!("{vk_ident}::"),
+                        &format!("self.{rs_member_ident}"),
+                        |name| {
+                            let count = siblings.get(name)?;
+                            let rs_count_ident = translation::vk_simple_ident(name).ok()?;
+                            Some((format!("self.{rs_count_ident}"), *count))
+                        },
+                    )?;
+                }
+            }
+        }
+
+        let rs_checks = rs_checks
+            .replace("{{rs_command}}", "command")
+            .replace("{{rs_vk}}", "");
+        Ok(Some(
+            TEMPLATE_IMPL
+                .replace("{{rs_ident}}", rs_ident)
+                .replace("{{rs_checks}}", rs_checks.trim_end()),
+        ))
+    }
+
+    /// Renders the checks of a wrapper's visible parameters, or nothing if
+    /// none of them have implicit valid usage.
+    pub fn command(
+        &self,
+        command: &registry::Command,
+        visible_params: &[&registry::CommandParam],
+    ) -> Result<String> {
+        let vk_ident = &command.name;
+        let params = visible_params
+            .iter()
+            .map(|param| {
+                let count = (
+                    param.ty.as_str(),
+                    param.optional.as_deref(),
+                    param.noautovalidity.is_some(),
+                );
+                (param.name.as_str(), count)
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut rs_checks = String::new();
+        for param in visible_params {
+            let pointer = Pointer {
+                name: &param.name,
+                ty: &param.ty,
+                text: param.text.as_deref(),
+                optional: param.optional.as_deref(),
+                len: param.len.as_deref(),
+                noautovalidity: param.noautovalidity.is_some(),
+            };
+            self.pointer(
+                &mut rs_checks,
+                &pointer,
+                "",
+                &translation::vk_simple_ident(&param.name)?,
+                |name| {
+                    let count = params.get(name)?;
+                    let rs_count_ident = translation::vk_simple_ident(name).ok()?;
+                    Some((rs_count_ident, *count))
+                },
+            )?;
+        }
+        if rs_checks.is_empty() {
+            return Ok(String::new());
+        }
+
+        let rs_checks = rs_checks
+            .replace("{{rs_command}}", &format!(r#""{vk_ident}""#))
+            .replace("{{rs_vk}}", "vk::");
+        Ok(TEMPLATE_WRAPPER.replace("{{rs_checks}}", rs_checks.trim_end()))
+    }
+
+    /// Checks a pointer and the count it is sized by. `count` resolves a
+    /// sibling name into its expression, type, `optional` and
+    /// `noautovalidity`.
+    fn pointer<'b>(
+        &self,
+        rs_checks: &mut String,
+        pointer: &Pointer<'_>,
+        vk_prefix: &str,
+        rs_pointer: &str,
+        count: impl Fn(&str) -> Option<(String, (&'b str, Option<&'b str>, bool))>,
+    ) -> Result<()> {
+        if pointer.noautovalidity || !is_const_pointer(pointer.text)? {
+            return Ok(());
+        }
+        let vk_name = format!("{vk_prefix}{}", pointer.name);
+        let pointer_optional = is_optional(pointer.optional);
+
+        // Arrays sized by a sibling count. Counts which are not plain
+        // integers, such as `latexmath` expressions, are not checked.
+        let vk_count_name = pointer
+            .len
+            .and_then(|len| len.split(',').next())
+            .filter(|&len| len != "null-terminated");
+        if let Some(vk_count_name) = vk_count_name {
+            let Some((rs_count, (vk_count_type, vk_count_optional, vk_count_noautovalidity))) =
+                count(vk_count_name)
+            else {
+                return Ok(());
+            };
+            let rs_count_usize = match vk_count_type {
+                "uint32_t" => format!("{rs_count} as usize"),
+                "size_t" => rs_count.clone(),
+                _ => return Ok(()),
+            };
+            // Counts shared by several arrays are only checked once.
+            let rs_count_check = TEMPLATE_COUNT
+                .replace("{{vk_name}}", &format!("{vk_prefix}{vk_count_name}"))
+                .replace("{{rs_count}}", &rs_count);
+            if !is_optional(vk_count_optional)
+                && !vk_count_noautovalidity
+                && !rs_checks.contains(&rs_count_check)
+            {
+                writeln!(rs_checks, "{rs_count_check}")?;
+            }
+            if !pointer_optional {
+                writeln!(
+                    rs_checks,
+                    "{}",
+                    TEMPLATE_ARRAY
+                        .replace("{{vk_name}}", &vk_name)
+                        .replace("{{rs_pointer}}", rs_pointer)
+                        .replace("{{rs_count}}", &rs_count_usize)
+                )?;
+            }
+            if self.is_checked(pointer.ty) && !is_double_pointer(pointer.text) {
+                writeln!(
+                    rs_checks,
+                    "{}",
+                    TEMPLATE_STRUCTS
+                        .replace("{{rs_pointer}}", rs_pointer)
+                        .replace("{{rs_count}}", &rs_count_usize)
+                )?;
+            }
+            return Ok(());
+        }
+
+        // Single pointers and null-terminated strings.
+        if !pointer_optional {
+            writeln!(
+                rs_checks,
+                "{}",
+                TEMPLATE_PTR
+                    .replace("{{vk_name}}", &vk_name)
+                    .replace("{{rs_pointer}}", rs_pointer)
+            )?;
+        }
+        if self.is_checked(pointer.ty) && !is_double_pointer(pointer.text) {
+            writeln!(
+                rs_checks,
+                "{}",
+                TEMPLATE_STRUCTS
+                    .replace("{{rs_pointer}}", rs_pointer)
+                    .replace("{{rs_count}}", "1")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+struct Pointer<'a> {
+    name: &'a str,
+    ty: &'a str,
+    text: Option<&'a str>,
+    optional: Option<&'a str>,
+    len: Option<&'a str>,
+    noautovalidity: bool,
+}
+
+fn is_const_pointer(text: Option<&str>) -> Result<bool> {
+    let Some(text) = text else {
+        return Ok(false);
+    };
+    if !text.contains('*') {
+        return Ok(false);
+    }
+    Ok(translation::c_specifier(text)?.starts_with("*const"))
+}
+
+fn is_double_pointer(text: Option<&str>) -> bool {
+    text.is_some_and(|text| text.matches('*').count() > 1)
+}
+
+/// The first value of `optional` refers to the member itself, the rest to
+/// what it points to.
+fn is_optional(optional: Option<&str>) -> bool {
+    optional.and_then(|optional| optional.split(',').next()) == Some("true")
+}
@@ -7,6 +7,7 @@
     clippy::needless_raw_string_hashes,
     clippy::struct_field_names,
     clippy::too_many_lines,
+    clippy::unnecessary_trailing_comma,
     clippy::unnecessary_wraps,
     clippy::wildcard_imports
 )]
@@ -18,5 +18,13 @@ pub fn c_type_map() -> CtypeMap {
     map.insert("uint64_t", "u64");
     map.insert("HINSTANCE", "*const c_void");
     map.insert("HWND", "*const c_void");
+    map.insert("Display", "c_void");
+    map.insert("Window", "c_ulong");
+    map.insert("VisualID", "c_ulong");
+    map.insert("xcb_connection_t", "c_void");
+    map.insert("xcb_window_t", "u32");
+    map.insert("xcb_visualid_t", "u32");
+    map.insert("wl_display", "c_void");
+    map.insert("wl_surface", "c_void");
     map
 }
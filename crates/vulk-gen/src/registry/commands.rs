@@ -16,6 +16,7 @@ pub struct CommandParam {
     pub optional: Option<String>,
     pub text: Option<String>,
     pub len: Option<String>,
+    pub noautovalidity: Option<String>,
 }
 
 pub(super) fn parse_commands<'a>(
@@ -51,12 +52,14 @@ pub(super) fn parse_commands<'a>(
                     let optional = node.attribute("optional");
                     let text = node.joined_children_text();
                     let len = node.attribute("len");
+                    let noautovalidity = node.attribute("noautovalidity");
                     Some(CommandParam {
                         name,
                         ty,
                         optional,
                         text,
                         len,
+                        noautovalidity,
                     })
                 })
                 .collect(),
@@ -10,6 +10,13 @@ pub enum Component {
     D,
 }
 
+#[derive(serde::Serialize, Debug)]
+pub struct FormatComponent {
+    pub name: Component,
+    pub bits: Option<u32>,
+    pub numeric_format: String,
+}
+
 #[derive(serde::Serialize, Debug)]
 pub enum Plane {
     P0,
@@ -17,12 +24,20 @@ pub enum Plane {
     P2,
 }
 
+#[derive(serde::Serialize, Debug)]
+pub struct FormatPlane {
+    pub index: Plane,
+    pub compatible: String,
+}
+
 #[derive(serde::Serialize, Debug)]
 pub struct Format {
     pub name: String,
     pub block_size: u32,
-    pub components: Vec<Component>,
-    pub planes: Vec<Plane>,
+    pub block_extent: Option<[u32; 3]>,
+    pub compressed: Option<String>,
+    pub components: Vec<FormatComponent>,
+    pub planes: Vec<FormatPlane>,
 }
 
 pub(super) fn parse_formats<'a>(nodes: impl Iterator<Item = xml::Node<'a>>) -> Result<Vec<Format>> {
@@ -32,11 +47,24 @@ pub(super) fn parse_formats<'a>(nodes: impl Iterator<Item = xml::Node<'a>>) -> R
         let name = node.required_attribute("name");
         let block_size = node.required_attribute("blockSize");
         let block_size: u32 = block_size.parse()?;
+        let block_extent = if let Some(block_extent) = node.attribute("blockExtent") {
+            let extent = block_extent
+                .split(',')
+                .map(str::parse)
+                .collect::<Result<Vec<u32>, _>>()?;
+            let Ok(extent) = <[u32; 3]>::try_from(extent) else {
+                bail!("Invalid blockExtent={block_extent}");
+            };
+            Some(extent)
+        } else {
+            None
+        };
+        let compressed = node.attribute("compressed");
 
         let mut components = vec![];
         for component in node.children_elements("component") {
             let component_name = component.required_attribute("name");
-            let component = match component_name.as_str() {
+            let component_name = match component_name.as_str() {
                 "R" => Component::R,
                 "G" => Component::G,
                 "B" => Component::B,
@@ -47,7 +75,17 @@ pub(super) fn parse_formats<'a>(nodes: impl Iterator<Item = xml::Node<'a>>) -> R
                     bail!("Unknown component={component}");
                 }
             };
-            components.push(component);
+            let bits = component.required_attribute("bits");
+            let bits = match bits.as_str() {
+                "compressed" => None,
+                bits => Some(bits.parse()?),
+            };
+            let numeric_format = component.required_attribute("numericFormat");
+            components.push(FormatComponent {
+                name: component_name,
+                bits,
+                numeric_format,
+            });
         }
 
         let mut planes = vec![];
@@ -61,12 +99,15 @@ pub(super) fn parse_formats<'a>(nodes: impl Iterator<Item = xml::Node<'a>>) -> R
                     bail!("Unknown plane index={index}");
                 }
             };
-            planes.push(index);
+            let compatible = plane.required_attribute("compatible");
+            planes.push(FormatPlane { index, compatible });
         }
 
         output.push(Format {
             name,
             block_size,
+            block_extent,
+            compressed,
             components,
             planes,
         });
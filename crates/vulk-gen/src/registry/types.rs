@@ -54,6 +54,8 @@ pub struct TypeMember {
     pub comment: Option<String>,
     pub text: Option<String>,
     pub en: Option<String>,
+    pub len: Option<String>,
+    pub noautovalidity: Option<String>,
 }
 
 pub(super) fn parse_types<'a>(nodes: impl Iterator<Item = xml::Node<'a>>) -> Result<Vec<Type>> {
@@ -172,6 +174,8 @@ pub(super) fn parse_types<'a>(nodes: impl Iterator<Item = xml::Node<'a>>) -> Res
                                     comment: node.child_text("comment"),
                                     text: node.joined_children_text(),
                                     en: node.child_text("enum"),
+                                    len: node.attribute("len"),
+                                    noautovalidity: node.attribute("noautovalidity"),
                                 };
 
                                 let api = node.attribute("api");
@@ -210,6 +214,8 @@ pub(super) fn parse_types<'a>(nodes: impl Iterator<Item = xml::Node<'a>>) -> Res
                                 let comment = node.child_text("comment");
                                 let text = node.joined_children_text();
                                 let en = node.child_text("enum");
+                                let len = node.attribute("len");
+                                let noautovalidity = node.attribute("noautovalidity");
                                 TypeMember {
                                     name,
                                     ty,
@@ -217,6 +223,8 @@ pub(super) fn parse_types<'a>(nodes: impl Iterator<Item = xml::Node<'a>>) -> Res
                                     comment,
                                     text,
                                     en,
+                                    len,
+                                    noautovalidity,
                                 }
                             })
                             .collect(),
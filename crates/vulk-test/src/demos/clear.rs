@@ -25,7 +25,7 @@ impl DemoCallbacks for Demo {
         let timestamps = vkx::TimestampQuery::create(&gpu.physical_device, &gpu.device, 2)?;
         let statistics = vkx::StatisticsQuery::create(&gpu.device)?;
         let render_targets = RenderTargets::create(gpu, &())?;
-        let output = OutputImage::create(&gpu.physical_device, &gpu.device)?;
+        let output = OutputImage::create(&gpu.device)?;
         Ok(Self {
             command_buffer,
             command_buffer_done,
@@ -39,7 +39,7 @@ impl DemoCallbacks for Demo {
                 descriptors: &descriptors,
             },
         )?;
-        let output = OutputImage::create(&gpu.physical_device, &gpu.device)?;
+        let output = OutputImage::create(&gpu.device)?;
 
         Ok(Self {
             command_buffer,
@@ -161,7 +161,6 @@ impl GpuResource for Descriptors {
         // Descriptor storage.
         let stages = vk::ShaderStageFlagBits::Compute.into();
         let storage = vkx::DescriptorStorage::create(
-            &gpu.physical_device,
             &gpu.device,
             &[
                 vkx::DescriptorBinding {
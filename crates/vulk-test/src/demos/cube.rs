@@ -41,7 +41,7 @@ impl DemoCallbacks for Demo {
             },
         )?;
         let render_targets = RenderTargets::create(gpu, &())?;
-        let output = OutputImage::create(&gpu.physical_device, &gpu.device)?;
+        let output = OutputImage::create(&gpu.device)?;
         Ok(Self {
             command_buffer,
             command_buffer_done,
@@ -149,7 +149,6 @@ impl GpuResource for Textures {
 
         // Upload.
         vkx::transfer_resources(
-            &gpu.physical_device,
             &gpu.device,
             &[],
             &[],
@@ -239,7 +238,6 @@ impl GpuResource for Descriptors {
             .map(vkx::SamplerResource::descriptor)
             .collect::<Vec<_>>();
         let storage = vkx::DescriptorStorage::create(
-            &gpu.physical_device,
             &gpu.device,
             &[
                 vkx::DescriptorBinding {
@@ -35,7 +35,7 @@ impl DemoCallbacks for Demo {
         let descriptors = Descriptors::create(gpu, &geometry, &textures)?;
         let shaders = Shaders::create(gpu, &descriptors)?;
         let render_targets = RenderTargets::create(gpu)?;
-        let output = OutputImage::create(&gpu.physical_device, &gpu.device)?;
+        let output = OutputImage::create(&gpu.device)?;
 
         Ok(Self {
             gui,
@@ -252,7 +252,6 @@ impl Geometry {
 
         // Transfer.
         vkx::transfer_resources(
-            &gpu.physical_device,
             &gpu.device,
             &buffer_resources,
             &[vertex_data, index_data],
@@ -302,7 +301,6 @@ impl Textures {
             vk::MemoryPropertyFlagBits::DeviceLocal,
         )?;
         vkx::transfer_resources(
-            &gpu.physical_device,
             &gpu.device,
             &[],
             &[],
@@ -364,7 +362,6 @@ impl Descriptors {
             | vk::ShaderStageFlagBits::MeshEXT
             | vk::ShaderStageFlagBits::Fragment;
         let storage = vkx::DescriptorStorage::create(
-            &gpu.physical_device,
             &gpu.device,
             &[
                 vkx::DescriptorBinding {
@@ -625,7 +622,6 @@ impl GpuResource for Output {
         Self: Sized,
     {
         let buffer = vkx::BufferDedicatedTransfer::create(
-            &gpu.physical_device,
             &gpu.device,
             vkx::BufferCreator::new(
                 DEFAULT_RENDER_TARGET_COLOR_BYTE_SIZE,
@@ -26,7 +26,6 @@ pub use cube::Demo as CubeDemo;
 pub use gui::Demo as GuiDemo;
 pub use raytracing::Demo as RaytracingDemo;
 pub use triangle::Demo as TriangleDemo;
-#[cfg(target_family = "windows")]
 pub use window::Demo as WindowDemo;
 
 //
@@ -54,13 +53,12 @@ struct OutputImage {
 }
 
 impl OutputImage {
-    unsafe fn create(physical_device: &vkx::PhysicalDevice, device: &vkx::Device) -> Result<Self>
+    unsafe fn create(device: &vkx::Device) -> Result<Self>
     where
         Self: Sized,
     {
         Ok(Self {
             buffer: vkx::BufferDedicatedTransfer::create(
-                physical_device,
                 device,
                 vkx::BufferCreator::new(
                     DEFAULT_RENDER_TARGET_COLOR_BYTE_SIZE,
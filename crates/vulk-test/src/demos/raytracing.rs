@@ -61,7 +61,7 @@ impl DemoCallbacks for Demo {
                 pipeline: &pipeline,
             },
         )?;
-        let output = OutputImage::create(&gpu.physical_device, &gpu.device)?;
+        let output = OutputImage::create(&gpu.device)?;
 
         Ok(Self {
             command_buffer,
@@ -179,7 +179,6 @@ impl GpuResource for Blas {
             type TransformType = vk::TransformMatrixKHR;
 
             let mut vertex_buffer = vkx::BufferDedicatedTransfer::create(
-                &gpu.physical_device,
                 &gpu.device,
                 vkx::BufferCreator::new(
                     (size_of::<VertexType>() * create_info.scene.vertex_data.len()) as _,
@@ -188,7 +187,6 @@ impl GpuResource for Blas {
                 vk::MemoryPropertyFlagBits::HostVisible | vk::MemoryPropertyFlagBits::HostCoherent,
             )?;
             let mut index_buffer = vkx::BufferDedicatedTransfer::create(
-                &gpu.physical_device,
                 &gpu.device,
                 vkx::BufferCreator::new(
                     (size_of::<IndexType>() * create_info.scene.index_data.len()) as _,
@@ -197,7 +195,6 @@ impl GpuResource for Blas {
                 vk::MemoryPropertyFlagBits::HostVisible | vk::MemoryPropertyFlagBits::HostCoherent,
             )?;
             let mut transform_buffer = vkx::BufferDedicatedTransfer::create(
-                &gpu.physical_device,
                 &gpu.device,
                 vkx::BufferCreator::new(
                     (size_of::<TransformType>() * create_info.scene.transform_data.len()) as _,
@@ -440,7 +437,6 @@ impl GpuResource for Tlas {
             };
 
             let mut instance_buffer = vkx::BufferDedicatedTransfer::create(
-                &gpu.physical_device,
                 &gpu.device,
                 vkx::BufferCreator::new(
                     size_of::<vk::AccelerationStructureInstanceKHR>() as _,
@@ -722,7 +718,6 @@ impl GpuResource for Descriptors {
 
         // Descriptor storage.
         let storage = vkx::DescriptorStorage::create(
-            &gpu.physical_device,
             &gpu.device,
             &[
                 vkx::DescriptorBinding {
@@ -1047,7 +1042,6 @@ impl GpuResource for Sbt {
 
         // Allocation.
         let buffer_allocations = vkx::BufferAllocations::allocate(
-            physical_device,
             device,
             &buffers,
             &buffer_create_infos,
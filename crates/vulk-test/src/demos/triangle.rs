@@ -27,7 +27,7 @@ impl DemoCallbacks for Demo {
         let statistics = vkx::StatisticsQuery::create(&gpu.device)?;
         let shaders = Shaders::create(gpu, &())?;
         let render_targets = RenderTargets::create(gpu, &())?;
-        let output = OutputImage::create(&gpu.physical_device, &gpu.device)?;
+        let output = OutputImage::create(&gpu.device)?;
         Ok(Self {
             command_buffer,
             command_buffer_done,
@@ -240,7 +240,6 @@ impl GpuResource for Output {
         Self: Sized,
     {
         let buffer = vkx::BufferDedicatedTransfer::create(
-            &gpu.physical_device,
             &gpu.device,
             vkx::BufferCreator::new(
                 DEFAULT_RENDER_TARGET_COLOR_BYTE_SIZE,
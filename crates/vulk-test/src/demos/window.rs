@@ -5,7 +5,7 @@ use super::*;
 //
 
 pub struct Demo {
-    window_system: WindowSystem,
+    window_system: Option<WindowSystem>,
     renderer: Renderer,
 }
 
@@ -16,11 +16,15 @@ impl DemoCallbacks for Demo {
     where
         Self: Sized,
     {
-        let window = WindowSystem::create()?;
+        let window = if headless() {
+            None
+        } else {
+            Some(WindowSystem::create()?)
+        };
         let renderer = Renderer::create(
             gpu,
             &RendererCreateInfo {
-                window_system: &window,
+                window_system: window.as_ref(),
             },
         )?;
         Ok(Self {
@@ -30,7 +34,11 @@ impl DemoCallbacks for Demo {
     }
 
     unsafe fn execute(gpu: &Gpu, state: &mut Self) -> Result<()> {
-        execute(gpu, &mut state.window_system, &state.renderer, Self::NAME)?;
+        if let Some(window_system) = &mut state.window_system {
+            execute(gpu, window_system, &state.renderer, Self::NAME)?;
+        } else {
+            execute_headless(gpu, &state.renderer, Self::NAME)?;
+        }
         gpu.device.device_wait_idle()?;
         Ok(())
     }
@@ -81,7 +89,7 @@ impl WindowSystem {
 //
 
 struct RendererCreateInfo<'a> {
-    window_system: &'a WindowSystem,
+    window_system: Option<&'a WindowSystem>,
 }
 
 struct Renderer {
@@ -97,11 +105,18 @@ impl GpuResource for Renderer {
     where
         Self: Sized,
     {
-        let surface = vkx::Surface::create(
-            &gpu.instance,
-            &gpu.physical_device,
-            &create_info.window_system.window,
-        )?;
+        let surface = if let Some(window_system) = create_info.window_system {
+            vkx::Surface::create(&gpu.instance, &gpu.physical_device, &window_system.window)?
+        } else {
+            vkx::Surface::create_headless(
+                &gpu.instance,
+                &gpu.physical_device,
+                vk::Extent2D {
+                    width: DEFAULT_RENDER_TARGET_WIDTH,
+                    height: DEFAULT_RENDER_TARGET_HEIGHT,
+                },
+            )?
+        };
         let swapchain = vkx::Swapchain::create(&gpu.device, &surface)?;
         let commands = Commands::create(
             gpu,
@@ -311,6 +326,16 @@ unsafe fn execute(
     frame_result.expect("Event loop should return a result on exit")
 }
 
+unsafe fn execute_headless(gpu: &Gpu, renderer: &Renderer, _: &str) -> Result<()> {
+    let mut frame_index = 0_u64;
+    for frame_count in 0..10 {
+        info!("Frame {frame_count},{frame_index}: Headless");
+        redraw(gpu, renderer, frame_index, frame_count)?;
+        frame_index = (frame_index + 1) % renderer.swapchain.image_count();
+    }
+    Ok(())
+}
+
 unsafe fn redraw(
     Gpu { device, .. }: &Gpu,
     Renderer {
@@ -327,15 +352,20 @@ unsafe fn redraw(
         .wait(device, frame_count, u64::MAX)?;
 
     // Acquire image.
-    let image_index = device.acquire_next_image2_khr(&vk::AcquireNextImageInfoKHR {
-        s_type: vk::StructureType::AcquireNextImageInfoKHR,
-        p_next: null(),
-        swapchain: swapchain.handle(),
-        timeout: u64::MAX,
-        semaphore: commands.present_complete(frame_index).handle(),
-        fence: vk::Fence::null(),
-        device_mask: 1,
-    })?;
+    let (image_index, acquire_result) =
+        device.acquire_next_image2_khr(&vk::AcquireNextImageInfoKHR {
+            s_type: vk::StructureType::AcquireNextImageInfoKHR,
+            p_next: null(),
+            swapchain: swapchain.handle(),
+            timeout: u64::MAX,
+            semaphore: commands.present_complete(frame_index).handle(),
+            fence: vk::Fence::null(),
+            device_mask: 1,
+        })?;
+    ensure!(
+        acquire_result == vk::Result::Success || acquire_result == vk::Result::SuboptimalKHR,
+        "got acquire_result={acquire_result}"
+    );
 
     // Begin command buffer.
     let command_buffer = commands.command_buffer(frame_index);
@@ -19,6 +19,7 @@
     clippy::struct_field_names,
     clippy::too_many_arguments,
     clippy::too_many_lines,
+    clippy::unnecessary_trailing_comma,
     clippy::wildcard_imports
 )]
 
@@ -64,7 +65,6 @@ fn main() -> Result<()> {
         run_demo::<demos::CubeDemo>(&gpu)?;
         run_demo::<demos::GuiDemo>(&gpu)?;
         run_demo::<demos::RaytracingDemo>(&gpu)?;
-        #[cfg(target_family = "windows")]
         run_demo::<demos::WindowDemo>(&gpu)?;
         gpu.destroy();
     };
@@ -88,13 +88,18 @@ impl Gpu {
     pub unsafe fn create() -> Result<Self> {
         let instance = vkx::Instance::create(&vkx::InstanceCreateInfo {
             validation_layers: true,
+            headless_surface: headless(),
             ..Default::default()
         })
         .context("Creating instance")?;
-        let physical_device =
-            vkx::PhysicalDevice::create(&instance).context("Creating physical device")?;
-        let device =
-            vkx::Device::create(&instance, &physical_device, None).context("Creating device")?;
+        let feature_request = vkx::DeviceFeatureRequest::default();
+        let physical_device = vkx::PhysicalDevice::create(
+            &instance,
+            &vkx::PhysicalDeviceSelector::for_device_features(&feature_request),
+        )
+        .context("Creating physical device")?;
+        let device = vkx::Device::create(&instance, &physical_device, None, &feature_request)
+            .context("Creating device")?;
 
         Ok(Self {
             instance,
@@ -152,6 +157,10 @@ trait GpuResource {
 // Utilities
 //
 
+fn headless() -> bool {
+    std::env::var_os("VULK_TEST_HEADLESS").is_some()
+}
+
 fn work_dir_or_create() -> Result<PathBuf> {
     let work_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("work");
     if !work_dir.exists() {
@@ -5,6 +5,7 @@
 //! - [`vk::GetDeviceProcAddr`] Return a function pointer for a command
 //! - [`vk::CreateInstance`] Create a new Vulkan instance
 //! - [`vk::DestroyInstance`] Destroy an instance of Vulkan
+//! - [`vk::EnumerateInstanceVersion`] Query instance-level version before instance creation
 //! ### Devices and Queues
 //! - [`vk::EnumeratePhysicalDevices`] Enumerates the physical devices accessible to a Vulkan instance
 //! - [`vk::GetPhysicalDeviceProperties2`] Returns properties of a physical device
@@ -118,7 +119,11 @@
 //! - [`vk::CmdDispatch`] Dispatch compute work items
 //! - [`vk::CmdDispatchIndirect`] Dispatch compute work items with indirect parameters
 //! ### Window System Integration (WSI)
+//! - [`vk::CreateWaylandSurfaceKHR`] Create a VkSurfaceKHR object for a Wayland window
 //! - [`vk::CreateWin32SurfaceKHR`] Create a VkSurfaceKHR object for an Win32 native window
+//! - [`vk::CreateXcbSurfaceKHR`] Create a VkSurfaceKHR object for a X11 window, using the XCB client-side library
+//! - [`vk::CreateXlibSurfaceKHR`] Create a VkSurfaceKHR object for an X11 window, using the Xlib client-side library
+//! - [`vk::CreateHeadlessSurfaceEXT`] Create a headless VkSurfaceKHR object
 //! - [`vk::DestroySurfaceKHR`] Destroy a VkSurfaceKHR object
 //! - [`vk::GetPhysicalDeviceSurfaceSupportKHR`] Query if presentation is supported
 //! - [`vk::GetPhysicalDeviceSurfaceCapabilitiesKHR`] Query surface capabilities
@@ -134,6 +139,12 @@
 //! ### Ray Tracing
 //! - [`vk::CmdTraceRaysKHR`] Initialize a ray tracing dispatch
 //! - [`vk::CmdTraceRaysIndirect2KHR`] Initialize an indirect ray tracing dispatch with indirect shader binding tables
+//! ### Extending Vulkan
+//! - [`vk::EnumerateInstanceLayerProperties`] Returns up to requested number of global layer properties
+//! - [`vk::EnumerateInstanceExtensionProperties`] Returns up to requested number of global extension properties
+//! - [`vk::EnumerateDeviceExtensionProperties`] Returns properties of available physical device extensions
+//! ### Features
+//! - [`vk::GetPhysicalDeviceFeatures2`] Reports capabilities of a physical device
 //! ### Additional Capabilities
 //! - [`vk::GetPhysicalDeviceCalibrateableTimeDomainsEXT`] Query calibrateable time domains
 //! ### Debugging
@@ -142,13 +153,18 @@
 //! ## Extensions
 //! - [`VK_KHR_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_surface.html)
 //! - [`VK_KHR_swapchain`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_swapchain.html)
+//! - [`VK_KHR_xlib_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_xlib_surface.html)
+//! - [`VK_KHR_xcb_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_xcb_surface.html)
+//! - [`VK_KHR_wayland_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_wayland_surface.html)
 //! - [`VK_KHR_win32_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_win32_surface.html)
 //! - [`VK_EXT_debug_utils`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_debug_utils.html)
 //! - [`VK_KHR_acceleration_structure`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_acceleration_structure.html)
 //! - [`VK_KHR_ray_tracing_pipeline`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_ray_tracing_pipeline.html)
 //! - [`VK_KHR_ray_query`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_ray_query.html)
 //! - [`VK_EXT_calibrated_timestamps`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_calibrated_timestamps.html)
+//! - [`VK_EXT_memory_budget`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_memory_budget.html)
 //! - [`VK_EXT_validation_features`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_validation_features.html)
+//! - [`VK_EXT_headless_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_headless_surface.html)
 //! - [`VK_KHR_deferred_host_operations`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_deferred_host_operations.html)
 //! - [`VK_KHR_map_memory2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_map_memory2.html)
 //! - [`VK_KHR_pipeline_library`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_pipeline_library.html)
@@ -174,6 +190,7 @@
     clippy::needless_raw_string_hashes,
     clippy::too_many_arguments,
     clippy::too_many_lines,
+    clippy::unnecessary_trailing_comma,
     clippy::unreadable_literal
 )]
 
@@ -200,6 +217,9 @@ pub use loader::Instance;
 
 pub const REQUIRED_VULKAN_VERSION: u32 = vk::make_api_version(0, 1, 3, 0);
 
+/// Environment variable that overrides the library loaded by [`Init::load`].
+pub const LIBRARY_PATH_ENV_VAR: &str = "VULK_LIBRARY_PATH";
+
 //
 // Error
 //
@@ -210,8 +230,26 @@ pub enum Error {
     LibraryLoad(std::borrow::Cow<'static, str>),
     #[error("failed to load function {0}")]
     FunctionLoad(std::borrow::Cow<'static, str>),
-    #[error("vulkan error {0:?}")]
-    Vulkan(vk::Result),
+    #[error(
+        "{command}{} failed with {result:?}",
+        create_info.map_or(String::new(), |create_info| format!(" ({create_info})"))
+    )]
+    Vulkan {
+        result: vk::Result,
+        command: &'static str,
+        create_info: Option<&'static str>,
+    },
+}
+
+impl Error {
+    /// The result code of a failed Vulkan command.
+    #[must_use]
+    pub fn result(&self) -> Option<vk::Result> {
+        match self {
+            Self::Vulkan { result, .. } => Some(*result),
+            _ => None,
+        }
+    }
 }
 
 //
@@ -272,6 +310,41 @@ pub const WIN32_INSTANCE_EXTENSIONS: [*const std::ffi::c_char; 1] =
 
 pub const WIN32_DEVICE_EXTENSIONS: [*const std::ffi::c_char; 0] = [];
 
+#[doc = "**Includes**: [`VK_KHR_wayland_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_wayland_surface.html)"]
+#[doc = "<br>"]
+pub const WAYLAND_INSTANCE_EXTENSIONS: [*const std::ffi::c_char; 1] =
+    [c"VK_KHR_wayland_surface".as_ptr().cast()];
+
+pub const WAYLAND_DEVICE_EXTENSIONS: [*const std::ffi::c_char; 0] = [];
+
+#[doc = "**Includes**: [`VK_KHR_xlib_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_xlib_surface.html)"]
+#[doc = "<br>"]
+pub const XLIB_INSTANCE_EXTENSIONS: [*const std::ffi::c_char; 1] =
+    [c"VK_KHR_xlib_surface".as_ptr().cast()];
+
+pub const XLIB_DEVICE_EXTENSIONS: [*const std::ffi::c_char; 0] = [];
+
+#[doc = "**Includes**: [`VK_KHR_xcb_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_xcb_surface.html)"]
+#[doc = "<br>"]
+pub const XCB_INSTANCE_EXTENSIONS: [*const std::ffi::c_char; 1] =
+    [c"VK_KHR_xcb_surface".as_ptr().cast()];
+
+pub const XCB_DEVICE_EXTENSIONS: [*const std::ffi::c_char; 0] = [];
+
+#[doc = "**Includes**: [`VK_EXT_headless_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_headless_surface.html)"]
+#[doc = "<br>"]
+pub const HEADLESS_INSTANCE_EXTENSIONS: [*const std::ffi::c_char; 1] =
+    [c"VK_EXT_headless_surface".as_ptr().cast()];
+
+pub const HEADLESS_DEVICE_EXTENSIONS: [*const std::ffi::c_char; 0] = [];
+
+pub const MEMORY_BUDGET_INSTANCE_EXTENSIONS: [*const std::ffi::c_char; 0] = [];
+
+#[doc = "**Includes**: [`VK_EXT_memory_budget`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_memory_budget.html)"]
+#[doc = "<br>"]
+pub const MEMORY_BUDGET_DEVICE_EXTENSIONS: [*const std::ffi::c_char; 1] =
+    [c"VK_EXT_memory_budget".as_ptr().cast()];
+
 #[doc = "**Includes**: [`VK_EXT_debug_utils`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_debug_utils.html)"]
 #[doc = "<br>"]
 #[doc = "**Includes**: [`VK_EXT_validation_features`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_validation_features.html)"]
@@ -283,46 +356,155 @@ pub const DEBUGGING_INSTANCE_EXTENSIONS: [*const std::ffi::c_char; 2] = [
 
 pub const DEBUGGING_DEVICE_EXTENSIONS: [*const std::ffi::c_char; 0] = [];
 
-//
-// Utilities
-//
+#[doc = "**Provided by**: [`VK_EXT_calibrated_timestamps`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_calibrated_timestamps.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_EXT_CALIBRATED_TIMESTAMPS_SPEC_VERSION`"]
+pub const EXT_CALIBRATED_TIMESTAMPS_SPEC_VERSION: u32 = 2;
 
-pub unsafe fn read_to_vec<F, T>(
-    f: F,
-    s_type: Option<vk::StructureType>,
-) -> Result<Vec<T>, Error>
-where
-    F: Fn(*mut u32, *mut T) -> Result<(), Error>,
-{
-    use std::alloc::alloc_zeroed;
-    use std::alloc::Layout;
-    use std::mem::size_of;
-    use std::mem::size_of_val;
-    use std::ptr::addr_of;
-
-    // Query the number of elements.
-    let mut len_u32 = 0_u32;
-    f(&raw mut len_u32, std::ptr::null_mut())?;
-    let len = len_u32 as usize;
-
-    // Allocate.
-    let layout = Layout::from_size_align(len * size_of::<T>(), 16).unwrap();
-    let ptr = alloc_zeroed(layout);
-
-    // Note: if the output type contains `s_type`, we have to write the
-    // structure type on each of the output elements.
-    if let Some(s_type) = s_type {
-        for i in 0..len {
-            let dst = ptr.add(i * size_of::<T>());
-            dst.copy_from(addr_of!(s_type).cast(), size_of_val(&s_type));
-        }
-    }
+#[doc = "**Provided by**: [`VK_EXT_debug_utils`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_debug_utils.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_EXT_DEBUG_UTILS_SPEC_VERSION`"]
+pub const EXT_DEBUG_UTILS_SPEC_VERSION: u32 = 2;
+
+#[doc = "**Provided by**: [`VK_EXT_descriptor_buffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_descriptor_buffer.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_EXT_DESCRIPTOR_BUFFER_SPEC_VERSION`"]
+pub const EXT_DESCRIPTOR_BUFFER_SPEC_VERSION: u32 = 1;
 
-    // Query elements.
-    f(&raw mut len_u32, ptr.cast())?;
+#[doc = "**Provided by**: [`VK_EXT_headless_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_headless_surface.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_EXT_HEADLESS_SURFACE_SPEC_VERSION`"]
+pub const EXT_HEADLESS_SURFACE_SPEC_VERSION: u32 = 1;
 
-    // Build the Vec.
-    let vec = Vec::from_raw_parts(ptr.cast::<T>(), len, len);
+#[doc = "**Provided by**: [`VK_EXT_memory_budget`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_memory_budget.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_EXT_MEMORY_BUDGET_SPEC_VERSION`"]
+pub const EXT_MEMORY_BUDGET_SPEC_VERSION: u32 = 1;
+
+#[doc = "**Provided by**: [`VK_EXT_mesh_shader`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_mesh_shader.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_EXT_MESH_SHADER_SPEC_VERSION`"]
+pub const EXT_MESH_SHADER_SPEC_VERSION: u32 = 1;
+
+#[doc = "**Provided by**: [`VK_EXT_shader_object`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_shader_object.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_EXT_SHADER_OBJECT_SPEC_VERSION`"]
+pub const EXT_SHADER_OBJECT_SPEC_VERSION: u32 = 1;
+
+#[doc = "**Provided by**: [`VK_EXT_validation_features`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_validation_features.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_EXT_VALIDATION_FEATURES_SPEC_VERSION`"]
+pub const EXT_VALIDATION_FEATURES_SPEC_VERSION: u32 = 5;
 
-    Ok(vec)
+#[doc = "**Provided by**: [`VK_KHR_acceleration_structure`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_acceleration_structure.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_KHR_ACCELERATION_STRUCTURE_SPEC_VERSION`"]
+pub const KHR_ACCELERATION_STRUCTURE_SPEC_VERSION: u32 = 13;
+
+#[doc = "**Provided by**: [`VK_KHR_deferred_host_operations`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_deferred_host_operations.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_KHR_DEFERRED_HOST_OPERATIONS_SPEC_VERSION`"]
+pub const KHR_DEFERRED_HOST_OPERATIONS_SPEC_VERSION: u32 = 4;
+
+#[doc = "**Provided by**: [`VK_KHR_map_memory2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_map_memory2.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_KHR_MAP_MEMORY2_SPEC_VERSION`"]
+pub const KHR_MAP_MEMORY2_SPEC_VERSION: u32 = 1;
+
+#[doc = "**Provided by**: [`VK_KHR_pipeline_library`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_pipeline_library.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_KHR_PIPELINE_LIBRARY_SPEC_VERSION`"]
+pub const KHR_PIPELINE_LIBRARY_SPEC_VERSION: u32 = 1;
+
+#[doc = "**Provided by**: [`VK_KHR_ray_query`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_ray_query.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_KHR_RAY_QUERY_SPEC_VERSION`"]
+pub const KHR_RAY_QUERY_SPEC_VERSION: u32 = 1;
+
+#[doc = "**Provided by**: [`VK_KHR_ray_tracing_maintenance1`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_ray_tracing_maintenance1.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_KHR_RAY_TRACING_MAINTENANCE1_SPEC_VERSION`"]
+pub const KHR_RAY_TRACING_MAINTENANCE1_SPEC_VERSION: u32 = 1;
+
+#[doc = "**Provided by**: [`VK_KHR_ray_tracing_pipeline`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_ray_tracing_pipeline.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_KHR_RAY_TRACING_PIPELINE_SPEC_VERSION`"]
+pub const KHR_RAY_TRACING_PIPELINE_SPEC_VERSION: u32 = 1;
+
+#[doc = "**Provided by**: [`VK_KHR_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_surface.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_KHR_SURFACE_SPEC_VERSION`"]
+pub const KHR_SURFACE_SPEC_VERSION: u32 = 25;
+
+#[doc = "**Provided by**: [`VK_KHR_swapchain`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_swapchain.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_KHR_SWAPCHAIN_SPEC_VERSION`"]
+pub const KHR_SWAPCHAIN_SPEC_VERSION: u32 = 70;
+
+#[doc = "**Provided by**: [`VK_KHR_synchronization2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_synchronization2.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_KHR_SYNCHRONIZATION2_SPEC_VERSION`"]
+pub const KHR_SYNCHRONIZATION2_SPEC_VERSION: u32 = 1;
+
+#[doc = "**Provided by**: [`VK_KHR_wayland_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_wayland_surface.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_KHR_WAYLAND_SURFACE_SPEC_VERSION`"]
+pub const KHR_WAYLAND_SURFACE_SPEC_VERSION: u32 = 6;
+
+#[doc = "**Provided by**: [`VK_KHR_win32_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_win32_surface.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_KHR_WIN32_SURFACE_SPEC_VERSION`"]
+pub const KHR_WIN32_SURFACE_SPEC_VERSION: u32 = 6;
+
+#[doc = "**Provided by**: [`VK_KHR_xcb_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_xcb_surface.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_KHR_XCB_SURFACE_SPEC_VERSION`"]
+pub const KHR_XCB_SURFACE_SPEC_VERSION: u32 = 6;
+
+#[doc = "**Provided by**: [`VK_KHR_xlib_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_xlib_surface.html)"]
+#[doc = "<br>"]
+#[doc = "**Translated from**: `VK_KHR_XLIB_SURFACE_SPEC_VERSION`"]
+pub const KHR_XLIB_SURFACE_SPEC_VERSION: u32 = 6;
+
+/// Spec version of an extension in the manifest, `None` for any other
+/// extension.
+#[must_use]
+pub fn extension_spec_version(extension_name: &std::ffi::CStr) -> Option<u32> {
+    match extension_name.to_bytes() {
+        b"VK_EXT_calibrated_timestamps" => {
+            Some(EXT_CALIBRATED_TIMESTAMPS_SPEC_VERSION)
+        }
+        b"VK_EXT_debug_utils" => Some(EXT_DEBUG_UTILS_SPEC_VERSION),
+        b"VK_EXT_descriptor_buffer" => Some(EXT_DESCRIPTOR_BUFFER_SPEC_VERSION),
+        b"VK_EXT_headless_surface" => Some(EXT_HEADLESS_SURFACE_SPEC_VERSION),
+        b"VK_EXT_memory_budget" => Some(EXT_MEMORY_BUDGET_SPEC_VERSION),
+        b"VK_EXT_mesh_shader" => Some(EXT_MESH_SHADER_SPEC_VERSION),
+        b"VK_EXT_shader_object" => Some(EXT_SHADER_OBJECT_SPEC_VERSION),
+        b"VK_EXT_validation_features" => {
+            Some(EXT_VALIDATION_FEATURES_SPEC_VERSION)
+        }
+        b"VK_KHR_acceleration_structure" => {
+            Some(KHR_ACCELERATION_STRUCTURE_SPEC_VERSION)
+        }
+        b"VK_KHR_deferred_host_operations" => {
+            Some(KHR_DEFERRED_HOST_OPERATIONS_SPEC_VERSION)
+        }
+        b"VK_KHR_map_memory2" => Some(KHR_MAP_MEMORY2_SPEC_VERSION),
+        b"VK_KHR_pipeline_library" => Some(KHR_PIPELINE_LIBRARY_SPEC_VERSION),
+        b"VK_KHR_ray_query" => Some(KHR_RAY_QUERY_SPEC_VERSION),
+        b"VK_KHR_ray_tracing_maintenance1" => {
+            Some(KHR_RAY_TRACING_MAINTENANCE1_SPEC_VERSION)
+        }
+        b"VK_KHR_ray_tracing_pipeline" => {
+            Some(KHR_RAY_TRACING_PIPELINE_SPEC_VERSION)
+        }
+        b"VK_KHR_surface" => Some(KHR_SURFACE_SPEC_VERSION),
+        b"VK_KHR_swapchain" => Some(KHR_SWAPCHAIN_SPEC_VERSION),
+        b"VK_KHR_synchronization2" => Some(KHR_SYNCHRONIZATION2_SPEC_VERSION),
+        b"VK_KHR_wayland_surface" => Some(KHR_WAYLAND_SURFACE_SPEC_VERSION),
+        b"VK_KHR_win32_surface" => Some(KHR_WIN32_SURFACE_SPEC_VERSION),
+        b"VK_KHR_xcb_surface" => Some(KHR_XCB_SURFACE_SPEC_VERSION),
+        b"VK_KHR_xlib_surface" => Some(KHR_XLIB_SURFACE_SPEC_VERSION),
+        _ => None,
+    }
 }
@@ -5,6 +5,41 @@
 use super::{vk, Error};
 use std::ffi::{c_char, c_void, CStr};
 
+//
+// Dispatch
+//
+
+#[cfg(not(feature = "unchecked"))]
+#[inline]
+#[track_caller]
+fn load_fn<T>(f: Option<T>, name: &'static str) -> T {
+    let Some(f) = f else {
+        panic!("{name} is not loaded");
+    };
+    f
+}
+
+#[cfg(not(feature = "unchecked"))]
+#[inline]
+fn try_load_fn<T>(f: Option<T>, name: &'static str) -> Result<T, Error> {
+    f.ok_or(Error::FunctionLoad(std::borrow::Cow::Borrowed(name)))
+}
+
+// Opt-out: calling a command that is not loaded is undefined behavior.
+
+#[cfg(feature = "unchecked")]
+#[inline]
+unsafe fn load_fn<T>(f: Option<T>, _name: &'static str) -> T {
+    f.unwrap_unchecked()
+}
+
+#[cfg(feature = "unchecked")]
+#[inline]
+#[allow(clippy::unnecessary_wraps)]
+unsafe fn try_load_fn<T>(f: Option<T>, _name: &'static str) -> Result<T, Error> {
+    Ok(f.unwrap_unchecked())
+}
+
 //
 // Init
 //
@@ -12,14 +47,20 @@ use std::ffi::{c_char, c_void, CStr};
 pub struct InitFunctions {
     pub get_instance_proc_addr: Option<vk::GetInstanceProcAddr>,
     pub create_instance: Option<vk::CreateInstance>,
+    pub enumerate_instance_version: Option<vk::EnumerateInstanceVersion>,
+    pub enumerate_instance_layer_properties: Option<vk::EnumerateInstanceLayerProperties>,
+    pub enumerate_instance_extension_properties: Option<vk::EnumerateInstanceExtensionProperties>,
 }
 
 pub struct Init {
     fns: InitFunctions,
-    _library: std::sync::Arc<libloading::Library>,
+    _library: Option<std::sync::Arc<libloading::Library>>,
 }
 
 impl Init {
+    /// Loads the system Vulkan loader, or the library named by the
+    /// [`LIBRARY_PATH_ENV_VAR`](crate::LIBRARY_PATH_ENV_VAR) environment
+    /// variable if it is set.
     pub unsafe fn load() -> Result<Self, Error> {
         // Only Windows and Linux are supported.
         #[cfg(windows)]
@@ -27,9 +68,21 @@ impl Init {
         #[cfg(unix)]
         const VULKAN_LIB_PATH: &str = "libvulkan.so.1";
 
+        // Environment override.
+        if let Some(path) = std::env::var_os(crate::LIBRARY_PATH_ENV_VAR) {
+            return Self::load_from_path(path);
+        }
+
+        Self::load_from_path(VULKAN_LIB_PATH)
+    }
+
+    /// Loads the Vulkan loader or driver library at `path`.
+    pub unsafe fn load_from_path(path: impl AsRef<std::ffi::OsStr>) -> Result<Self, Error> {
+        let path = path.as_ref();
+
         // Load library.
-        let library = libloading::Library::new(VULKAN_LIB_PATH)
-            .map_err(|_| Error::LibraryLoad(std::borrow::Cow::Borrowed(VULKAN_LIB_PATH)))
+        let library = libloading::Library::new(path)
+            .map_err(|_| Error::LibraryLoad(std::borrow::Cow::Owned(path.to_string_lossy().into_owned())))
             .map(std::sync::Arc::new)?;
 
         // Load functions.
@@ -49,16 +102,77 @@ impl Init {
             fns: InitFunctions {
                 get_instance_proc_addr: load(c"vkGetInstanceProcAddr").map(|f| std::mem::transmute(f)),
                 create_instance: load(c"vkCreateInstance").map(|f| std::mem::transmute(f)),
+                enumerate_instance_version: load(c"vkEnumerateInstanceVersion").map(|f| std::mem::transmute(f)),
+                enumerate_instance_layer_properties: load(c"vkEnumerateInstanceLayerProperties").map(|f| std::mem::transmute(f)),
+                enumerate_instance_extension_properties: load(c"vkEnumerateInstanceExtensionProperties").map(|f| std::mem::transmute(f)),
             },
-            _library: library,
+            _library: Some(library),
         })
     }
 
+    /// Loads the global commands through a `vkGetInstanceProcAddr` provided by
+    /// another library, such as an OpenXR runtime or a statically linked
+    /// driver. The caller must keep that library loaded while `Init` and
+    /// everything created from it are alive.
+    pub unsafe fn from_get_instance_proc_addr(get_instance_proc_addr: vk::GetInstanceProcAddr) -> Self {
+        let load = |name: &'static CStr| {
+            if name == c"vkGetInstanceProcAddr" {
+                return Some(get_instance_proc_addr as vk::PfnVoidFunction);
+            }
+            let pfn = get_instance_proc_addr(vk::Instance::null(), name.as_ptr());
+            if pfn as usize == 0 {
+                return None;
+            }
+            Some(pfn)
+        };
+
+        Self {
+            fns: InitFunctions {
+                get_instance_proc_addr: load(c"vkGetInstanceProcAddr").map(|f| std::mem::transmute(f)),
+                create_instance: load(c"vkCreateInstance").map(|f| std::mem::transmute(f)),
+                enumerate_instance_version: load(c"vkEnumerateInstanceVersion").map(|f| std::mem::transmute(f)),
+                enumerate_instance_layer_properties: load(c"vkEnumerateInstanceLayerProperties").map(|f| std::mem::transmute(f)),
+                enumerate_instance_extension_properties: load(c"vkEnumerateInstanceExtensionProperties").map(|f| std::mem::transmute(f)),
+            },
+            _library: None,
+        }
+    }
+
     #[must_use]
     pub fn fns(&self) -> &InitFunctions {
         &self.fns
     }
 
+    /// Returns `true` if the command `name`, e.g. `"vkCreateBuffer"`, was
+    /// resolved when loading.
+    #[must_use]
+    pub fn is_loaded(&self, name: &str) -> bool {
+        match name {
+            "vkGetInstanceProcAddr" => self.fns.get_instance_proc_addr.is_some(),
+            "vkCreateInstance" => self.fns.create_instance.is_some(),
+            "vkEnumerateInstanceVersion" => self.fns.enumerate_instance_version.is_some(),
+            "vkEnumerateInstanceLayerProperties" => self.fns.enumerate_instance_layer_properties.is_some(),
+            "vkEnumerateInstanceExtensionProperties" => self.fns.enumerate_instance_extension_properties.is_some(),
+            _ => false,
+        }
+    }
+
+    /// Commands that failed to resolve when loading, typically because their
+    /// extension was not enabled.
+    #[must_use]
+    pub fn missing_commands(&self) -> Vec<&'static str> {
+        [
+            ("vkGetInstanceProcAddr", self.fns.get_instance_proc_addr.is_some()),
+            ("vkCreateInstance", self.fns.create_instance.is_some()),
+            ("vkEnumerateInstanceVersion", self.fns.enumerate_instance_version.is_some()),
+            ("vkEnumerateInstanceLayerProperties", self.fns.enumerate_instance_layer_properties.is_some()),
+            ("vkEnumerateInstanceExtensionProperties", self.fns.enumerate_instance_extension_properties.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(name, loaded)| (!loaded).then_some(name))
+        .collect()
+    }
+
     #[must_use]
     #[inline]
     #[doc = "**Chapter**: Initialization"]
@@ -69,7 +183,11 @@ impl Init {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkGetInstanceProcAddr`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetInstanceProcAddr.html)"]
     pub unsafe fn get_instance_proc_addr(&self, instance: vk::Instance, p_name: *const c_char) -> vk::PfnVoidFunction {
-        (self.fns.get_instance_proc_addr.unwrap_unchecked())(instance, p_name)
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkGetInstanceProcAddr", "pName", p_name);
+        }
+        (load_fn(self.fns.get_instance_proc_addr, "vkGetInstanceProcAddr"))(instance, p_name)
     }
 
     #[inline]
@@ -81,10 +199,155 @@ impl Init {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCreateInstance`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateInstance.html)"]
     pub unsafe fn create_instance(&self, p_create_info: *const vk::InstanceCreateInfo) -> Result<vk::Instance, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreateInstance", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreateInstance", p_create_info, 1);
+        }
         let mut p_instance = std::mem::MaybeUninit::uninit();
-        match (self.fns.create_instance.unwrap_unchecked())(p_create_info, std::ptr::null(), p_instance.as_mut_ptr()) {
+        match (try_load_fn(self.fns.create_instance, "vkCreateInstance")?)(p_create_info, std::ptr::null(), p_instance.as_mut_ptr()) {
             vk::Result::Success => Ok(p_instance.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateInstance",
+                create_info: Some("VkInstanceCreateInfo"),
+            }),
+        }
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Initialization"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Query instance-level version before instance creation"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_VERSION_1_1`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_VERSION_1_1.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkEnumerateInstanceVersion`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceVersion.html)"]
+    pub unsafe fn enumerate_instance_version(&self) -> Result<u32, Error> {
+        let mut p_api_version = std::mem::MaybeUninit::uninit();
+        match (try_load_fn(self.fns.enumerate_instance_version, "vkEnumerateInstanceVersion")?)(p_api_version.as_mut_ptr()) {
+            vk::Result::Success => Ok(p_api_version.assume_init()),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkEnumerateInstanceVersion",
+                create_info: None,
+            }),
+        }
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Extending Vulkan"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Returns up to requested number of global layer properties"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_VERSION_1_0`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_VERSION_1_0.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkEnumerateInstanceLayerProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceLayerProperties.html)"]
+    pub unsafe fn enumerate_instance_layer_properties(&self, p_property_count: *mut u32, p_properties: *mut vk::LayerProperties) -> Result<vk::Result, Error> {
+        match (try_load_fn(self.fns.enumerate_instance_layer_properties, "vkEnumerateInstanceLayerProperties")?)(p_property_count, p_properties) {
+            result @ (vk::Result::Success | vk::Result::Incomplete) => Ok(result),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkEnumerateInstanceLayerProperties",
+                create_info: None,
+            }),
+        }
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Extending Vulkan"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Returns up to requested number of global layer properties"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_VERSION_1_0`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_VERSION_1_0.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkEnumerateInstanceLayerProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceLayerProperties.html)"]
+    pub unsafe fn enumerate_instance_layer_properties_to_vec(&self) -> Result<Vec<vk::LayerProperties>, Error> {
+        loop {
+            let mut property_count = 0;
+            match (try_load_fn(self.fns.enumerate_instance_layer_properties, "vkEnumerateInstanceLayerProperties")?)(&raw mut property_count, std::ptr::null_mut()) {
+                vk::Result::Success => {}
+                result => {
+                    return Err(Error::Vulkan {
+                        result,
+                        command: "vkEnumerateInstanceLayerProperties",
+                        create_info: None,
+                    })
+                }
+            }
+            let mut properties = Vec::with_capacity(property_count as usize);
+            match (try_load_fn(self.fns.enumerate_instance_layer_properties, "vkEnumerateInstanceLayerProperties")?)(&raw mut property_count, properties.as_mut_ptr()) {
+                vk::Result::Success => {
+                    properties.set_len(property_count as usize);
+                    return Ok(properties);
+                }
+                vk::Result::Incomplete => {}
+                result => {
+                    return Err(Error::Vulkan {
+                        result,
+                        command: "vkEnumerateInstanceLayerProperties",
+                        create_info: None,
+                    })
+                }
+            }
+        }
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Extending Vulkan"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Returns up to requested number of global extension properties"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_VERSION_1_0`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_VERSION_1_0.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkEnumerateInstanceExtensionProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceExtensionProperties.html)"]
+    pub unsafe fn enumerate_instance_extension_properties(&self, p_layer_name: *const c_char, p_property_count: *mut u32, p_properties: *mut vk::ExtensionProperties) -> Result<vk::Result, Error> {
+        match (try_load_fn(self.fns.enumerate_instance_extension_properties, "vkEnumerateInstanceExtensionProperties")?)(p_layer_name, p_property_count, p_properties) {
+            result @ (vk::Result::Success | vk::Result::Incomplete) => Ok(result),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkEnumerateInstanceExtensionProperties",
+                create_info: None,
+            }),
+        }
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Extending Vulkan"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Returns up to requested number of global extension properties"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_VERSION_1_0`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_VERSION_1_0.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkEnumerateInstanceExtensionProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateInstanceExtensionProperties.html)"]
+    pub unsafe fn enumerate_instance_extension_properties_to_vec(&self, p_layer_name: *const c_char) -> Result<Vec<vk::ExtensionProperties>, Error> {
+        loop {
+            let mut property_count = 0;
+            match (try_load_fn(self.fns.enumerate_instance_extension_properties, "vkEnumerateInstanceExtensionProperties")?)(p_layer_name, &raw mut property_count, std::ptr::null_mut()) {
+                vk::Result::Success => {}
+                result => {
+                    return Err(Error::Vulkan {
+                        result,
+                        command: "vkEnumerateInstanceExtensionProperties",
+                        create_info: None,
+                    })
+                }
+            }
+            let mut properties = Vec::with_capacity(property_count as usize);
+            match (try_load_fn(self.fns.enumerate_instance_extension_properties, "vkEnumerateInstanceExtensionProperties")?)(p_layer_name, &raw mut property_count, properties.as_mut_ptr()) {
+                vk::Result::Success => {
+                    properties.set_len(property_count as usize);
+                    return Ok(properties);
+                }
+                vk::Result::Incomplete => {}
+                result => {
+                    return Err(Error::Vulkan {
+                        result,
+                        command: "vkEnumerateInstanceExtensionProperties",
+                        create_info: None,
+                    })
+                }
+            }
         }
     }
 }
@@ -101,12 +364,18 @@ pub struct InstanceFunctions {
     pub get_physical_device_queue_family_properties2: Option<vk::GetPhysicalDeviceQueueFamilyProperties2>,
     pub create_device: Option<vk::CreateDevice>,
     pub get_physical_device_memory_properties2: Option<vk::GetPhysicalDeviceMemoryProperties2>,
+    pub create_wayland_surface_khr: Option<vk::CreateWaylandSurfaceKHR>,
     pub create_win32_surface_khr: Option<vk::CreateWin32SurfaceKHR>,
+    pub create_xcb_surface_khr: Option<vk::CreateXcbSurfaceKHR>,
+    pub create_xlib_surface_khr: Option<vk::CreateXlibSurfaceKHR>,
+    pub create_headless_surface_ext: Option<vk::CreateHeadlessSurfaceEXT>,
     pub destroy_surface_khr: Option<vk::DestroySurfaceKHR>,
     pub get_physical_device_surface_support_khr: Option<vk::GetPhysicalDeviceSurfaceSupportKHR>,
     pub get_physical_device_surface_capabilities_khr: Option<vk::GetPhysicalDeviceSurfaceCapabilitiesKHR>,
     pub get_physical_device_surface_formats_khr: Option<vk::GetPhysicalDeviceSurfaceFormatsKHR>,
     pub get_physical_device_surface_present_modes_khr: Option<vk::GetPhysicalDeviceSurfacePresentModesKHR>,
+    pub enumerate_device_extension_properties: Option<vk::EnumerateDeviceExtensionProperties>,
+    pub get_physical_device_features2: Option<vk::GetPhysicalDeviceFeatures2>,
     pub get_physical_device_calibrateable_time_domains_ext: Option<vk::GetPhysicalDeviceCalibrateableTimeDomainsEXT>,
     pub create_debug_utils_messenger_ext: Option<vk::CreateDebugUtilsMessengerEXT>,
     pub destroy_debug_utils_messenger_ext: Option<vk::DestroyDebugUtilsMessengerEXT>,
@@ -136,12 +405,18 @@ impl Instance {
                 get_physical_device_queue_family_properties2: load(c"vkGetPhysicalDeviceQueueFamilyProperties2").map(|f| std::mem::transmute(f)),
                 create_device: load(c"vkCreateDevice").map(|f| std::mem::transmute(f)),
                 get_physical_device_memory_properties2: load(c"vkGetPhysicalDeviceMemoryProperties2").map(|f| std::mem::transmute(f)),
+                create_wayland_surface_khr: load(c"vkCreateWaylandSurfaceKHR").map(|f| std::mem::transmute(f)),
                 create_win32_surface_khr: load(c"vkCreateWin32SurfaceKHR").map(|f| std::mem::transmute(f)),
+                create_xcb_surface_khr: load(c"vkCreateXcbSurfaceKHR").map(|f| std::mem::transmute(f)),
+                create_xlib_surface_khr: load(c"vkCreateXlibSurfaceKHR").map(|f| std::mem::transmute(f)),
+                create_headless_surface_ext: load(c"vkCreateHeadlessSurfaceEXT").map(|f| std::mem::transmute(f)),
                 destroy_surface_khr: load(c"vkDestroySurfaceKHR").map(|f| std::mem::transmute(f)),
                 get_physical_device_surface_support_khr: load(c"vkGetPhysicalDeviceSurfaceSupportKHR").map(|f| std::mem::transmute(f)),
                 get_physical_device_surface_capabilities_khr: load(c"vkGetPhysicalDeviceSurfaceCapabilitiesKHR").map(|f| std::mem::transmute(f)),
                 get_physical_device_surface_formats_khr: load(c"vkGetPhysicalDeviceSurfaceFormatsKHR").map(|f| std::mem::transmute(f)),
                 get_physical_device_surface_present_modes_khr: load(c"vkGetPhysicalDeviceSurfacePresentModesKHR").map(|f| std::mem::transmute(f)),
+                enumerate_device_extension_properties: load(c"vkEnumerateDeviceExtensionProperties").map(|f| std::mem::transmute(f)),
+                get_physical_device_features2: load(c"vkGetPhysicalDeviceFeatures2").map(|f| std::mem::transmute(f)),
                 get_physical_device_calibrateable_time_domains_ext: load(c"vkGetPhysicalDeviceCalibrateableTimeDomainsEXT").map(|f| std::mem::transmute(f)),
                 create_debug_utils_messenger_ext: load(c"vkCreateDebugUtilsMessengerEXT").map(|f| std::mem::transmute(f)),
                 destroy_debug_utils_messenger_ext: load(c"vkDestroyDebugUtilsMessengerEXT").map(|f| std::mem::transmute(f)),
@@ -160,6 +435,70 @@ impl Instance {
         &self.fns
     }
 
+    /// Returns `true` if the command `name`, e.g. `"vkCreateBuffer"`, was
+    /// resolved when loading.
+    #[must_use]
+    pub fn is_loaded(&self, name: &str) -> bool {
+        match name {
+            "vkGetDeviceProcAddr" => self.fns.get_device_proc_addr.is_some(),
+            "vkDestroyInstance" => self.fns.destroy_instance.is_some(),
+            "vkEnumeratePhysicalDevices" => self.fns.enumerate_physical_devices.is_some(),
+            "vkGetPhysicalDeviceProperties2" => self.fns.get_physical_device_properties2.is_some(),
+            "vkGetPhysicalDeviceQueueFamilyProperties2" => self.fns.get_physical_device_queue_family_properties2.is_some(),
+            "vkCreateDevice" => self.fns.create_device.is_some(),
+            "vkGetPhysicalDeviceMemoryProperties2" => self.fns.get_physical_device_memory_properties2.is_some(),
+            "vkCreateWaylandSurfaceKHR" => self.fns.create_wayland_surface_khr.is_some(),
+            "vkCreateWin32SurfaceKHR" => self.fns.create_win32_surface_khr.is_some(),
+            "vkCreateXcbSurfaceKHR" => self.fns.create_xcb_surface_khr.is_some(),
+            "vkCreateXlibSurfaceKHR" => self.fns.create_xlib_surface_khr.is_some(),
+            "vkCreateHeadlessSurfaceEXT" => self.fns.create_headless_surface_ext.is_some(),
+            "vkDestroySurfaceKHR" => self.fns.destroy_surface_khr.is_some(),
+            "vkGetPhysicalDeviceSurfaceSupportKHR" => self.fns.get_physical_device_surface_support_khr.is_some(),
+            "vkGetPhysicalDeviceSurfaceCapabilitiesKHR" => self.fns.get_physical_device_surface_capabilities_khr.is_some(),
+            "vkGetPhysicalDeviceSurfaceFormatsKHR" => self.fns.get_physical_device_surface_formats_khr.is_some(),
+            "vkGetPhysicalDeviceSurfacePresentModesKHR" => self.fns.get_physical_device_surface_present_modes_khr.is_some(),
+            "vkEnumerateDeviceExtensionProperties" => self.fns.enumerate_device_extension_properties.is_some(),
+            "vkGetPhysicalDeviceFeatures2" => self.fns.get_physical_device_features2.is_some(),
+            "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT" => self.fns.get_physical_device_calibrateable_time_domains_ext.is_some(),
+            "vkCreateDebugUtilsMessengerEXT" => self.fns.create_debug_utils_messenger_ext.is_some(),
+            "vkDestroyDebugUtilsMessengerEXT" => self.fns.destroy_debug_utils_messenger_ext.is_some(),
+            _ => false,
+        }
+    }
+
+    /// Commands that failed to resolve when loading, typically because their
+    /// extension was not enabled.
+    #[must_use]
+    pub fn missing_commands(&self) -> Vec<&'static str> {
+        [
+            ("vkGetDeviceProcAddr", self.fns.get_device_proc_addr.is_some()),
+            ("vkDestroyInstance", self.fns.destroy_instance.is_some()),
+            ("vkEnumeratePhysicalDevices", self.fns.enumerate_physical_devices.is_some()),
+            ("vkGetPhysicalDeviceProperties2", self.fns.get_physical_device_properties2.is_some()),
+            ("vkGetPhysicalDeviceQueueFamilyProperties2", self.fns.get_physical_device_queue_family_properties2.is_some()),
+            ("vkCreateDevice", self.fns.create_device.is_some()),
+            ("vkGetPhysicalDeviceMemoryProperties2", self.fns.get_physical_device_memory_properties2.is_some()),
+            ("vkCreateWaylandSurfaceKHR", self.fns.create_wayland_surface_khr.is_some()),
+            ("vkCreateWin32SurfaceKHR", self.fns.create_win32_surface_khr.is_some()),
+            ("vkCreateXcbSurfaceKHR", self.fns.create_xcb_surface_khr.is_some()),
+            ("vkCreateXlibSurfaceKHR", self.fns.create_xlib_surface_khr.is_some()),
+            ("vkCreateHeadlessSurfaceEXT", self.fns.create_headless_surface_ext.is_some()),
+            ("vkDestroySurfaceKHR", self.fns.destroy_surface_khr.is_some()),
+            ("vkGetPhysicalDeviceSurfaceSupportKHR", self.fns.get_physical_device_surface_support_khr.is_some()),
+            ("vkGetPhysicalDeviceSurfaceCapabilitiesKHR", self.fns.get_physical_device_surface_capabilities_khr.is_some()),
+            ("vkGetPhysicalDeviceSurfaceFormatsKHR", self.fns.get_physical_device_surface_formats_khr.is_some()),
+            ("vkGetPhysicalDeviceSurfacePresentModesKHR", self.fns.get_physical_device_surface_present_modes_khr.is_some()),
+            ("vkEnumerateDeviceExtensionProperties", self.fns.enumerate_device_extension_properties.is_some()),
+            ("vkGetPhysicalDeviceFeatures2", self.fns.get_physical_device_features2.is_some()),
+            ("vkGetPhysicalDeviceCalibrateableTimeDomainsEXT", self.fns.get_physical_device_calibrateable_time_domains_ext.is_some()),
+            ("vkCreateDebugUtilsMessengerEXT", self.fns.create_debug_utils_messenger_ext.is_some()),
+            ("vkDestroyDebugUtilsMessengerEXT", self.fns.destroy_debug_utils_messenger_ext.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(name, loaded)| (!loaded).then_some(name))
+        .collect()
+    }
+
     #[must_use]
     #[inline]
     #[doc = "**Chapter**: Initialization"]
@@ -170,7 +509,11 @@ impl Instance {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkGetDeviceProcAddr`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceProcAddr.html)"]
     pub unsafe fn get_device_proc_addr(&self, device: vk::Device, p_name: *const c_char) -> vk::PfnVoidFunction {
-        (self.fns.get_device_proc_addr.unwrap_unchecked())(device, p_name)
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkGetDeviceProcAddr", "pName", p_name);
+        }
+        (load_fn(self.fns.get_device_proc_addr, "vkGetDeviceProcAddr"))(device, p_name)
     }
 
     #[inline]
@@ -182,7 +525,7 @@ impl Instance {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkDestroyInstance`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyInstance.html)"]
     pub unsafe fn destroy_instance(&self) {
-        (self.fns.destroy_instance.unwrap_unchecked())(self.handle, std::ptr::null());
+        (load_fn(self.fns.destroy_instance, "vkDestroyInstance"))(self.handle, std::ptr::null());
     }
 
     #[inline]
@@ -193,10 +536,53 @@ impl Instance {
     #[doc = "**Provided by**: [`VK_VERSION_1_0`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_VERSION_1_0.html)"]
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkEnumeratePhysicalDevices`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumeratePhysicalDevices.html)"]
-    pub unsafe fn enumerate_physical_devices(&self, p_physical_device_count: *mut u32, p_physical_devices: *mut vk::PhysicalDevice) -> Result<(), Error> {
-        match (self.fns.enumerate_physical_devices.unwrap_unchecked())(self.handle, p_physical_device_count, p_physical_devices) {
-            vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+    pub unsafe fn enumerate_physical_devices(&self, p_physical_device_count: *mut u32, p_physical_devices: *mut vk::PhysicalDevice) -> Result<vk::Result, Error> {
+        match (try_load_fn(self.fns.enumerate_physical_devices, "vkEnumeratePhysicalDevices")?)(self.handle, p_physical_device_count, p_physical_devices) {
+            result @ (vk::Result::Success | vk::Result::Incomplete) => Ok(result),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkEnumeratePhysicalDevices",
+                create_info: None,
+            }),
+        }
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Devices and Queues"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Enumerates the physical devices accessible to a Vulkan instance"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_VERSION_1_0`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_VERSION_1_0.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkEnumeratePhysicalDevices`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumeratePhysicalDevices.html)"]
+    pub unsafe fn enumerate_physical_devices_to_vec(&self) -> Result<Vec<vk::PhysicalDevice>, Error> {
+        loop {
+            let mut physical_device_count = 0;
+            match (try_load_fn(self.fns.enumerate_physical_devices, "vkEnumeratePhysicalDevices")?)(self.handle, &raw mut physical_device_count, std::ptr::null_mut()) {
+                vk::Result::Success => {}
+                result => {
+                    return Err(Error::Vulkan {
+                        result,
+                        command: "vkEnumeratePhysicalDevices",
+                        create_info: None,
+                    })
+                }
+            }
+            let mut physical_devices = Vec::with_capacity(physical_device_count as usize);
+            match (try_load_fn(self.fns.enumerate_physical_devices, "vkEnumeratePhysicalDevices")?)(self.handle, &raw mut physical_device_count, physical_devices.as_mut_ptr()) {
+                vk::Result::Success => {
+                    physical_devices.set_len(physical_device_count as usize);
+                    return Ok(physical_devices);
+                }
+                vk::Result::Incomplete => {}
+                result => {
+                    return Err(Error::Vulkan {
+                        result,
+                        command: "vkEnumeratePhysicalDevices",
+                        create_info: None,
+                    })
+                }
+            }
         }
     }
 
@@ -209,7 +595,7 @@ impl Instance {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkGetPhysicalDeviceProperties2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceProperties2.html)"]
     pub unsafe fn get_physical_device_properties2(&self, physical_device: vk::PhysicalDevice, p_properties: *mut vk::PhysicalDeviceProperties2) {
-        (self.fns.get_physical_device_properties2.unwrap_unchecked())(physical_device, p_properties);
+        (load_fn(self.fns.get_physical_device_properties2, "vkGetPhysicalDeviceProperties2"))(physical_device, p_properties);
     }
 
     #[inline]
@@ -226,7 +612,30 @@ impl Instance {
         p_queue_family_property_count: *mut u32,
         p_queue_family_properties: *mut vk::QueueFamilyProperties2,
     ) {
-        (self.fns.get_physical_device_queue_family_properties2.unwrap_unchecked())(physical_device, p_queue_family_property_count, p_queue_family_properties);
+        (load_fn(self.fns.get_physical_device_queue_family_properties2, "vkGetPhysicalDeviceQueueFamilyProperties2"))(physical_device, p_queue_family_property_count, p_queue_family_properties);
+    }
+
+    #[must_use]
+    #[inline]
+    #[doc = "**Chapter**: Devices and Queues"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Reports properties of the queues of the specified physical device"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_VERSION_1_1`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_VERSION_1_1.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkGetPhysicalDeviceQueueFamilyProperties2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceQueueFamilyProperties2.html)"]
+    pub unsafe fn get_physical_device_queue_family_properties2_to_vec(&self, physical_device: vk::PhysicalDevice) -> Vec<vk::QueueFamilyProperties2> {
+        let mut queue_family_property_count = 0;
+        (load_fn(self.fns.get_physical_device_queue_family_properties2, "vkGetPhysicalDeviceQueueFamilyProperties2"))(physical_device, &raw mut queue_family_property_count, std::ptr::null_mut());
+        let mut queue_family_properties = Vec::with_capacity(queue_family_property_count as usize);
+        queue_family_properties.resize_with(queue_family_property_count as usize, vk::QueueFamilyProperties2::new);
+        (load_fn(self.fns.get_physical_device_queue_family_properties2, "vkGetPhysicalDeviceQueueFamilyProperties2"))(
+            physical_device,
+            &raw mut queue_family_property_count,
+            queue_family_properties.as_mut_ptr(),
+        );
+        queue_family_properties.set_len(queue_family_property_count as usize);
+        queue_family_properties
     }
 
     #[inline]
@@ -238,10 +647,19 @@ impl Instance {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCreateDevice`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDevice.html)"]
     pub unsafe fn create_device(&self, physical_device: vk::PhysicalDevice, p_create_info: *const vk::DeviceCreateInfo) -> Result<vk::Device, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreateDevice", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreateDevice", p_create_info, 1);
+        }
         let mut p_device = std::mem::MaybeUninit::uninit();
-        match (self.fns.create_device.unwrap_unchecked())(physical_device, p_create_info, std::ptr::null(), p_device.as_mut_ptr()) {
+        match (try_load_fn(self.fns.create_device, "vkCreateDevice")?)(physical_device, p_create_info, std::ptr::null(), p_device.as_mut_ptr()) {
             vk::Result::Success => Ok(p_device.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateDevice",
+                create_info: Some("VkDeviceCreateInfo"),
+            }),
         }
     }
 
@@ -254,7 +672,32 @@ impl Instance {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkGetPhysicalDeviceMemoryProperties2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceMemoryProperties2.html)"]
     pub unsafe fn get_physical_device_memory_properties2(&self, physical_device: vk::PhysicalDevice, p_memory_properties: *mut vk::PhysicalDeviceMemoryProperties2) {
-        (self.fns.get_physical_device_memory_properties2.unwrap_unchecked())(physical_device, p_memory_properties);
+        (load_fn(self.fns.get_physical_device_memory_properties2, "vkGetPhysicalDeviceMemoryProperties2"))(physical_device, p_memory_properties);
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Window System Integration (WSI)"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Create a VkSurfaceKHR object for a Wayland window"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_KHR_wayland_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_wayland_surface.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkCreateWaylandSurfaceKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateWaylandSurfaceKHR.html)"]
+    pub unsafe fn create_wayland_surface_khr(&self, p_create_info: *const vk::WaylandSurfaceCreateInfoKHR) -> Result<vk::SurfaceKHR, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreateWaylandSurfaceKHR", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreateWaylandSurfaceKHR", p_create_info, 1);
+        }
+        let mut p_surface = std::mem::MaybeUninit::uninit();
+        match (try_load_fn(self.fns.create_wayland_surface_khr, "vkCreateWaylandSurfaceKHR")?)(self.handle, p_create_info, std::ptr::null(), p_surface.as_mut_ptr()) {
+            vk::Result::Success => Ok(p_surface.assume_init()),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateWaylandSurfaceKHR",
+                create_info: Some("VkWaylandSurfaceCreateInfoKHR"),
+            }),
+        }
     }
 
     #[inline]
@@ -266,10 +709,94 @@ impl Instance {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCreateWin32SurfaceKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateWin32SurfaceKHR.html)"]
     pub unsafe fn create_win32_surface_khr(&self, p_create_info: *const vk::Win32SurfaceCreateInfoKHR) -> Result<vk::SurfaceKHR, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreateWin32SurfaceKHR", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreateWin32SurfaceKHR", p_create_info, 1);
+        }
+        let mut p_surface = std::mem::MaybeUninit::uninit();
+        match (try_load_fn(self.fns.create_win32_surface_khr, "vkCreateWin32SurfaceKHR")?)(self.handle, p_create_info, std::ptr::null(), p_surface.as_mut_ptr()) {
+            vk::Result::Success => Ok(p_surface.assume_init()),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateWin32SurfaceKHR",
+                create_info: Some("VkWin32SurfaceCreateInfoKHR"),
+            }),
+        }
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Window System Integration (WSI)"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Create a VkSurfaceKHR object for a X11 window, using the XCB client-side library"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_KHR_xcb_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_xcb_surface.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkCreateXcbSurfaceKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateXcbSurfaceKHR.html)"]
+    pub unsafe fn create_xcb_surface_khr(&self, p_create_info: *const vk::XcbSurfaceCreateInfoKHR) -> Result<vk::SurfaceKHR, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreateXcbSurfaceKHR", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreateXcbSurfaceKHR", p_create_info, 1);
+        }
+        let mut p_surface = std::mem::MaybeUninit::uninit();
+        match (try_load_fn(self.fns.create_xcb_surface_khr, "vkCreateXcbSurfaceKHR")?)(self.handle, p_create_info, std::ptr::null(), p_surface.as_mut_ptr()) {
+            vk::Result::Success => Ok(p_surface.assume_init()),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateXcbSurfaceKHR",
+                create_info: Some("VkXcbSurfaceCreateInfoKHR"),
+            }),
+        }
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Window System Integration (WSI)"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Create a VkSurfaceKHR object for an X11 window, using the Xlib client-side library"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_KHR_xlib_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_xlib_surface.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkCreateXlibSurfaceKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateXlibSurfaceKHR.html)"]
+    pub unsafe fn create_xlib_surface_khr(&self, p_create_info: *const vk::XlibSurfaceCreateInfoKHR) -> Result<vk::SurfaceKHR, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreateXlibSurfaceKHR", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreateXlibSurfaceKHR", p_create_info, 1);
+        }
+        let mut p_surface = std::mem::MaybeUninit::uninit();
+        match (try_load_fn(self.fns.create_xlib_surface_khr, "vkCreateXlibSurfaceKHR")?)(self.handle, p_create_info, std::ptr::null(), p_surface.as_mut_ptr()) {
+            vk::Result::Success => Ok(p_surface.assume_init()),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateXlibSurfaceKHR",
+                create_info: Some("VkXlibSurfaceCreateInfoKHR"),
+            }),
+        }
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Window System Integration (WSI)"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Create a headless VkSurfaceKHR object"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_EXT_headless_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_headless_surface.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkCreateHeadlessSurfaceEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateHeadlessSurfaceEXT.html)"]
+    pub unsafe fn create_headless_surface_ext(&self, p_create_info: *const vk::HeadlessSurfaceCreateInfoEXT) -> Result<vk::SurfaceKHR, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreateHeadlessSurfaceEXT", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreateHeadlessSurfaceEXT", p_create_info, 1);
+        }
         let mut p_surface = std::mem::MaybeUninit::uninit();
-        match (self.fns.create_win32_surface_khr.unwrap_unchecked())(self.handle, p_create_info, std::ptr::null(), p_surface.as_mut_ptr()) {
+        match (try_load_fn(self.fns.create_headless_surface_ext, "vkCreateHeadlessSurfaceEXT")?)(self.handle, p_create_info, std::ptr::null(), p_surface.as_mut_ptr()) {
             vk::Result::Success => Ok(p_surface.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateHeadlessSurfaceEXT",
+                create_info: Some("VkHeadlessSurfaceCreateInfoEXT"),
+            }),
         }
     }
 
@@ -282,7 +809,7 @@ impl Instance {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkDestroySurfaceKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroySurfaceKHR.html)"]
     pub unsafe fn destroy_surface_khr(&self, surface: vk::SurfaceKHR) {
-        (self.fns.destroy_surface_khr.unwrap_unchecked())(self.handle, surface, std::ptr::null());
+        (load_fn(self.fns.destroy_surface_khr, "vkDestroySurfaceKHR"))(self.handle, surface, std::ptr::null());
     }
 
     #[inline]
@@ -295,9 +822,13 @@ impl Instance {
     #[doc = "**Reference**: [`vkGetPhysicalDeviceSurfaceSupportKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceSupportKHR.html)"]
     pub unsafe fn get_physical_device_surface_support_khr(&self, physical_device: vk::PhysicalDevice, queue_family_index: u32, surface: vk::SurfaceKHR) -> Result<vk::Bool32, Error> {
         let mut p_supported = std::mem::MaybeUninit::uninit();
-        match (self.fns.get_physical_device_surface_support_khr.unwrap_unchecked())(physical_device, queue_family_index, surface, p_supported.as_mut_ptr()) {
+        match (try_load_fn(self.fns.get_physical_device_surface_support_khr, "vkGetPhysicalDeviceSurfaceSupportKHR")?)(physical_device, queue_family_index, surface, p_supported.as_mut_ptr()) {
             vk::Result::Success => Ok(p_supported.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkGetPhysicalDeviceSurfaceSupportKHR",
+                create_info: None,
+            }),
         }
     }
 
@@ -311,9 +842,13 @@ impl Instance {
     #[doc = "**Reference**: [`vkGetPhysicalDeviceSurfaceCapabilitiesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceCapabilitiesKHR.html)"]
     pub unsafe fn get_physical_device_surface_capabilities_khr(&self, physical_device: vk::PhysicalDevice, surface: vk::SurfaceKHR) -> Result<vk::SurfaceCapabilitiesKHR, Error> {
         let mut p_surface_capabilities = std::mem::MaybeUninit::uninit();
-        match (self.fns.get_physical_device_surface_capabilities_khr.unwrap_unchecked())(physical_device, surface, p_surface_capabilities.as_mut_ptr()) {
+        match (try_load_fn(self.fns.get_physical_device_surface_capabilities_khr, "vkGetPhysicalDeviceSurfaceCapabilitiesKHR")?)(physical_device, surface, p_surface_capabilities.as_mut_ptr()) {
             vk::Result::Success => Ok(p_surface_capabilities.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkGetPhysicalDeviceSurfaceCapabilitiesKHR",
+                create_info: None,
+            }),
         }
     }
 
@@ -331,10 +866,63 @@ impl Instance {
         surface: vk::SurfaceKHR,
         p_surface_format_count: *mut u32,
         p_surface_formats: *mut vk::SurfaceFormatKHR,
-    ) -> Result<(), Error> {
-        match (self.fns.get_physical_device_surface_formats_khr.unwrap_unchecked())(physical_device, surface, p_surface_format_count, p_surface_formats) {
-            vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+    ) -> Result<vk::Result, Error> {
+        match (try_load_fn(self.fns.get_physical_device_surface_formats_khr, "vkGetPhysicalDeviceSurfaceFormatsKHR")?)(physical_device, surface, p_surface_format_count, p_surface_formats) {
+            result @ (vk::Result::Success | vk::Result::Incomplete) => Ok(result),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkGetPhysicalDeviceSurfaceFormatsKHR",
+                create_info: None,
+            }),
+        }
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Window System Integration (WSI)"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Query color formats supported by surface"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_KHR_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_surface.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkGetPhysicalDeviceSurfaceFormatsKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfaceFormatsKHR.html)"]
+    pub unsafe fn get_physical_device_surface_formats_khr_to_vec(&self, physical_device: vk::PhysicalDevice, surface: vk::SurfaceKHR) -> Result<Vec<vk::SurfaceFormatKHR>, Error> {
+        loop {
+            let mut surface_format_count = 0;
+            match (try_load_fn(self.fns.get_physical_device_surface_formats_khr, "vkGetPhysicalDeviceSurfaceFormatsKHR")?)(
+                physical_device,
+                surface,
+                &raw mut surface_format_count,
+                std::ptr::null_mut(),
+            ) {
+                vk::Result::Success => {}
+                result => {
+                    return Err(Error::Vulkan {
+                        result,
+                        command: "vkGetPhysicalDeviceSurfaceFormatsKHR",
+                        create_info: None,
+                    })
+                }
+            }
+            let mut surface_formats = Vec::with_capacity(surface_format_count as usize);
+            match (try_load_fn(self.fns.get_physical_device_surface_formats_khr, "vkGetPhysicalDeviceSurfaceFormatsKHR")?)(
+                physical_device,
+                surface,
+                &raw mut surface_format_count,
+                surface_formats.as_mut_ptr(),
+            ) {
+                vk::Result::Success => {
+                    surface_formats.set_len(surface_format_count as usize);
+                    return Ok(surface_formats);
+                }
+                vk::Result::Incomplete => {}
+                result => {
+                    return Err(Error::Vulkan {
+                        result,
+                        command: "vkGetPhysicalDeviceSurfaceFormatsKHR",
+                        create_info: None,
+                    })
+                }
+            }
         }
     }
 
@@ -352,13 +940,147 @@ impl Instance {
         surface: vk::SurfaceKHR,
         p_present_mode_count: *mut u32,
         p_present_modes: *mut vk::PresentModeKHR,
-    ) -> Result<(), Error> {
-        match (self.fns.get_physical_device_surface_present_modes_khr.unwrap_unchecked())(physical_device, surface, p_present_mode_count, p_present_modes) {
-            vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+    ) -> Result<vk::Result, Error> {
+        match (try_load_fn(self.fns.get_physical_device_surface_present_modes_khr, "vkGetPhysicalDeviceSurfacePresentModesKHR")?)(physical_device, surface, p_present_mode_count, p_present_modes) {
+            result @ (vk::Result::Success | vk::Result::Incomplete) => Ok(result),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkGetPhysicalDeviceSurfacePresentModesKHR",
+                create_info: None,
+            }),
         }
     }
 
+    #[inline]
+    #[doc = "**Chapter**: Window System Integration (WSI)"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Query supported presentation modes"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_KHR_surface`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_surface.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkGetPhysicalDeviceSurfacePresentModesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceSurfacePresentModesKHR.html)"]
+    pub unsafe fn get_physical_device_surface_present_modes_khr_to_vec(&self, physical_device: vk::PhysicalDevice, surface: vk::SurfaceKHR) -> Result<Vec<vk::PresentModeKHR>, Error> {
+        loop {
+            let mut present_mode_count = 0;
+            match (try_load_fn(self.fns.get_physical_device_surface_present_modes_khr, "vkGetPhysicalDeviceSurfacePresentModesKHR")?)(
+                physical_device,
+                surface,
+                &raw mut present_mode_count,
+                std::ptr::null_mut(),
+            ) {
+                vk::Result::Success => {}
+                result => {
+                    return Err(Error::Vulkan {
+                        result,
+                        command: "vkGetPhysicalDeviceSurfacePresentModesKHR",
+                        create_info: None,
+                    })
+                }
+            }
+            let mut present_modes = Vec::with_capacity(present_mode_count as usize);
+            match (try_load_fn(self.fns.get_physical_device_surface_present_modes_khr, "vkGetPhysicalDeviceSurfacePresentModesKHR")?)(
+                physical_device,
+                surface,
+                &raw mut present_mode_count,
+                present_modes.as_mut_ptr(),
+            ) {
+                vk::Result::Success => {
+                    present_modes.set_len(present_mode_count as usize);
+                    return Ok(present_modes);
+                }
+                vk::Result::Incomplete => {}
+                result => {
+                    return Err(Error::Vulkan {
+                        result,
+                        command: "vkGetPhysicalDeviceSurfacePresentModesKHR",
+                        create_info: None,
+                    })
+                }
+            }
+        }
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Extending Vulkan"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Returns properties of available physical device extensions"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_VERSION_1_0`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_VERSION_1_0.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkEnumerateDeviceExtensionProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateDeviceExtensionProperties.html)"]
+    pub unsafe fn enumerate_device_extension_properties(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        p_layer_name: *const c_char,
+        p_property_count: *mut u32,
+        p_properties: *mut vk::ExtensionProperties,
+    ) -> Result<vk::Result, Error> {
+        match (try_load_fn(self.fns.enumerate_device_extension_properties, "vkEnumerateDeviceExtensionProperties")?)(physical_device, p_layer_name, p_property_count, p_properties) {
+            result @ (vk::Result::Success | vk::Result::Incomplete) => Ok(result),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkEnumerateDeviceExtensionProperties",
+                create_info: None,
+            }),
+        }
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Extending Vulkan"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Returns properties of available physical device extensions"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_VERSION_1_0`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_VERSION_1_0.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkEnumerateDeviceExtensionProperties`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEnumerateDeviceExtensionProperties.html)"]
+    pub unsafe fn enumerate_device_extension_properties_to_vec(&self, physical_device: vk::PhysicalDevice, p_layer_name: *const c_char) -> Result<Vec<vk::ExtensionProperties>, Error> {
+        loop {
+            let mut property_count = 0;
+            match (try_load_fn(self.fns.enumerate_device_extension_properties, "vkEnumerateDeviceExtensionProperties")?)(physical_device, p_layer_name, &raw mut property_count, std::ptr::null_mut()) {
+                vk::Result::Success => {}
+                result => {
+                    return Err(Error::Vulkan {
+                        result,
+                        command: "vkEnumerateDeviceExtensionProperties",
+                        create_info: None,
+                    })
+                }
+            }
+            let mut properties = Vec::with_capacity(property_count as usize);
+            match (try_load_fn(self.fns.enumerate_device_extension_properties, "vkEnumerateDeviceExtensionProperties")?)(
+                physical_device,
+                p_layer_name,
+                &raw mut property_count,
+                properties.as_mut_ptr(),
+            ) {
+                vk::Result::Success => {
+                    properties.set_len(property_count as usize);
+                    return Ok(properties);
+                }
+                vk::Result::Incomplete => {}
+                result => {
+                    return Err(Error::Vulkan {
+                        result,
+                        command: "vkEnumerateDeviceExtensionProperties",
+                        create_info: None,
+                    })
+                }
+            }
+        }
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Features"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Reports capabilities of a physical device"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_VERSION_1_1`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_VERSION_1_1.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkGetPhysicalDeviceFeatures2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceFeatures2.html)"]
+    pub unsafe fn get_physical_device_features2(&self, physical_device: vk::PhysicalDevice, p_features: *mut vk::PhysicalDeviceFeatures2) {
+        (load_fn(self.fns.get_physical_device_features2, "vkGetPhysicalDeviceFeatures2"))(physical_device, p_features);
+    }
+
     #[inline]
     #[doc = "**Chapter**: Additional Capabilities"]
     #[doc = "<br>"]
@@ -372,10 +1094,61 @@ impl Instance {
         physical_device: vk::PhysicalDevice,
         p_time_domain_count: *mut u32,
         p_time_domains: *mut vk::TimeDomainEXT,
-    ) -> Result<(), Error> {
-        match (self.fns.get_physical_device_calibrateable_time_domains_ext.unwrap_unchecked())(physical_device, p_time_domain_count, p_time_domains) {
-            vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+    ) -> Result<vk::Result, Error> {
+        match (try_load_fn(self.fns.get_physical_device_calibrateable_time_domains_ext, "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT")?)(physical_device, p_time_domain_count, p_time_domains) {
+            result @ (vk::Result::Success | vk::Result::Incomplete) => Ok(result),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT",
+                create_info: None,
+            }),
+        }
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Additional Capabilities"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Query calibrateable time domains"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_EXT_calibrated_timestamps`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_calibrated_timestamps.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkGetPhysicalDeviceCalibrateableTimeDomainsEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetPhysicalDeviceCalibrateableTimeDomainsEXT.html)"]
+    pub unsafe fn get_physical_device_calibrateable_time_domains_ext_to_vec(&self, physical_device: vk::PhysicalDevice) -> Result<Vec<vk::TimeDomainEXT>, Error> {
+        loop {
+            let mut time_domain_count = 0;
+            match (try_load_fn(self.fns.get_physical_device_calibrateable_time_domains_ext, "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT")?)(
+                physical_device,
+                &raw mut time_domain_count,
+                std::ptr::null_mut(),
+            ) {
+                vk::Result::Success => {}
+                result => {
+                    return Err(Error::Vulkan {
+                        result,
+                        command: "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT",
+                        create_info: None,
+                    })
+                }
+            }
+            let mut time_domains = Vec::with_capacity(time_domain_count as usize);
+            match (try_load_fn(self.fns.get_physical_device_calibrateable_time_domains_ext, "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT")?)(
+                physical_device,
+                &raw mut time_domain_count,
+                time_domains.as_mut_ptr(),
+            ) {
+                vk::Result::Success => {
+                    time_domains.set_len(time_domain_count as usize);
+                    return Ok(time_domains);
+                }
+                vk::Result::Incomplete => {}
+                result => {
+                    return Err(Error::Vulkan {
+                        result,
+                        command: "vkGetPhysicalDeviceCalibrateableTimeDomainsEXT",
+                        create_info: None,
+                    })
+                }
+            }
         }
     }
 
@@ -388,10 +1161,19 @@ impl Instance {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCreateDebugUtilsMessengerEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDebugUtilsMessengerEXT.html)"]
     pub unsafe fn create_debug_utils_messenger_ext(&self, p_create_info: *const vk::DebugUtilsMessengerCreateInfoEXT) -> Result<vk::DebugUtilsMessengerEXT, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreateDebugUtilsMessengerEXT", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreateDebugUtilsMessengerEXT", p_create_info, 1);
+        }
         let mut p_messenger = std::mem::MaybeUninit::uninit();
-        match (self.fns.create_debug_utils_messenger_ext.unwrap_unchecked())(self.handle, p_create_info, std::ptr::null(), p_messenger.as_mut_ptr()) {
+        match (try_load_fn(self.fns.create_debug_utils_messenger_ext, "vkCreateDebugUtilsMessengerEXT")?)(self.handle, p_create_info, std::ptr::null(), p_messenger.as_mut_ptr()) {
             vk::Result::Success => Ok(p_messenger.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateDebugUtilsMessengerEXT",
+                create_info: Some("VkDebugUtilsMessengerCreateInfoEXT"),
+            }),
         }
     }
 
@@ -404,7 +1186,7 @@ impl Instance {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkDestroyDebugUtilsMessengerEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyDebugUtilsMessengerEXT.html)"]
     pub unsafe fn destroy_debug_utils_messenger_ext(&self, messenger: vk::DebugUtilsMessengerEXT) {
-        (self.fns.destroy_debug_utils_messenger_ext.unwrap_unchecked())(self.handle, messenger, std::ptr::null());
+        (load_fn(self.fns.destroy_debug_utils_messenger_ext, "vkDestroyDebugUtilsMessengerEXT"))(self.handle, messenger, std::ptr::null());
     }
 }
 
@@ -641,6 +1423,220 @@ impl Device {
         &self.fns
     }
 
+    /// Returns `true` if the command `name`, e.g. `"vkCreateBuffer"`, was
+    /// resolved when loading.
+    #[must_use]
+    pub fn is_loaded(&self, name: &str) -> bool {
+        match name {
+            "vkDestroyDevice" => self.fns.destroy_device.is_some(),
+            "vkGetDeviceQueue2" => self.fns.get_device_queue2.is_some(),
+            "vkCreateCommandPool" => self.fns.create_command_pool.is_some(),
+            "vkResetCommandPool" => self.fns.reset_command_pool.is_some(),
+            "vkDestroyCommandPool" => self.fns.destroy_command_pool.is_some(),
+            "vkAllocateCommandBuffers" => self.fns.allocate_command_buffers.is_some(),
+            "vkResetCommandBuffer" => self.fns.reset_command_buffer.is_some(),
+            "vkFreeCommandBuffers" => self.fns.free_command_buffers.is_some(),
+            "vkBeginCommandBuffer" => self.fns.begin_command_buffer.is_some(),
+            "vkEndCommandBuffer" => self.fns.end_command_buffer.is_some(),
+            "vkQueueSubmit2" => self.fns.queue_submit2.is_some(),
+            "vkCreateSemaphore" => self.fns.create_semaphore.is_some(),
+            "vkDestroySemaphore" => self.fns.destroy_semaphore.is_some(),
+            "vkGetSemaphoreCounterValue" => self.fns.get_semaphore_counter_value.is_some(),
+            "vkWaitSemaphores" => self.fns.wait_semaphores.is_some(),
+            "vkSignalSemaphore" => self.fns.signal_semaphore.is_some(),
+            "vkCmdPipelineBarrier2" => self.fns.cmd_pipeline_barrier2.is_some(),
+            "vkQueueWaitIdle" => self.fns.queue_wait_idle.is_some(),
+            "vkDeviceWaitIdle" => self.fns.device_wait_idle.is_some(),
+            "vkGetCalibratedTimestampsEXT" => self.fns.get_calibrated_timestamps_ext.is_some(),
+            "vkCmdBeginRendering" => self.fns.cmd_begin_rendering.is_some(),
+            "vkCmdEndRendering" => self.fns.cmd_end_rendering.is_some(),
+            "vkCreateShadersEXT" => self.fns.create_shaders_ext.is_some(),
+            "vkCmdBindShadersEXT" => self.fns.cmd_bind_shaders_ext.is_some(),
+            "vkDestroyShaderEXT" => self.fns.destroy_shader_ext.is_some(),
+            "vkCreateShaderModule" => self.fns.create_shader_module.is_some(),
+            "vkDestroyShaderModule" => self.fns.destroy_shader_module.is_some(),
+            "vkCreateRayTracingPipelinesKHR" => self.fns.create_ray_tracing_pipelines_khr.is_some(),
+            "vkGetRayTracingShaderGroupHandlesKHR" => self.fns.get_ray_tracing_shader_group_handles_khr.is_some(),
+            "vkDestroyPipeline" => self.fns.destroy_pipeline.is_some(),
+            "vkCmdBindPipeline" => self.fns.cmd_bind_pipeline.is_some(),
+            "vkAllocateMemory" => self.fns.allocate_memory.is_some(),
+            "vkFreeMemory" => self.fns.free_memory.is_some(),
+            "vkMapMemory2KHR" => self.fns.map_memory2_khr.is_some(),
+            "vkUnmapMemory2KHR" => self.fns.unmap_memory2_khr.is_some(),
+            "vkCreateBuffer" => self.fns.create_buffer.is_some(),
+            "vkDestroyBuffer" => self.fns.destroy_buffer.is_some(),
+            "vkCreateImage" => self.fns.create_image.is_some(),
+            "vkDestroyImage" => self.fns.destroy_image.is_some(),
+            "vkCreateImageView" => self.fns.create_image_view.is_some(),
+            "vkDestroyImageView" => self.fns.destroy_image_view.is_some(),
+            "vkCreateAccelerationStructureKHR" => self.fns.create_acceleration_structure_khr.is_some(),
+            "vkGetAccelerationStructureBuildSizesKHR" => self.fns.get_acceleration_structure_build_sizes_khr.is_some(),
+            "vkDestroyAccelerationStructureKHR" => self.fns.destroy_acceleration_structure_khr.is_some(),
+            "vkGetAccelerationStructureDeviceAddressKHR" => self.fns.get_acceleration_structure_device_address_khr.is_some(),
+            "vkGetDeviceBufferMemoryRequirements" => self.fns.get_device_buffer_memory_requirements.is_some(),
+            "vkGetDeviceImageMemoryRequirements" => self.fns.get_device_image_memory_requirements.is_some(),
+            "vkBindBufferMemory2" => self.fns.bind_buffer_memory2.is_some(),
+            "vkBindImageMemory2" => self.fns.bind_image_memory2.is_some(),
+            "vkCreateSampler" => self.fns.create_sampler.is_some(),
+            "vkDestroySampler" => self.fns.destroy_sampler.is_some(),
+            "vkCreateDescriptorSetLayout" => self.fns.create_descriptor_set_layout.is_some(),
+            "vkDestroyDescriptorSetLayout" => self.fns.destroy_descriptor_set_layout.is_some(),
+            "vkCreatePipelineLayout" => self.fns.create_pipeline_layout.is_some(),
+            "vkDestroyPipelineLayout" => self.fns.destroy_pipeline_layout.is_some(),
+            "vkCmdPushConstants" => self.fns.cmd_push_constants.is_some(),
+            "vkGetBufferDeviceAddress" => self.fns.get_buffer_device_address.is_some(),
+            "vkGetDescriptorSetLayoutSizeEXT" => self.fns.get_descriptor_set_layout_size_ext.is_some(),
+            "vkGetDescriptorSetLayoutBindingOffsetEXT" => self.fns.get_descriptor_set_layout_binding_offset_ext.is_some(),
+            "vkGetDescriptorEXT" => self.fns.get_descriptor_ext.is_some(),
+            "vkCmdBindDescriptorBuffersEXT" => self.fns.cmd_bind_descriptor_buffers_ext.is_some(),
+            "vkCmdSetDescriptorBufferOffsetsEXT" => self.fns.cmd_set_descriptor_buffer_offsets_ext.is_some(),
+            "vkCreateQueryPool" => self.fns.create_query_pool.is_some(),
+            "vkDestroyQueryPool" => self.fns.destroy_query_pool.is_some(),
+            "vkResetQueryPool" => self.fns.reset_query_pool.is_some(),
+            "vkCmdBeginQuery" => self.fns.cmd_begin_query.is_some(),
+            "vkCmdEndQuery" => self.fns.cmd_end_query.is_some(),
+            "vkGetQueryPoolResults" => self.fns.get_query_pool_results.is_some(),
+            "vkCmdWriteTimestamp2" => self.fns.cmd_write_timestamp2.is_some(),
+            "vkCmdCopyBuffer2" => self.fns.cmd_copy_buffer2.is_some(),
+            "vkCmdCopyImage2" => self.fns.cmd_copy_image2.is_some(),
+            "vkCmdCopyBufferToImage2" => self.fns.cmd_copy_buffer_to_image2.is_some(),
+            "vkCmdCopyImageToBuffer2" => self.fns.cmd_copy_image_to_buffer2.is_some(),
+            "vkCmdDrawMeshTasksEXT" => self.fns.cmd_draw_mesh_tasks_ext.is_some(),
+            "vkCmdDrawMeshTasksIndirectEXT" => self.fns.cmd_draw_mesh_tasks_indirect_ext.is_some(),
+            "vkCmdDrawMeshTasksIndirectCountEXT" => self.fns.cmd_draw_mesh_tasks_indirect_count_ext.is_some(),
+            "vkCmdSetViewportWithCount" => self.fns.cmd_set_viewport_with_count.is_some(),
+            "vkCmdSetScissorWithCount" => self.fns.cmd_set_scissor_with_count.is_some(),
+            "vkCmdSetRasterizationSamplesEXT" => self.fns.cmd_set_rasterization_samples_ext.is_some(),
+            "vkCmdSetFrontFace" => self.fns.cmd_set_front_face.is_some(),
+            "vkCmdSetCullMode" => self.fns.cmd_set_cull_mode.is_some(),
+            "vkCmdSetDepthTestEnable" => self.fns.cmd_set_depth_test_enable.is_some(),
+            "vkCmdSetDepthCompareOp" => self.fns.cmd_set_depth_compare_op.is_some(),
+            "vkCmdSetDepthWriteEnable" => self.fns.cmd_set_depth_write_enable.is_some(),
+            "vkCmdSetColorBlendEnableEXT" => self.fns.cmd_set_color_blend_enable_ext.is_some(),
+            "vkCmdSetColorBlendEquationEXT" => self.fns.cmd_set_color_blend_equation_ext.is_some(),
+            "vkCmdSetColorWriteMaskEXT" => self.fns.cmd_set_color_write_mask_ext.is_some(),
+            "vkCmdDispatch" => self.fns.cmd_dispatch.is_some(),
+            "vkCmdDispatchIndirect" => self.fns.cmd_dispatch_indirect.is_some(),
+            "vkCreateSwapchainKHR" => self.fns.create_swapchain_khr.is_some(),
+            "vkDestroySwapchainKHR" => self.fns.destroy_swapchain_khr.is_some(),
+            "vkGetSwapchainImagesKHR" => self.fns.get_swapchain_images_khr.is_some(),
+            "vkAcquireNextImage2KHR" => self.fns.acquire_next_image2_khr.is_some(),
+            "vkQueuePresentKHR" => self.fns.queue_present_khr.is_some(),
+            "vkCmdBuildAccelerationStructuresKHR" => self.fns.cmd_build_acceleration_structures_khr.is_some(),
+            "vkCmdTraceRaysKHR" => self.fns.cmd_trace_rays_khr.is_some(),
+            "vkCmdTraceRaysIndirect2KHR" => self.fns.cmd_trace_rays_indirect2_khr.is_some(),
+            _ => false,
+        }
+    }
+
+    /// Commands that failed to resolve when loading, typically because their
+    /// extension was not enabled.
+    #[must_use]
+    pub fn missing_commands(&self) -> Vec<&'static str> {
+        [
+            ("vkDestroyDevice", self.fns.destroy_device.is_some()),
+            ("vkGetDeviceQueue2", self.fns.get_device_queue2.is_some()),
+            ("vkCreateCommandPool", self.fns.create_command_pool.is_some()),
+            ("vkResetCommandPool", self.fns.reset_command_pool.is_some()),
+            ("vkDestroyCommandPool", self.fns.destroy_command_pool.is_some()),
+            ("vkAllocateCommandBuffers", self.fns.allocate_command_buffers.is_some()),
+            ("vkResetCommandBuffer", self.fns.reset_command_buffer.is_some()),
+            ("vkFreeCommandBuffers", self.fns.free_command_buffers.is_some()),
+            ("vkBeginCommandBuffer", self.fns.begin_command_buffer.is_some()),
+            ("vkEndCommandBuffer", self.fns.end_command_buffer.is_some()),
+            ("vkQueueSubmit2", self.fns.queue_submit2.is_some()),
+            ("vkCreateSemaphore", self.fns.create_semaphore.is_some()),
+            ("vkDestroySemaphore", self.fns.destroy_semaphore.is_some()),
+            ("vkGetSemaphoreCounterValue", self.fns.get_semaphore_counter_value.is_some()),
+            ("vkWaitSemaphores", self.fns.wait_semaphores.is_some()),
+            ("vkSignalSemaphore", self.fns.signal_semaphore.is_some()),
+            ("vkCmdPipelineBarrier2", self.fns.cmd_pipeline_barrier2.is_some()),
+            ("vkQueueWaitIdle", self.fns.queue_wait_idle.is_some()),
+            ("vkDeviceWaitIdle", self.fns.device_wait_idle.is_some()),
+            ("vkGetCalibratedTimestampsEXT", self.fns.get_calibrated_timestamps_ext.is_some()),
+            ("vkCmdBeginRendering", self.fns.cmd_begin_rendering.is_some()),
+            ("vkCmdEndRendering", self.fns.cmd_end_rendering.is_some()),
+            ("vkCreateShadersEXT", self.fns.create_shaders_ext.is_some()),
+            ("vkCmdBindShadersEXT", self.fns.cmd_bind_shaders_ext.is_some()),
+            ("vkDestroyShaderEXT", self.fns.destroy_shader_ext.is_some()),
+            ("vkCreateShaderModule", self.fns.create_shader_module.is_some()),
+            ("vkDestroyShaderModule", self.fns.destroy_shader_module.is_some()),
+            ("vkCreateRayTracingPipelinesKHR", self.fns.create_ray_tracing_pipelines_khr.is_some()),
+            ("vkGetRayTracingShaderGroupHandlesKHR", self.fns.get_ray_tracing_shader_group_handles_khr.is_some()),
+            ("vkDestroyPipeline", self.fns.destroy_pipeline.is_some()),
+            ("vkCmdBindPipeline", self.fns.cmd_bind_pipeline.is_some()),
+            ("vkAllocateMemory", self.fns.allocate_memory.is_some()),
+            ("vkFreeMemory", self.fns.free_memory.is_some()),
+            ("vkMapMemory2KHR", self.fns.map_memory2_khr.is_some()),
+            ("vkUnmapMemory2KHR", self.fns.unmap_memory2_khr.is_some()),
+            ("vkCreateBuffer", self.fns.create_buffer.is_some()),
+            ("vkDestroyBuffer", self.fns.destroy_buffer.is_some()),
+            ("vkCreateImage", self.fns.create_image.is_some()),
+            ("vkDestroyImage", self.fns.destroy_image.is_some()),
+            ("vkCreateImageView", self.fns.create_image_view.is_some()),
+            ("vkDestroyImageView", self.fns.destroy_image_view.is_some()),
+            ("vkCreateAccelerationStructureKHR", self.fns.create_acceleration_structure_khr.is_some()),
+            ("vkGetAccelerationStructureBuildSizesKHR", self.fns.get_acceleration_structure_build_sizes_khr.is_some()),
+            ("vkDestroyAccelerationStructureKHR", self.fns.destroy_acceleration_structure_khr.is_some()),
+            ("vkGetAccelerationStructureDeviceAddressKHR", self.fns.get_acceleration_structure_device_address_khr.is_some()),
+            ("vkGetDeviceBufferMemoryRequirements", self.fns.get_device_buffer_memory_requirements.is_some()),
+            ("vkGetDeviceImageMemoryRequirements", self.fns.get_device_image_memory_requirements.is_some()),
+            ("vkBindBufferMemory2", self.fns.bind_buffer_memory2.is_some()),
+            ("vkBindImageMemory2", self.fns.bind_image_memory2.is_some()),
+            ("vkCreateSampler", self.fns.create_sampler.is_some()),
+            ("vkDestroySampler", self.fns.destroy_sampler.is_some()),
+            ("vkCreateDescriptorSetLayout", self.fns.create_descriptor_set_layout.is_some()),
+            ("vkDestroyDescriptorSetLayout", self.fns.destroy_descriptor_set_layout.is_some()),
+            ("vkCreatePipelineLayout", self.fns.create_pipeline_layout.is_some()),
+            ("vkDestroyPipelineLayout", self.fns.destroy_pipeline_layout.is_some()),
+            ("vkCmdPushConstants", self.fns.cmd_push_constants.is_some()),
+            ("vkGetBufferDeviceAddress", self.fns.get_buffer_device_address.is_some()),
+            ("vkGetDescriptorSetLayoutSizeEXT", self.fns.get_descriptor_set_layout_size_ext.is_some()),
+            ("vkGetDescriptorSetLayoutBindingOffsetEXT", self.fns.get_descriptor_set_layout_binding_offset_ext.is_some()),
+            ("vkGetDescriptorEXT", self.fns.get_descriptor_ext.is_some()),
+            ("vkCmdBindDescriptorBuffersEXT", self.fns.cmd_bind_descriptor_buffers_ext.is_some()),
+            ("vkCmdSetDescriptorBufferOffsetsEXT", self.fns.cmd_set_descriptor_buffer_offsets_ext.is_some()),
+            ("vkCreateQueryPool", self.fns.create_query_pool.is_some()),
+            ("vkDestroyQueryPool", self.fns.destroy_query_pool.is_some()),
+            ("vkResetQueryPool", self.fns.reset_query_pool.is_some()),
+            ("vkCmdBeginQuery", self.fns.cmd_begin_query.is_some()),
+            ("vkCmdEndQuery", self.fns.cmd_end_query.is_some()),
+            ("vkGetQueryPoolResults", self.fns.get_query_pool_results.is_some()),
+            ("vkCmdWriteTimestamp2", self.fns.cmd_write_timestamp2.is_some()),
+            ("vkCmdCopyBuffer2", self.fns.cmd_copy_buffer2.is_some()),
+            ("vkCmdCopyImage2", self.fns.cmd_copy_image2.is_some()),
+            ("vkCmdCopyBufferToImage2", self.fns.cmd_copy_buffer_to_image2.is_some()),
+            ("vkCmdCopyImageToBuffer2", self.fns.cmd_copy_image_to_buffer2.is_some()),
+            ("vkCmdDrawMeshTasksEXT", self.fns.cmd_draw_mesh_tasks_ext.is_some()),
+            ("vkCmdDrawMeshTasksIndirectEXT", self.fns.cmd_draw_mesh_tasks_indirect_ext.is_some()),
+            ("vkCmdDrawMeshTasksIndirectCountEXT", self.fns.cmd_draw_mesh_tasks_indirect_count_ext.is_some()),
+            ("vkCmdSetViewportWithCount", self.fns.cmd_set_viewport_with_count.is_some()),
+            ("vkCmdSetScissorWithCount", self.fns.cmd_set_scissor_with_count.is_some()),
+            ("vkCmdSetRasterizationSamplesEXT", self.fns.cmd_set_rasterization_samples_ext.is_some()),
+            ("vkCmdSetFrontFace", self.fns.cmd_set_front_face.is_some()),
+            ("vkCmdSetCullMode", self.fns.cmd_set_cull_mode.is_some()),
+            ("vkCmdSetDepthTestEnable", self.fns.cmd_set_depth_test_enable.is_some()),
+            ("vkCmdSetDepthCompareOp", self.fns.cmd_set_depth_compare_op.is_some()),
+            ("vkCmdSetDepthWriteEnable", self.fns.cmd_set_depth_write_enable.is_some()),
+            ("vkCmdSetColorBlendEnableEXT", self.fns.cmd_set_color_blend_enable_ext.is_some()),
+            ("vkCmdSetColorBlendEquationEXT", self.fns.cmd_set_color_blend_equation_ext.is_some()),
+            ("vkCmdSetColorWriteMaskEXT", self.fns.cmd_set_color_write_mask_ext.is_some()),
+            ("vkCmdDispatch", self.fns.cmd_dispatch.is_some()),
+            ("vkCmdDispatchIndirect", self.fns.cmd_dispatch_indirect.is_some()),
+            ("vkCreateSwapchainKHR", self.fns.create_swapchain_khr.is_some()),
+            ("vkDestroySwapchainKHR", self.fns.destroy_swapchain_khr.is_some()),
+            ("vkGetSwapchainImagesKHR", self.fns.get_swapchain_images_khr.is_some()),
+            ("vkAcquireNextImage2KHR", self.fns.acquire_next_image2_khr.is_some()),
+            ("vkQueuePresentKHR", self.fns.queue_present_khr.is_some()),
+            ("vkCmdBuildAccelerationStructuresKHR", self.fns.cmd_build_acceleration_structures_khr.is_some()),
+            ("vkCmdTraceRaysKHR", self.fns.cmd_trace_rays_khr.is_some()),
+            ("vkCmdTraceRaysIndirect2KHR", self.fns.cmd_trace_rays_indirect2_khr.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(name, loaded)| (!loaded).then_some(name))
+        .collect()
+    }
+
     #[inline]
     #[doc = "**Chapter**: Devices and Queues"]
     #[doc = "<br>"]
@@ -650,7 +1646,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkDestroyDevice`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyDevice.html)"]
     pub unsafe fn destroy_device(&self) {
-        (self.fns.destroy_device.unwrap_unchecked())(self.handle, std::ptr::null());
+        (load_fn(self.fns.destroy_device, "vkDestroyDevice"))(self.handle, std::ptr::null());
     }
 
     #[must_use]
@@ -663,8 +1659,13 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkGetDeviceQueue2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceQueue2.html)"]
     pub unsafe fn get_device_queue2(&self, p_queue_info: *const vk::DeviceQueueInfo2) -> vk::Queue {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkGetDeviceQueue2", "pQueueInfo", p_queue_info);
+            vk::check_structs("vkGetDeviceQueue2", p_queue_info, 1);
+        }
         let mut p_queue = std::mem::MaybeUninit::uninit();
-        (self.fns.get_device_queue2.unwrap_unchecked())(self.handle, p_queue_info, p_queue.as_mut_ptr());
+        (load_fn(self.fns.get_device_queue2, "vkGetDeviceQueue2"))(self.handle, p_queue_info, p_queue.as_mut_ptr());
         p_queue.assume_init()
     }
 
@@ -677,10 +1678,19 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCreateCommandPool`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateCommandPool.html)"]
     pub unsafe fn create_command_pool(&self, p_create_info: *const vk::CommandPoolCreateInfo) -> Result<vk::CommandPool, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreateCommandPool", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreateCommandPool", p_create_info, 1);
+        }
         let mut p_command_pool = std::mem::MaybeUninit::uninit();
-        match (self.fns.create_command_pool.unwrap_unchecked())(self.handle, p_create_info, std::ptr::null(), p_command_pool.as_mut_ptr()) {
+        match (try_load_fn(self.fns.create_command_pool, "vkCreateCommandPool")?)(self.handle, p_create_info, std::ptr::null(), p_command_pool.as_mut_ptr()) {
             vk::Result::Success => Ok(p_command_pool.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateCommandPool",
+                create_info: Some("VkCommandPoolCreateInfo"),
+            }),
         }
     }
 
@@ -693,9 +1703,13 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkResetCommandPool`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkResetCommandPool.html)"]
     pub unsafe fn reset_command_pool(&self, command_pool: vk::CommandPool, flags: vk::CommandPoolResetFlags) -> Result<(), Error> {
-        match (self.fns.reset_command_pool.unwrap_unchecked())(self.handle, command_pool, flags) {
+        match (try_load_fn(self.fns.reset_command_pool, "vkResetCommandPool")?)(self.handle, command_pool, flags) {
             vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkResetCommandPool",
+                create_info: None,
+            }),
         }
     }
 
@@ -708,7 +1722,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkDestroyCommandPool`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyCommandPool.html)"]
     pub unsafe fn destroy_command_pool(&self, command_pool: vk::CommandPool) {
-        (self.fns.destroy_command_pool.unwrap_unchecked())(self.handle, command_pool, std::ptr::null());
+        (load_fn(self.fns.destroy_command_pool, "vkDestroyCommandPool"))(self.handle, command_pool, std::ptr::null());
     }
 
     #[inline]
@@ -720,9 +1734,18 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkAllocateCommandBuffers`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAllocateCommandBuffers.html)"]
     pub unsafe fn allocate_command_buffers(&self, p_allocate_info: *const vk::CommandBufferAllocateInfo, p_command_buffers: *mut vk::CommandBuffer) -> Result<(), Error> {
-        match (self.fns.allocate_command_buffers.unwrap_unchecked())(self.handle, p_allocate_info, p_command_buffers) {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkAllocateCommandBuffers", "pAllocateInfo", p_allocate_info);
+            vk::check_structs("vkAllocateCommandBuffers", p_allocate_info, 1);
+        }
+        match (try_load_fn(self.fns.allocate_command_buffers, "vkAllocateCommandBuffers")?)(self.handle, p_allocate_info, p_command_buffers) {
             vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkAllocateCommandBuffers",
+                create_info: Some("VkCommandBufferAllocateInfo"),
+            }),
         }
     }
 
@@ -735,9 +1758,13 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkResetCommandBuffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkResetCommandBuffer.html)"]
     pub unsafe fn reset_command_buffer(&self, command_buffer: vk::CommandBuffer, flags: vk::CommandBufferResetFlags) -> Result<(), Error> {
-        match (self.fns.reset_command_buffer.unwrap_unchecked())(command_buffer, flags) {
+        match (try_load_fn(self.fns.reset_command_buffer, "vkResetCommandBuffer")?)(command_buffer, flags) {
             vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkResetCommandBuffer",
+                create_info: None,
+            }),
         }
     }
 
@@ -750,7 +1777,19 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkFreeCommandBuffers`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkFreeCommandBuffers.html)"]
     pub unsafe fn free_command_buffers(&self, command_pool: vk::CommandPool, command_buffer_count: u32, p_command_buffers: *const vk::CommandBuffer) {
-        (self.fns.free_command_buffers.unwrap_unchecked())(self.handle, command_pool, command_buffer_count, p_command_buffers);
+        (load_fn(self.fns.free_command_buffers, "vkFreeCommandBuffers"))(self.handle, command_pool, command_buffer_count, p_command_buffers);
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Command Buffers"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Free command buffers"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_VERSION_1_0`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_VERSION_1_0.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkFreeCommandBuffers`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkFreeCommandBuffers.html)"]
+    pub unsafe fn free_command_buffers_slice(&self, command_pool: vk::CommandPool, command_buffers: &[vk::CommandBuffer]) {
+        self.free_command_buffers(command_pool, u32::try_from(command_buffers.len()).unwrap(), command_buffers.as_ptr());
     }
 
     #[inline]
@@ -762,9 +1801,18 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkBeginCommandBuffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBeginCommandBuffer.html)"]
     pub unsafe fn begin_command_buffer(&self, command_buffer: vk::CommandBuffer, p_begin_info: *const vk::CommandBufferBeginInfo) -> Result<(), Error> {
-        match (self.fns.begin_command_buffer.unwrap_unchecked())(command_buffer, p_begin_info) {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkBeginCommandBuffer", "pBeginInfo", p_begin_info);
+            vk::check_structs("vkBeginCommandBuffer", p_begin_info, 1);
+        }
+        match (try_load_fn(self.fns.begin_command_buffer, "vkBeginCommandBuffer")?)(command_buffer, p_begin_info) {
             vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkBeginCommandBuffer",
+                create_info: None,
+            }),
         }
     }
 
@@ -777,9 +1825,13 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkEndCommandBuffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkEndCommandBuffer.html)"]
     pub unsafe fn end_command_buffer(&self, command_buffer: vk::CommandBuffer) -> Result<(), Error> {
-        match (self.fns.end_command_buffer.unwrap_unchecked())(command_buffer) {
+        match (try_load_fn(self.fns.end_command_buffer, "vkEndCommandBuffer")?)(command_buffer) {
             vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkEndCommandBuffer",
+                create_info: None,
+            }),
         }
     }
 
@@ -792,12 +1844,33 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkQueueSubmit2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueSubmit2.html)"]
     pub unsafe fn queue_submit2(&self, queue: vk::Queue, submit_count: u32, p_submits: *const vk::SubmitInfo2, fence: vk::Fence) -> Result<(), Error> {
-        match (self.fns.queue_submit2.unwrap_unchecked())(queue, submit_count, p_submits, fence) {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_array("vkQueueSubmit2", "pSubmits", p_submits, submit_count as usize);
+            vk::check_structs("vkQueueSubmit2", p_submits, submit_count as usize);
+        }
+        match (try_load_fn(self.fns.queue_submit2, "vkQueueSubmit2")?)(queue, submit_count, p_submits, fence) {
             vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkQueueSubmit2",
+                create_info: None,
+            }),
         }
     }
 
+    #[inline]
+    #[doc = "**Chapter**: Command Buffers"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Submits command buffers to a queue"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_VERSION_1_3`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_VERSION_1_3.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkQueueSubmit2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueSubmit2.html)"]
+    pub unsafe fn queue_submit2_slice(&self, queue: vk::Queue, submits: &[vk::SubmitInfo2], fence: vk::Fence) -> Result<(), Error> {
+        self.queue_submit2(queue, u32::try_from(submits.len()).unwrap(), submits.as_ptr(), fence)
+    }
+
     #[inline]
     #[doc = "**Chapter**: Synchronization and Cache Control"]
     #[doc = "<br>"]
@@ -807,10 +1880,19 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCreateSemaphore`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateSemaphore.html)"]
     pub unsafe fn create_semaphore(&self, p_create_info: *const vk::SemaphoreCreateInfo) -> Result<vk::Semaphore, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreateSemaphore", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreateSemaphore", p_create_info, 1);
+        }
         let mut p_semaphore = std::mem::MaybeUninit::uninit();
-        match (self.fns.create_semaphore.unwrap_unchecked())(self.handle, p_create_info, std::ptr::null(), p_semaphore.as_mut_ptr()) {
+        match (try_load_fn(self.fns.create_semaphore, "vkCreateSemaphore")?)(self.handle, p_create_info, std::ptr::null(), p_semaphore.as_mut_ptr()) {
             vk::Result::Success => Ok(p_semaphore.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateSemaphore",
+                create_info: Some("VkSemaphoreCreateInfo"),
+            }),
         }
     }
 
@@ -823,7 +1905,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkDestroySemaphore`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroySemaphore.html)"]
     pub unsafe fn destroy_semaphore(&self, semaphore: vk::Semaphore) {
-        (self.fns.destroy_semaphore.unwrap_unchecked())(self.handle, semaphore, std::ptr::null());
+        (load_fn(self.fns.destroy_semaphore, "vkDestroySemaphore"))(self.handle, semaphore, std::ptr::null());
     }
 
     #[inline]
@@ -836,9 +1918,13 @@ impl Device {
     #[doc = "**Reference**: [`vkGetSemaphoreCounterValue`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSemaphoreCounterValue.html)"]
     pub unsafe fn get_semaphore_counter_value(&self, semaphore: vk::Semaphore) -> Result<u64, Error> {
         let mut p_value = std::mem::MaybeUninit::uninit();
-        match (self.fns.get_semaphore_counter_value.unwrap_unchecked())(self.handle, semaphore, p_value.as_mut_ptr()) {
+        match (try_load_fn(self.fns.get_semaphore_counter_value, "vkGetSemaphoreCounterValue")?)(self.handle, semaphore, p_value.as_mut_ptr()) {
             vk::Result::Success => Ok(p_value.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkGetSemaphoreCounterValue",
+                create_info: None,
+            }),
         }
     }
 
@@ -850,10 +1936,19 @@ impl Device {
     #[doc = "**Provided by**: [`VK_VERSION_1_2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_VERSION_1_2.html)"]
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkWaitSemaphores`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkWaitSemaphores.html)"]
-    pub unsafe fn wait_semaphores(&self, p_wait_info: *const vk::SemaphoreWaitInfo, timeout: u64) -> Result<(), Error> {
-        match (self.fns.wait_semaphores.unwrap_unchecked())(self.handle, p_wait_info, timeout) {
-            vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+    pub unsafe fn wait_semaphores(&self, p_wait_info: *const vk::SemaphoreWaitInfo, timeout: u64) -> Result<vk::Result, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkWaitSemaphores", "pWaitInfo", p_wait_info);
+            vk::check_structs("vkWaitSemaphores", p_wait_info, 1);
+        }
+        match (try_load_fn(self.fns.wait_semaphores, "vkWaitSemaphores")?)(self.handle, p_wait_info, timeout) {
+            result @ (vk::Result::Success | vk::Result::Timeout) => Ok(result),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkWaitSemaphores",
+                create_info: None,
+            }),
         }
     }
 
@@ -866,9 +1961,18 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkSignalSemaphore`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkSignalSemaphore.html)"]
     pub unsafe fn signal_semaphore(&self, p_signal_info: *const vk::SemaphoreSignalInfo) -> Result<(), Error> {
-        match (self.fns.signal_semaphore.unwrap_unchecked())(self.handle, p_signal_info) {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkSignalSemaphore", "pSignalInfo", p_signal_info);
+            vk::check_structs("vkSignalSemaphore", p_signal_info, 1);
+        }
+        match (try_load_fn(self.fns.signal_semaphore, "vkSignalSemaphore")?)(self.handle, p_signal_info) {
             vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkSignalSemaphore",
+                create_info: None,
+            }),
         }
     }
 
@@ -881,7 +1985,12 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdPipelineBarrier2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPipelineBarrier2.html)"]
     pub unsafe fn cmd_pipeline_barrier2(&self, command_buffer: vk::CommandBuffer, p_dependency_info: *const vk::DependencyInfo) {
-        (self.fns.cmd_pipeline_barrier2.unwrap_unchecked())(command_buffer, p_dependency_info);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCmdPipelineBarrier2", "pDependencyInfo", p_dependency_info);
+            vk::check_structs("vkCmdPipelineBarrier2", p_dependency_info, 1);
+        }
+        (load_fn(self.fns.cmd_pipeline_barrier2, "vkCmdPipelineBarrier2"))(command_buffer, p_dependency_info);
     }
 
     #[inline]
@@ -893,9 +2002,13 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkQueueWaitIdle`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueueWaitIdle.html)"]
     pub unsafe fn queue_wait_idle(&self, queue: vk::Queue) -> Result<(), Error> {
-        match (self.fns.queue_wait_idle.unwrap_unchecked())(queue) {
+        match (try_load_fn(self.fns.queue_wait_idle, "vkQueueWaitIdle")?)(queue) {
             vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkQueueWaitIdle",
+                create_info: None,
+            }),
         }
     }
 
@@ -908,9 +2021,13 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkDeviceWaitIdle`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDeviceWaitIdle.html)"]
     pub unsafe fn device_wait_idle(&self) -> Result<(), Error> {
-        match (self.fns.device_wait_idle.unwrap_unchecked())(self.handle) {
+        match (try_load_fn(self.fns.device_wait_idle, "vkDeviceWaitIdle")?)(self.handle) {
             vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkDeviceWaitIdle",
+                create_info: None,
+            }),
         }
     }
 
@@ -929,12 +2046,35 @@ impl Device {
         p_timestamps: *mut u64,
         p_max_deviation: *mut u64,
     ) -> Result<(), Error> {
-        match (self.fns.get_calibrated_timestamps_ext.unwrap_unchecked())(self.handle, timestamp_count, p_timestamp_infos, p_timestamps, p_max_deviation) {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_count("vkGetCalibratedTimestampsEXT", "timestampCount", timestamp_count);
+            vk::check_array("vkGetCalibratedTimestampsEXT", "pTimestampInfos", p_timestamp_infos, timestamp_count as usize);
+            vk::check_structs("vkGetCalibratedTimestampsEXT", p_timestamp_infos, timestamp_count as usize);
+        }
+        match (try_load_fn(self.fns.get_calibrated_timestamps_ext, "vkGetCalibratedTimestampsEXT")?)(self.handle, timestamp_count, p_timestamp_infos, p_timestamps, p_max_deviation) {
             vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkGetCalibratedTimestampsEXT",
+                create_info: None,
+            }),
         }
     }
 
+    #[inline]
+    #[doc = "**Chapter**: Synchronization and Cache Control"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Query calibrated timestamps"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_EXT_calibrated_timestamps`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_calibrated_timestamps.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkGetCalibratedTimestampsEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetCalibratedTimestampsEXT.html)"]
+    pub unsafe fn get_calibrated_timestamps_ext_slice(&self, timestamp_infos: &[vk::CalibratedTimestampInfoEXT], timestamps: &mut [u64], p_max_deviation: *mut u64) -> Result<(), Error> {
+        assert_eq!(timestamp_infos.len(), timestamps.len());
+        self.get_calibrated_timestamps_ext(u32::try_from(timestamp_infos.len()).unwrap(), timestamp_infos.as_ptr(), timestamps.as_mut_ptr(), p_max_deviation)
+    }
+
     #[inline]
     #[doc = "**Chapter**: Render Pass"]
     #[doc = "<br>"]
@@ -944,7 +2084,12 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdBeginRendering`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginRendering.html)"]
     pub unsafe fn cmd_begin_rendering(&self, command_buffer: vk::CommandBuffer, p_rendering_info: *const vk::RenderingInfo) {
-        (self.fns.cmd_begin_rendering.unwrap_unchecked())(command_buffer, p_rendering_info);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCmdBeginRendering", "pRenderingInfo", p_rendering_info);
+            vk::check_structs("vkCmdBeginRendering", p_rendering_info, 1);
+        }
+        (load_fn(self.fns.cmd_begin_rendering, "vkCmdBeginRendering"))(command_buffer, p_rendering_info);
     }
 
     #[inline]
@@ -956,7 +2101,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdEndRendering`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndRendering.html)"]
     pub unsafe fn cmd_end_rendering(&self, command_buffer: vk::CommandBuffer) {
-        (self.fns.cmd_end_rendering.unwrap_unchecked())(command_buffer);
+        (load_fn(self.fns.cmd_end_rendering, "vkCmdEndRendering"))(command_buffer);
     }
 
     #[inline]
@@ -968,12 +2113,35 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCreateShadersEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateShadersEXT.html)"]
     pub unsafe fn create_shaders_ext(&self, create_info_count: u32, p_create_infos: *const vk::ShaderCreateInfoEXT, p_shaders: *mut vk::ShaderEXT) -> Result<(), Error> {
-        match (self.fns.create_shaders_ext.unwrap_unchecked())(self.handle, create_info_count, p_create_infos, std::ptr::null(), p_shaders) {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_count("vkCreateShadersEXT", "createInfoCount", create_info_count);
+            vk::check_array("vkCreateShadersEXT", "pCreateInfos", p_create_infos, create_info_count as usize);
+            vk::check_structs("vkCreateShadersEXT", p_create_infos, create_info_count as usize);
+        }
+        match (try_load_fn(self.fns.create_shaders_ext, "vkCreateShadersEXT")?)(self.handle, create_info_count, p_create_infos, std::ptr::null(), p_shaders) {
             vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateShadersEXT",
+                create_info: Some("VkShaderCreateInfoEXT"),
+            }),
         }
     }
 
+    #[inline]
+    #[doc = "**Chapter**: Shaders"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Create one or more new shaders"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_EXT_shader_object`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_shader_object.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkCreateShadersEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateShadersEXT.html)"]
+    pub unsafe fn create_shaders_ext_slice(&self, create_infos: &[vk::ShaderCreateInfoEXT], shaders: &mut [vk::ShaderEXT]) -> Result<(), Error> {
+        assert_eq!(create_infos.len(), shaders.len());
+        self.create_shaders_ext(u32::try_from(create_infos.len()).unwrap(), create_infos.as_ptr(), shaders.as_mut_ptr())
+    }
+
     #[inline]
     #[doc = "**Chapter**: Shaders"]
     #[doc = "<br>"]
@@ -983,7 +2151,26 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdBindShadersEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindShadersEXT.html)"]
     pub unsafe fn cmd_bind_shaders_ext(&self, command_buffer: vk::CommandBuffer, stage_count: u32, p_stages: *const vk::ShaderStageFlagBits, p_shaders: *const vk::ShaderEXT) {
-        (self.fns.cmd_bind_shaders_ext.unwrap_unchecked())(command_buffer, stage_count, p_stages, p_shaders);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_count("vkCmdBindShadersEXT", "stageCount", stage_count);
+            vk::check_array("vkCmdBindShadersEXT", "pStages", p_stages, stage_count as usize);
+            vk::check_array("vkCmdBindShadersEXT", "pShaders", p_shaders, stage_count as usize);
+        }
+        (load_fn(self.fns.cmd_bind_shaders_ext, "vkCmdBindShadersEXT"))(command_buffer, stage_count, p_stages, p_shaders);
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Shaders"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Bind shader objects to a command buffer"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_EXT_shader_object`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_shader_object.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkCmdBindShadersEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindShadersEXT.html)"]
+    pub unsafe fn cmd_bind_shaders_ext_slice(&self, command_buffer: vk::CommandBuffer, stages: &[vk::ShaderStageFlagBits], shaders: &[vk::ShaderEXT]) {
+        assert_eq!(stages.len(), shaders.len());
+        self.cmd_bind_shaders_ext(command_buffer, u32::try_from(stages.len()).unwrap(), stages.as_ptr(), shaders.as_ptr());
     }
 
     #[inline]
@@ -995,7 +2182,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkDestroyShaderEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyShaderEXT.html)"]
     pub unsafe fn destroy_shader_ext(&self, shader: vk::ShaderEXT) {
-        (self.fns.destroy_shader_ext.unwrap_unchecked())(self.handle, shader, std::ptr::null());
+        (load_fn(self.fns.destroy_shader_ext, "vkDestroyShaderEXT"))(self.handle, shader, std::ptr::null());
     }
 
     #[inline]
@@ -1007,10 +2194,19 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCreateShaderModule`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateShaderModule.html)"]
     pub unsafe fn create_shader_module(&self, p_create_info: *const vk::ShaderModuleCreateInfo) -> Result<vk::ShaderModule, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreateShaderModule", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreateShaderModule", p_create_info, 1);
+        }
         let mut p_shader_module = std::mem::MaybeUninit::uninit();
-        match (self.fns.create_shader_module.unwrap_unchecked())(self.handle, p_create_info, std::ptr::null(), p_shader_module.as_mut_ptr()) {
+        match (try_load_fn(self.fns.create_shader_module, "vkCreateShaderModule")?)(self.handle, p_create_info, std::ptr::null(), p_shader_module.as_mut_ptr()) {
             vk::Result::Success => Ok(p_shader_module.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateShaderModule",
+                create_info: Some("VkShaderModuleCreateInfo"),
+            }),
         }
     }
 
@@ -1023,7 +2219,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkDestroyShaderModule`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyShaderModule.html)"]
     pub unsafe fn destroy_shader_module(&self, shader_module: vk::ShaderModule) {
-        (self.fns.destroy_shader_module.unwrap_unchecked())(self.handle, shader_module, std::ptr::null());
+        (load_fn(self.fns.destroy_shader_module, "vkDestroyShaderModule"))(self.handle, shader_module, std::ptr::null());
     }
 
     #[inline]
@@ -1041,13 +2237,56 @@ impl Device {
         create_info_count: u32,
         p_create_infos: *const vk::RayTracingPipelineCreateInfoKHR,
         p_pipelines: *mut vk::Pipeline,
-    ) -> Result<(), Error> {
-        match (self.fns.create_ray_tracing_pipelines_khr.unwrap_unchecked())(self.handle, deferred_operation, pipeline_cache, create_info_count, p_create_infos, std::ptr::null(), p_pipelines) {
-            vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+    ) -> Result<vk::Result, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_count("vkCreateRayTracingPipelinesKHR", "createInfoCount", create_info_count);
+            vk::check_array("vkCreateRayTracingPipelinesKHR", "pCreateInfos", p_create_infos, create_info_count as usize);
+            vk::check_structs("vkCreateRayTracingPipelinesKHR", p_create_infos, create_info_count as usize);
+        }
+        match (try_load_fn(self.fns.create_ray_tracing_pipelines_khr, "vkCreateRayTracingPipelinesKHR")?)(
+            self.handle,
+            deferred_operation,
+            pipeline_cache,
+            create_info_count,
+            p_create_infos,
+            std::ptr::null(),
+            p_pipelines,
+        ) {
+            result @ (vk::Result::Success | vk::Result::OperationDeferredKHR | vk::Result::OperationNotDeferredKHR | vk::Result::PipelineCompileRequired) => Ok(result),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateRayTracingPipelinesKHR",
+                create_info: Some("VkRayTracingPipelineCreateInfoKHR"),
+            }),
         }
     }
 
+    #[inline]
+    #[doc = "**Chapter**: Pipelines"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Creates a new ray tracing pipeline object"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_KHR_ray_tracing_pipeline`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_ray_tracing_pipeline.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkCreateRayTracingPipelinesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateRayTracingPipelinesKHR.html)"]
+    pub unsafe fn create_ray_tracing_pipelines_khr_slice(
+        &self,
+        deferred_operation: vk::DeferredOperationKHR,
+        pipeline_cache: vk::PipelineCache,
+        create_infos: &[vk::RayTracingPipelineCreateInfoKHR],
+        pipelines: &mut [vk::Pipeline],
+    ) -> Result<vk::Result, Error> {
+        assert_eq!(create_infos.len(), pipelines.len());
+        self.create_ray_tracing_pipelines_khr(
+            deferred_operation,
+            pipeline_cache,
+            u32::try_from(create_infos.len()).unwrap(),
+            create_infos.as_ptr(),
+            pipelines.as_mut_ptr(),
+        )
+    }
+
     #[inline]
     #[doc = "**Chapter**: Pipelines"]
     #[doc = "<br>"]
@@ -1057,9 +2296,13 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkGetRayTracingShaderGroupHandlesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetRayTracingShaderGroupHandlesKHR.html)"]
     pub unsafe fn get_ray_tracing_shader_group_handles_khr(&self, pipeline: vk::Pipeline, first_group: u32, group_count: u32, data_size: usize, p_data: *mut c_void) -> Result<(), Error> {
-        match (self.fns.get_ray_tracing_shader_group_handles_khr.unwrap_unchecked())(self.handle, pipeline, first_group, group_count, data_size, p_data) {
+        match (try_load_fn(self.fns.get_ray_tracing_shader_group_handles_khr, "vkGetRayTracingShaderGroupHandlesKHR")?)(self.handle, pipeline, first_group, group_count, data_size, p_data) {
             vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkGetRayTracingShaderGroupHandlesKHR",
+                create_info: None,
+            }),
         }
     }
 
@@ -1072,7 +2315,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkDestroyPipeline`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyPipeline.html)"]
     pub unsafe fn destroy_pipeline(&self, pipeline: vk::Pipeline) {
-        (self.fns.destroy_pipeline.unwrap_unchecked())(self.handle, pipeline, std::ptr::null());
+        (load_fn(self.fns.destroy_pipeline, "vkDestroyPipeline"))(self.handle, pipeline, std::ptr::null());
     }
 
     #[inline]
@@ -1084,7 +2327,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdBindPipeline`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindPipeline.html)"]
     pub unsafe fn cmd_bind_pipeline(&self, command_buffer: vk::CommandBuffer, pipeline_bind_point: vk::PipelineBindPoint, pipeline: vk::Pipeline) {
-        (self.fns.cmd_bind_pipeline.unwrap_unchecked())(command_buffer, pipeline_bind_point, pipeline);
+        (load_fn(self.fns.cmd_bind_pipeline, "vkCmdBindPipeline"))(command_buffer, pipeline_bind_point, pipeline);
     }
 
     #[inline]
@@ -1096,10 +2339,19 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkAllocateMemory`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAllocateMemory.html)"]
     pub unsafe fn allocate_memory(&self, p_allocate_info: *const vk::MemoryAllocateInfo) -> Result<vk::DeviceMemory, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkAllocateMemory", "pAllocateInfo", p_allocate_info);
+            vk::check_structs("vkAllocateMemory", p_allocate_info, 1);
+        }
         let mut p_memory = std::mem::MaybeUninit::uninit();
-        match (self.fns.allocate_memory.unwrap_unchecked())(self.handle, p_allocate_info, std::ptr::null(), p_memory.as_mut_ptr()) {
+        match (try_load_fn(self.fns.allocate_memory, "vkAllocateMemory")?)(self.handle, p_allocate_info, std::ptr::null(), p_memory.as_mut_ptr()) {
             vk::Result::Success => Ok(p_memory.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkAllocateMemory",
+                create_info: Some("VkMemoryAllocateInfo"),
+            }),
         }
     }
 
@@ -1112,7 +2364,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkFreeMemory`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkFreeMemory.html)"]
     pub unsafe fn free_memory(&self, memory: vk::DeviceMemory) {
-        (self.fns.free_memory.unwrap_unchecked())(self.handle, memory, std::ptr::null());
+        (load_fn(self.fns.free_memory, "vkFreeMemory"))(self.handle, memory, std::ptr::null());
     }
 
     #[inline]
@@ -1124,10 +2376,19 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkMapMemory2KHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkMapMemory2KHR.html)"]
     pub unsafe fn map_memory2_khr(&self, p_memory_map_info: *const vk::MemoryMapInfoKHR) -> Result<*mut c_void, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkMapMemory2KHR", "pMemoryMapInfo", p_memory_map_info);
+            vk::check_structs("vkMapMemory2KHR", p_memory_map_info, 1);
+        }
         let mut pp_data = std::mem::MaybeUninit::uninit();
-        match (self.fns.map_memory2_khr.unwrap_unchecked())(self.handle, p_memory_map_info, pp_data.as_mut_ptr()) {
+        match (try_load_fn(self.fns.map_memory2_khr, "vkMapMemory2KHR")?)(self.handle, p_memory_map_info, pp_data.as_mut_ptr()) {
             vk::Result::Success => Ok(pp_data.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkMapMemory2KHR",
+                create_info: None,
+            }),
         }
     }
 
@@ -1140,9 +2401,18 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkUnmapMemory2KHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkUnmapMemory2KHR.html)"]
     pub unsafe fn unmap_memory2_khr(&self, p_memory_unmap_info: *const vk::MemoryUnmapInfoKHR) -> Result<(), Error> {
-        match (self.fns.unmap_memory2_khr.unwrap_unchecked())(self.handle, p_memory_unmap_info) {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkUnmapMemory2KHR", "pMemoryUnmapInfo", p_memory_unmap_info);
+            vk::check_structs("vkUnmapMemory2KHR", p_memory_unmap_info, 1);
+        }
+        match (try_load_fn(self.fns.unmap_memory2_khr, "vkUnmapMemory2KHR")?)(self.handle, p_memory_unmap_info) {
             vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkUnmapMemory2KHR",
+                create_info: None,
+            }),
         }
     }
 
@@ -1155,10 +2425,19 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCreateBuffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateBuffer.html)"]
     pub unsafe fn create_buffer(&self, p_create_info: *const vk::BufferCreateInfo) -> Result<vk::Buffer, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreateBuffer", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreateBuffer", p_create_info, 1);
+        }
         let mut p_buffer = std::mem::MaybeUninit::uninit();
-        match (self.fns.create_buffer.unwrap_unchecked())(self.handle, p_create_info, std::ptr::null(), p_buffer.as_mut_ptr()) {
+        match (try_load_fn(self.fns.create_buffer, "vkCreateBuffer")?)(self.handle, p_create_info, std::ptr::null(), p_buffer.as_mut_ptr()) {
             vk::Result::Success => Ok(p_buffer.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateBuffer",
+                create_info: Some("VkBufferCreateInfo"),
+            }),
         }
     }
 
@@ -1171,7 +2450,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkDestroyBuffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyBuffer.html)"]
     pub unsafe fn destroy_buffer(&self, buffer: vk::Buffer) {
-        (self.fns.destroy_buffer.unwrap_unchecked())(self.handle, buffer, std::ptr::null());
+        (load_fn(self.fns.destroy_buffer, "vkDestroyBuffer"))(self.handle, buffer, std::ptr::null());
     }
 
     #[inline]
@@ -1183,10 +2462,19 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCreateImage`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateImage.html)"]
     pub unsafe fn create_image(&self, p_create_info: *const vk::ImageCreateInfo) -> Result<vk::Image, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreateImage", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreateImage", p_create_info, 1);
+        }
         let mut p_image = std::mem::MaybeUninit::uninit();
-        match (self.fns.create_image.unwrap_unchecked())(self.handle, p_create_info, std::ptr::null(), p_image.as_mut_ptr()) {
+        match (try_load_fn(self.fns.create_image, "vkCreateImage")?)(self.handle, p_create_info, std::ptr::null(), p_image.as_mut_ptr()) {
             vk::Result::Success => Ok(p_image.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateImage",
+                create_info: Some("VkImageCreateInfo"),
+            }),
         }
     }
 
@@ -1199,7 +2487,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkDestroyImage`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyImage.html)"]
     pub unsafe fn destroy_image(&self, image: vk::Image) {
-        (self.fns.destroy_image.unwrap_unchecked())(self.handle, image, std::ptr::null());
+        (load_fn(self.fns.destroy_image, "vkDestroyImage"))(self.handle, image, std::ptr::null());
     }
 
     #[inline]
@@ -1211,10 +2499,19 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCreateImageView`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateImageView.html)"]
     pub unsafe fn create_image_view(&self, p_create_info: *const vk::ImageViewCreateInfo) -> Result<vk::ImageView, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreateImageView", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreateImageView", p_create_info, 1);
+        }
         let mut p_view = std::mem::MaybeUninit::uninit();
-        match (self.fns.create_image_view.unwrap_unchecked())(self.handle, p_create_info, std::ptr::null(), p_view.as_mut_ptr()) {
+        match (try_load_fn(self.fns.create_image_view, "vkCreateImageView")?)(self.handle, p_create_info, std::ptr::null(), p_view.as_mut_ptr()) {
             vk::Result::Success => Ok(p_view.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateImageView",
+                create_info: Some("VkImageViewCreateInfo"),
+            }),
         }
     }
 
@@ -1227,7 +2524,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkDestroyImageView`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyImageView.html)"]
     pub unsafe fn destroy_image_view(&self, image_view: vk::ImageView) {
-        (self.fns.destroy_image_view.unwrap_unchecked())(self.handle, image_view, std::ptr::null());
+        (load_fn(self.fns.destroy_image_view, "vkDestroyImageView"))(self.handle, image_view, std::ptr::null());
     }
 
     #[inline]
@@ -1239,10 +2536,19 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCreateAccelerationStructureKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateAccelerationStructureKHR.html)"]
     pub unsafe fn create_acceleration_structure_khr(&self, p_create_info: *const vk::AccelerationStructureCreateInfoKHR) -> Result<vk::AccelerationStructureKHR, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreateAccelerationStructureKHR", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreateAccelerationStructureKHR", p_create_info, 1);
+        }
         let mut p_acceleration_structure = std::mem::MaybeUninit::uninit();
-        match (self.fns.create_acceleration_structure_khr.unwrap_unchecked())(self.handle, p_create_info, std::ptr::null(), p_acceleration_structure.as_mut_ptr()) {
+        match (try_load_fn(self.fns.create_acceleration_structure_khr, "vkCreateAccelerationStructureKHR")?)(self.handle, p_create_info, std::ptr::null(), p_acceleration_structure.as_mut_ptr()) {
             vk::Result::Success => Ok(p_acceleration_structure.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateAccelerationStructureKHR",
+                create_info: Some("VkAccelerationStructureCreateInfoKHR"),
+            }),
         }
     }
 
@@ -1261,7 +2567,12 @@ impl Device {
         p_max_primitive_counts: *const u32,
         p_size_info: *mut vk::AccelerationStructureBuildSizesInfoKHR,
     ) {
-        (self.fns.get_acceleration_structure_build_sizes_khr.unwrap_unchecked())(self.handle, build_type, p_build_info, p_max_primitive_counts, p_size_info);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkGetAccelerationStructureBuildSizesKHR", "pBuildInfo", p_build_info);
+            vk::check_structs("vkGetAccelerationStructureBuildSizesKHR", p_build_info, 1);
+        }
+        (load_fn(self.fns.get_acceleration_structure_build_sizes_khr, "vkGetAccelerationStructureBuildSizesKHR"))(self.handle, build_type, p_build_info, p_max_primitive_counts, p_size_info);
     }
 
     #[inline]
@@ -1273,7 +2584,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkDestroyAccelerationStructureKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyAccelerationStructureKHR.html)"]
     pub unsafe fn destroy_acceleration_structure_khr(&self, acceleration_structure: vk::AccelerationStructureKHR) {
-        (self.fns.destroy_acceleration_structure_khr.unwrap_unchecked())(self.handle, acceleration_structure, std::ptr::null());
+        (load_fn(self.fns.destroy_acceleration_structure_khr, "vkDestroyAccelerationStructureKHR"))(self.handle, acceleration_structure, std::ptr::null());
     }
 
     #[must_use]
@@ -1286,7 +2597,12 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkGetAccelerationStructureDeviceAddressKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetAccelerationStructureDeviceAddressKHR.html)"]
     pub unsafe fn get_acceleration_structure_device_address_khr(&self, p_info: *const vk::AccelerationStructureDeviceAddressInfoKHR) -> vk::DeviceAddress {
-        (self.fns.get_acceleration_structure_device_address_khr.unwrap_unchecked())(self.handle, p_info)
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkGetAccelerationStructureDeviceAddressKHR", "pInfo", p_info);
+            vk::check_structs("vkGetAccelerationStructureDeviceAddressKHR", p_info, 1);
+        }
+        (load_fn(self.fns.get_acceleration_structure_device_address_khr, "vkGetAccelerationStructureDeviceAddressKHR"))(self.handle, p_info)
     }
 
     #[inline]
@@ -1298,7 +2614,12 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkGetDeviceBufferMemoryRequirements`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceBufferMemoryRequirements.html)"]
     pub unsafe fn get_device_buffer_memory_requirements(&self, p_info: *const vk::DeviceBufferMemoryRequirements, p_memory_requirements: *mut vk::MemoryRequirements2) {
-        (self.fns.get_device_buffer_memory_requirements.unwrap_unchecked())(self.handle, p_info, p_memory_requirements);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkGetDeviceBufferMemoryRequirements", "pInfo", p_info);
+            vk::check_structs("vkGetDeviceBufferMemoryRequirements", p_info, 1);
+        }
+        (load_fn(self.fns.get_device_buffer_memory_requirements, "vkGetDeviceBufferMemoryRequirements"))(self.handle, p_info, p_memory_requirements);
     }
 
     #[inline]
@@ -1310,7 +2631,12 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkGetDeviceImageMemoryRequirements`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDeviceImageMemoryRequirements.html)"]
     pub unsafe fn get_device_image_memory_requirements(&self, p_info: *const vk::DeviceImageMemoryRequirements, p_memory_requirements: *mut vk::MemoryRequirements2) {
-        (self.fns.get_device_image_memory_requirements.unwrap_unchecked())(self.handle, p_info, p_memory_requirements);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkGetDeviceImageMemoryRequirements", "pInfo", p_info);
+            vk::check_structs("vkGetDeviceImageMemoryRequirements", p_info, 1);
+        }
+        (load_fn(self.fns.get_device_image_memory_requirements, "vkGetDeviceImageMemoryRequirements"))(self.handle, p_info, p_memory_requirements);
     }
 
     #[inline]
@@ -1322,12 +2648,34 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkBindBufferMemory2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindBufferMemory2.html)"]
     pub unsafe fn bind_buffer_memory2(&self, bind_info_count: u32, p_bind_infos: *const vk::BindBufferMemoryInfo) -> Result<(), Error> {
-        match (self.fns.bind_buffer_memory2.unwrap_unchecked())(self.handle, bind_info_count, p_bind_infos) {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_count("vkBindBufferMemory2", "bindInfoCount", bind_info_count);
+            vk::check_array("vkBindBufferMemory2", "pBindInfos", p_bind_infos, bind_info_count as usize);
+            vk::check_structs("vkBindBufferMemory2", p_bind_infos, bind_info_count as usize);
+        }
+        match (try_load_fn(self.fns.bind_buffer_memory2, "vkBindBufferMemory2")?)(self.handle, bind_info_count, p_bind_infos) {
             vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkBindBufferMemory2",
+                create_info: None,
+            }),
         }
     }
 
+    #[inline]
+    #[doc = "**Chapter**: Resource Creation"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Bind device memory to buffer objects"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_VERSION_1_1`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_VERSION_1_1.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkBindBufferMemory2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindBufferMemory2.html)"]
+    pub unsafe fn bind_buffer_memory2_slice(&self, bind_infos: &[vk::BindBufferMemoryInfo]) -> Result<(), Error> {
+        self.bind_buffer_memory2(u32::try_from(bind_infos.len()).unwrap(), bind_infos.as_ptr())
+    }
+
     #[inline]
     #[doc = "**Chapter**: Resource Creation"]
     #[doc = "<br>"]
@@ -1337,12 +2685,34 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkBindImageMemory2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindImageMemory2.html)"]
     pub unsafe fn bind_image_memory2(&self, bind_info_count: u32, p_bind_infos: *const vk::BindImageMemoryInfo) -> Result<(), Error> {
-        match (self.fns.bind_image_memory2.unwrap_unchecked())(self.handle, bind_info_count, p_bind_infos) {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_count("vkBindImageMemory2", "bindInfoCount", bind_info_count);
+            vk::check_array("vkBindImageMemory2", "pBindInfos", p_bind_infos, bind_info_count as usize);
+            vk::check_structs("vkBindImageMemory2", p_bind_infos, bind_info_count as usize);
+        }
+        match (try_load_fn(self.fns.bind_image_memory2, "vkBindImageMemory2")?)(self.handle, bind_info_count, p_bind_infos) {
             vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkBindImageMemory2",
+                create_info: None,
+            }),
         }
     }
 
+    #[inline]
+    #[doc = "**Chapter**: Resource Creation"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Bind device memory to image objects"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_VERSION_1_1`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_VERSION_1_1.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkBindImageMemory2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkBindImageMemory2.html)"]
+    pub unsafe fn bind_image_memory2_slice(&self, bind_infos: &[vk::BindImageMemoryInfo]) -> Result<(), Error> {
+        self.bind_image_memory2(u32::try_from(bind_infos.len()).unwrap(), bind_infos.as_ptr())
+    }
+
     #[inline]
     #[doc = "**Chapter**: Samplers"]
     #[doc = "<br>"]
@@ -1352,10 +2722,19 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCreateSampler`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateSampler.html)"]
     pub unsafe fn create_sampler(&self, p_create_info: *const vk::SamplerCreateInfo) -> Result<vk::Sampler, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreateSampler", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreateSampler", p_create_info, 1);
+        }
         let mut p_sampler = std::mem::MaybeUninit::uninit();
-        match (self.fns.create_sampler.unwrap_unchecked())(self.handle, p_create_info, std::ptr::null(), p_sampler.as_mut_ptr()) {
+        match (try_load_fn(self.fns.create_sampler, "vkCreateSampler")?)(self.handle, p_create_info, std::ptr::null(), p_sampler.as_mut_ptr()) {
             vk::Result::Success => Ok(p_sampler.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateSampler",
+                create_info: Some("VkSamplerCreateInfo"),
+            }),
         }
     }
 
@@ -1368,7 +2747,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkDestroySampler`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroySampler.html)"]
     pub unsafe fn destroy_sampler(&self, sampler: vk::Sampler) {
-        (self.fns.destroy_sampler.unwrap_unchecked())(self.handle, sampler, std::ptr::null());
+        (load_fn(self.fns.destroy_sampler, "vkDestroySampler"))(self.handle, sampler, std::ptr::null());
     }
 
     #[inline]
@@ -1380,10 +2759,19 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCreateDescriptorSetLayout`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateDescriptorSetLayout.html)"]
     pub unsafe fn create_descriptor_set_layout(&self, p_create_info: *const vk::DescriptorSetLayoutCreateInfo) -> Result<vk::DescriptorSetLayout, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreateDescriptorSetLayout", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreateDescriptorSetLayout", p_create_info, 1);
+        }
         let mut p_set_layout = std::mem::MaybeUninit::uninit();
-        match (self.fns.create_descriptor_set_layout.unwrap_unchecked())(self.handle, p_create_info, std::ptr::null(), p_set_layout.as_mut_ptr()) {
+        match (try_load_fn(self.fns.create_descriptor_set_layout, "vkCreateDescriptorSetLayout")?)(self.handle, p_create_info, std::ptr::null(), p_set_layout.as_mut_ptr()) {
             vk::Result::Success => Ok(p_set_layout.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateDescriptorSetLayout",
+                create_info: Some("VkDescriptorSetLayoutCreateInfo"),
+            }),
         }
     }
 
@@ -1396,7 +2784,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkDestroyDescriptorSetLayout`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyDescriptorSetLayout.html)"]
     pub unsafe fn destroy_descriptor_set_layout(&self, descriptor_set_layout: vk::DescriptorSetLayout) {
-        (self.fns.destroy_descriptor_set_layout.unwrap_unchecked())(self.handle, descriptor_set_layout, std::ptr::null());
+        (load_fn(self.fns.destroy_descriptor_set_layout, "vkDestroyDescriptorSetLayout"))(self.handle, descriptor_set_layout, std::ptr::null());
     }
 
     #[inline]
@@ -1408,10 +2796,19 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCreatePipelineLayout`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreatePipelineLayout.html)"]
     pub unsafe fn create_pipeline_layout(&self, p_create_info: *const vk::PipelineLayoutCreateInfo) -> Result<vk::PipelineLayout, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreatePipelineLayout", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreatePipelineLayout", p_create_info, 1);
+        }
         let mut p_pipeline_layout = std::mem::MaybeUninit::uninit();
-        match (self.fns.create_pipeline_layout.unwrap_unchecked())(self.handle, p_create_info, std::ptr::null(), p_pipeline_layout.as_mut_ptr()) {
+        match (try_load_fn(self.fns.create_pipeline_layout, "vkCreatePipelineLayout")?)(self.handle, p_create_info, std::ptr::null(), p_pipeline_layout.as_mut_ptr()) {
             vk::Result::Success => Ok(p_pipeline_layout.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreatePipelineLayout",
+                create_info: Some("VkPipelineLayoutCreateInfo"),
+            }),
         }
     }
 
@@ -1424,7 +2821,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkDestroyPipelineLayout`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyPipelineLayout.html)"]
     pub unsafe fn destroy_pipeline_layout(&self, pipeline_layout: vk::PipelineLayout) {
-        (self.fns.destroy_pipeline_layout.unwrap_unchecked())(self.handle, pipeline_layout, std::ptr::null());
+        (load_fn(self.fns.destroy_pipeline_layout, "vkDestroyPipelineLayout"))(self.handle, pipeline_layout, std::ptr::null());
     }
 
     #[inline]
@@ -1436,7 +2833,12 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdPushConstants`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdPushConstants.html)"]
     pub unsafe fn cmd_push_constants(&self, command_buffer: vk::CommandBuffer, layout: vk::PipelineLayout, stage_flags: vk::ShaderStageFlags, offset: u32, size: u32, p_values: *const c_void) {
-        (self.fns.cmd_push_constants.unwrap_unchecked())(command_buffer, layout, stage_flags, offset, size, p_values);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_count("vkCmdPushConstants", "size", size);
+            vk::check_array("vkCmdPushConstants", "pValues", p_values, size as usize);
+        }
+        (load_fn(self.fns.cmd_push_constants, "vkCmdPushConstants"))(command_buffer, layout, stage_flags, offset, size, p_values);
     }
 
     #[must_use]
@@ -1449,7 +2851,12 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkGetBufferDeviceAddress`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetBufferDeviceAddress.html)"]
     pub unsafe fn get_buffer_device_address(&self, p_info: *const vk::BufferDeviceAddressInfo) -> vk::DeviceAddress {
-        (self.fns.get_buffer_device_address.unwrap_unchecked())(self.handle, p_info)
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkGetBufferDeviceAddress", "pInfo", p_info);
+            vk::check_structs("vkGetBufferDeviceAddress", p_info, 1);
+        }
+        (load_fn(self.fns.get_buffer_device_address, "vkGetBufferDeviceAddress"))(self.handle, p_info)
     }
 
     #[must_use]
@@ -1463,7 +2870,7 @@ impl Device {
     #[doc = "**Reference**: [`vkGetDescriptorSetLayoutSizeEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutSizeEXT.html)"]
     pub unsafe fn get_descriptor_set_layout_size_ext(&self, layout: vk::DescriptorSetLayout) -> vk::DeviceSize {
         let mut p_layout_size_in_bytes = std::mem::MaybeUninit::uninit();
-        (self.fns.get_descriptor_set_layout_size_ext.unwrap_unchecked())(self.handle, layout, p_layout_size_in_bytes.as_mut_ptr());
+        (load_fn(self.fns.get_descriptor_set_layout_size_ext, "vkGetDescriptorSetLayoutSizeEXT"))(self.handle, layout, p_layout_size_in_bytes.as_mut_ptr());
         p_layout_size_in_bytes.assume_init()
     }
 
@@ -1478,7 +2885,7 @@ impl Device {
     #[doc = "**Reference**: [`vkGetDescriptorSetLayoutBindingOffsetEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorSetLayoutBindingOffsetEXT.html)"]
     pub unsafe fn get_descriptor_set_layout_binding_offset_ext(&self, layout: vk::DescriptorSetLayout, binding: u32) -> vk::DeviceSize {
         let mut p_offset = std::mem::MaybeUninit::uninit();
-        (self.fns.get_descriptor_set_layout_binding_offset_ext.unwrap_unchecked())(self.handle, layout, binding, p_offset.as_mut_ptr());
+        (load_fn(self.fns.get_descriptor_set_layout_binding_offset_ext, "vkGetDescriptorSetLayoutBindingOffsetEXT"))(self.handle, layout, binding, p_offset.as_mut_ptr());
         p_offset.assume_init()
     }
 
@@ -1491,7 +2898,12 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkGetDescriptorEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetDescriptorEXT.html)"]
     pub unsafe fn get_descriptor_ext(&self, p_descriptor_info: *const vk::DescriptorGetInfoEXT, data_size: usize, p_descriptor: *mut c_void) {
-        (self.fns.get_descriptor_ext.unwrap_unchecked())(self.handle, p_descriptor_info, data_size, p_descriptor);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkGetDescriptorEXT", "pDescriptorInfo", p_descriptor_info);
+            vk::check_structs("vkGetDescriptorEXT", p_descriptor_info, 1);
+        }
+        (load_fn(self.fns.get_descriptor_ext, "vkGetDescriptorEXT"))(self.handle, p_descriptor_info, data_size, p_descriptor);
     }
 
     #[inline]
@@ -1503,7 +2915,25 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdBindDescriptorBuffersEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindDescriptorBuffersEXT.html)"]
     pub unsafe fn cmd_bind_descriptor_buffers_ext(&self, command_buffer: vk::CommandBuffer, buffer_count: u32, p_binding_infos: *const vk::DescriptorBufferBindingInfoEXT) {
-        (self.fns.cmd_bind_descriptor_buffers_ext.unwrap_unchecked())(command_buffer, buffer_count, p_binding_infos);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_count("vkCmdBindDescriptorBuffersEXT", "bufferCount", buffer_count);
+            vk::check_array("vkCmdBindDescriptorBuffersEXT", "pBindingInfos", p_binding_infos, buffer_count as usize);
+            vk::check_structs("vkCmdBindDescriptorBuffersEXT", p_binding_infos, buffer_count as usize);
+        }
+        (load_fn(self.fns.cmd_bind_descriptor_buffers_ext, "vkCmdBindDescriptorBuffersEXT"))(command_buffer, buffer_count, p_binding_infos);
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Resource Descriptors"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Binding descriptor buffers to a command buffer"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_EXT_descriptor_buffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_descriptor_buffer.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkCmdBindDescriptorBuffersEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBindDescriptorBuffersEXT.html)"]
+    pub unsafe fn cmd_bind_descriptor_buffers_ext_slice(&self, command_buffer: vk::CommandBuffer, binding_infos: &[vk::DescriptorBufferBindingInfoEXT]) {
+        self.cmd_bind_descriptor_buffers_ext(command_buffer, u32::try_from(binding_infos.len()).unwrap(), binding_infos.as_ptr());
     }
 
     #[inline]
@@ -1524,7 +2954,42 @@ impl Device {
         p_buffer_indices: *const u32,
         p_offsets: *const vk::DeviceSize,
     ) {
-        (self.fns.cmd_set_descriptor_buffer_offsets_ext.unwrap_unchecked())(command_buffer, pipeline_bind_point, layout, first_set, set_count, p_buffer_indices, p_offsets);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_count("vkCmdSetDescriptorBufferOffsetsEXT", "setCount", set_count);
+            vk::check_array("vkCmdSetDescriptorBufferOffsetsEXT", "pBufferIndices", p_buffer_indices, set_count as usize);
+            vk::check_array("vkCmdSetDescriptorBufferOffsetsEXT", "pOffsets", p_offsets, set_count as usize);
+        }
+        (load_fn(self.fns.cmd_set_descriptor_buffer_offsets_ext, "vkCmdSetDescriptorBufferOffsetsEXT"))(command_buffer, pipeline_bind_point, layout, first_set, set_count, p_buffer_indices, p_offsets);
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Resource Descriptors"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Setting descriptor buffer offsets in a command buffer"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_EXT_descriptor_buffer`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_descriptor_buffer.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkCmdSetDescriptorBufferOffsetsEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDescriptorBufferOffsetsEXT.html)"]
+    pub unsafe fn cmd_set_descriptor_buffer_offsets_ext_slice(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        pipeline_bind_point: vk::PipelineBindPoint,
+        layout: vk::PipelineLayout,
+        first_set: u32,
+        buffer_indices: &[u32],
+        offsets: &[vk::DeviceSize],
+    ) {
+        assert_eq!(buffer_indices.len(), offsets.len());
+        self.cmd_set_descriptor_buffer_offsets_ext(
+            command_buffer,
+            pipeline_bind_point,
+            layout,
+            first_set,
+            u32::try_from(buffer_indices.len()).unwrap(),
+            buffer_indices.as_ptr(),
+            offsets.as_ptr(),
+        );
     }
 
     #[inline]
@@ -1536,10 +3001,19 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCreateQueryPool`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateQueryPool.html)"]
     pub unsafe fn create_query_pool(&self, p_create_info: *const vk::QueryPoolCreateInfo) -> Result<vk::QueryPool, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreateQueryPool", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreateQueryPool", p_create_info, 1);
+        }
         let mut p_query_pool = std::mem::MaybeUninit::uninit();
-        match (self.fns.create_query_pool.unwrap_unchecked())(self.handle, p_create_info, std::ptr::null(), p_query_pool.as_mut_ptr()) {
+        match (try_load_fn(self.fns.create_query_pool, "vkCreateQueryPool")?)(self.handle, p_create_info, std::ptr::null(), p_query_pool.as_mut_ptr()) {
             vk::Result::Success => Ok(p_query_pool.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateQueryPool",
+                create_info: Some("VkQueryPoolCreateInfo"),
+            }),
         }
     }
 
@@ -1552,7 +3026,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkDestroyQueryPool`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroyQueryPool.html)"]
     pub unsafe fn destroy_query_pool(&self, query_pool: vk::QueryPool) {
-        (self.fns.destroy_query_pool.unwrap_unchecked())(self.handle, query_pool, std::ptr::null());
+        (load_fn(self.fns.destroy_query_pool, "vkDestroyQueryPool"))(self.handle, query_pool, std::ptr::null());
     }
 
     #[inline]
@@ -1564,7 +3038,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkResetQueryPool`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkResetQueryPool.html)"]
     pub unsafe fn reset_query_pool(&self, query_pool: vk::QueryPool, first_query: u32, query_count: u32) {
-        (self.fns.reset_query_pool.unwrap_unchecked())(self.handle, query_pool, first_query, query_count);
+        (load_fn(self.fns.reset_query_pool, "vkResetQueryPool"))(self.handle, query_pool, first_query, query_count);
     }
 
     #[inline]
@@ -1576,7 +3050,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdBeginQuery`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBeginQuery.html)"]
     pub unsafe fn cmd_begin_query(&self, command_buffer: vk::CommandBuffer, query_pool: vk::QueryPool, query: u32, flags: vk::QueryControlFlags) {
-        (self.fns.cmd_begin_query.unwrap_unchecked())(command_buffer, query_pool, query, flags);
+        (load_fn(self.fns.cmd_begin_query, "vkCmdBeginQuery"))(command_buffer, query_pool, query, flags);
     }
 
     #[inline]
@@ -1588,7 +3062,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdEndQuery`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdEndQuery.html)"]
     pub unsafe fn cmd_end_query(&self, command_buffer: vk::CommandBuffer, query_pool: vk::QueryPool, query: u32) {
-        (self.fns.cmd_end_query.unwrap_unchecked())(command_buffer, query_pool, query);
+        (load_fn(self.fns.cmd_end_query, "vkCmdEndQuery"))(command_buffer, query_pool, query);
     }
 
     #[inline]
@@ -1608,10 +3082,14 @@ impl Device {
         p_data: *mut c_void,
         stride: vk::DeviceSize,
         flags: vk::QueryResultFlags,
-    ) -> Result<(), Error> {
-        match (self.fns.get_query_pool_results.unwrap_unchecked())(self.handle, query_pool, first_query, query_count, data_size, p_data, stride, flags) {
-            vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+    ) -> Result<vk::Result, Error> {
+        match (try_load_fn(self.fns.get_query_pool_results, "vkGetQueryPoolResults")?)(self.handle, query_pool, first_query, query_count, data_size, p_data, stride, flags) {
+            result @ (vk::Result::Success | vk::Result::NotReady) => Ok(result),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkGetQueryPoolResults",
+                create_info: None,
+            }),
         }
     }
 
@@ -1624,7 +3102,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdWriteTimestamp2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdWriteTimestamp2.html)"]
     pub unsafe fn cmd_write_timestamp2(&self, command_buffer: vk::CommandBuffer, stage: vk::PipelineStageFlags2, query_pool: vk::QueryPool, query: u32) {
-        (self.fns.cmd_write_timestamp2.unwrap_unchecked())(command_buffer, stage, query_pool, query);
+        (load_fn(self.fns.cmd_write_timestamp2, "vkCmdWriteTimestamp2"))(command_buffer, stage, query_pool, query);
     }
 
     #[inline]
@@ -1636,7 +3114,12 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdCopyBuffer2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyBuffer2.html)"]
     pub unsafe fn cmd_copy_buffer2(&self, command_buffer: vk::CommandBuffer, p_copy_buffer_info: *const vk::CopyBufferInfo2) {
-        (self.fns.cmd_copy_buffer2.unwrap_unchecked())(command_buffer, p_copy_buffer_info);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCmdCopyBuffer2", "pCopyBufferInfo", p_copy_buffer_info);
+            vk::check_structs("vkCmdCopyBuffer2", p_copy_buffer_info, 1);
+        }
+        (load_fn(self.fns.cmd_copy_buffer2, "vkCmdCopyBuffer2"))(command_buffer, p_copy_buffer_info);
     }
 
     #[inline]
@@ -1648,7 +3131,12 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdCopyImage2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyImage2.html)"]
     pub unsafe fn cmd_copy_image2(&self, command_buffer: vk::CommandBuffer, p_copy_image_info: *const vk::CopyImageInfo2) {
-        (self.fns.cmd_copy_image2.unwrap_unchecked())(command_buffer, p_copy_image_info);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCmdCopyImage2", "pCopyImageInfo", p_copy_image_info);
+            vk::check_structs("vkCmdCopyImage2", p_copy_image_info, 1);
+        }
+        (load_fn(self.fns.cmd_copy_image2, "vkCmdCopyImage2"))(command_buffer, p_copy_image_info);
     }
 
     #[inline]
@@ -1660,7 +3148,12 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdCopyBufferToImage2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyBufferToImage2.html)"]
     pub unsafe fn cmd_copy_buffer_to_image2(&self, command_buffer: vk::CommandBuffer, p_copy_buffer_to_image_info: *const vk::CopyBufferToImageInfo2) {
-        (self.fns.cmd_copy_buffer_to_image2.unwrap_unchecked())(command_buffer, p_copy_buffer_to_image_info);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCmdCopyBufferToImage2", "pCopyBufferToImageInfo", p_copy_buffer_to_image_info);
+            vk::check_structs("vkCmdCopyBufferToImage2", p_copy_buffer_to_image_info, 1);
+        }
+        (load_fn(self.fns.cmd_copy_buffer_to_image2, "vkCmdCopyBufferToImage2"))(command_buffer, p_copy_buffer_to_image_info);
     }
 
     #[inline]
@@ -1672,7 +3165,12 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdCopyImageToBuffer2`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdCopyImageToBuffer2.html)"]
     pub unsafe fn cmd_copy_image_to_buffer2(&self, command_buffer: vk::CommandBuffer, p_copy_image_to_buffer_info: *const vk::CopyImageToBufferInfo2) {
-        (self.fns.cmd_copy_image_to_buffer2.unwrap_unchecked())(command_buffer, p_copy_image_to_buffer_info);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCmdCopyImageToBuffer2", "pCopyImageToBufferInfo", p_copy_image_to_buffer_info);
+            vk::check_structs("vkCmdCopyImageToBuffer2", p_copy_image_to_buffer_info, 1);
+        }
+        (load_fn(self.fns.cmd_copy_image_to_buffer2, "vkCmdCopyImageToBuffer2"))(command_buffer, p_copy_image_to_buffer_info);
     }
 
     #[inline]
@@ -1684,7 +3182,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdDrawMeshTasksEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksEXT.html)"]
     pub unsafe fn cmd_draw_mesh_tasks_ext(&self, command_buffer: vk::CommandBuffer, group_count_x: u32, group_count_y: u32, group_count_z: u32) {
-        (self.fns.cmd_draw_mesh_tasks_ext.unwrap_unchecked())(command_buffer, group_count_x, group_count_y, group_count_z);
+        (load_fn(self.fns.cmd_draw_mesh_tasks_ext, "vkCmdDrawMeshTasksEXT"))(command_buffer, group_count_x, group_count_y, group_count_z);
     }
 
     #[inline]
@@ -1696,7 +3194,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdDrawMeshTasksIndirectEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDrawMeshTasksIndirectEXT.html)"]
     pub unsafe fn cmd_draw_mesh_tasks_indirect_ext(&self, command_buffer: vk::CommandBuffer, buffer: vk::Buffer, offset: vk::DeviceSize, draw_count: u32, stride: u32) {
-        (self.fns.cmd_draw_mesh_tasks_indirect_ext.unwrap_unchecked())(command_buffer, buffer, offset, draw_count, stride);
+        (load_fn(self.fns.cmd_draw_mesh_tasks_indirect_ext, "vkCmdDrawMeshTasksIndirectEXT"))(command_buffer, buffer, offset, draw_count, stride);
     }
 
     #[inline]
@@ -1717,7 +3215,7 @@ impl Device {
         max_draw_count: u32,
         stride: u32,
     ) {
-        (self.fns.cmd_draw_mesh_tasks_indirect_count_ext.unwrap_unchecked())(command_buffer, buffer, offset, count_buffer, count_buffer_offset, max_draw_count, stride);
+        (load_fn(self.fns.cmd_draw_mesh_tasks_indirect_count_ext, "vkCmdDrawMeshTasksIndirectCountEXT"))(command_buffer, buffer, offset, count_buffer, count_buffer_offset, max_draw_count, stride);
     }
 
     #[inline]
@@ -1729,7 +3227,24 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdSetViewportWithCount`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportWithCount.html)"]
     pub unsafe fn cmd_set_viewport_with_count(&self, command_buffer: vk::CommandBuffer, viewport_count: u32, p_viewports: *const vk::Viewport) {
-        (self.fns.cmd_set_viewport_with_count.unwrap_unchecked())(command_buffer, viewport_count, p_viewports);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_count("vkCmdSetViewportWithCount", "viewportCount", viewport_count);
+            vk::check_array("vkCmdSetViewportWithCount", "pViewports", p_viewports, viewport_count as usize);
+        }
+        (load_fn(self.fns.cmd_set_viewport_with_count, "vkCmdSetViewportWithCount"))(command_buffer, viewport_count, p_viewports);
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Fixed-Function Vertex Post-Processing"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Set the viewport count and viewports dynamically for a command buffer"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_VERSION_1_3`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_VERSION_1_3.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkCmdSetViewportWithCount`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetViewportWithCount.html)"]
+    pub unsafe fn cmd_set_viewport_with_count_slice(&self, command_buffer: vk::CommandBuffer, viewports: &[vk::Viewport]) {
+        self.cmd_set_viewport_with_count(command_buffer, u32::try_from(viewports.len()).unwrap(), viewports.as_ptr());
     }
 
     #[inline]
@@ -1741,7 +3256,24 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdSetScissorWithCount`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetScissorWithCount.html)"]
     pub unsafe fn cmd_set_scissor_with_count(&self, command_buffer: vk::CommandBuffer, scissor_count: u32, p_scissors: *const vk::Rect2D) {
-        (self.fns.cmd_set_scissor_with_count.unwrap_unchecked())(command_buffer, scissor_count, p_scissors);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_count("vkCmdSetScissorWithCount", "scissorCount", scissor_count);
+            vk::check_array("vkCmdSetScissorWithCount", "pScissors", p_scissors, scissor_count as usize);
+        }
+        (load_fn(self.fns.cmd_set_scissor_with_count, "vkCmdSetScissorWithCount"))(command_buffer, scissor_count, p_scissors);
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Fixed-Function Vertex Post-Processing"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Set the scissor count and scissor rectangular bounds dynamically for a command buffer"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_VERSION_1_3`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_VERSION_1_3.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkCmdSetScissorWithCount`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetScissorWithCount.html)"]
+    pub unsafe fn cmd_set_scissor_with_count_slice(&self, command_buffer: vk::CommandBuffer, scissors: &[vk::Rect2D]) {
+        self.cmd_set_scissor_with_count(command_buffer, u32::try_from(scissors.len()).unwrap(), scissors.as_ptr());
     }
 
     #[inline]
@@ -1753,7 +3285,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdSetRasterizationSamplesEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetRasterizationSamplesEXT.html)"]
     pub unsafe fn cmd_set_rasterization_samples_ext(&self, command_buffer: vk::CommandBuffer, rasterization_samples: vk::SampleCountFlagBits) {
-        (self.fns.cmd_set_rasterization_samples_ext.unwrap_unchecked())(command_buffer, rasterization_samples);
+        (load_fn(self.fns.cmd_set_rasterization_samples_ext, "vkCmdSetRasterizationSamplesEXT"))(command_buffer, rasterization_samples);
     }
 
     #[inline]
@@ -1765,7 +3297,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdSetFrontFace`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetFrontFace.html)"]
     pub unsafe fn cmd_set_front_face(&self, command_buffer: vk::CommandBuffer, front_face: vk::FrontFace) {
-        (self.fns.cmd_set_front_face.unwrap_unchecked())(command_buffer, front_face);
+        (load_fn(self.fns.cmd_set_front_face, "vkCmdSetFrontFace"))(command_buffer, front_face);
     }
 
     #[inline]
@@ -1777,7 +3309,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdSetCullMode`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetCullMode.html)"]
     pub unsafe fn cmd_set_cull_mode(&self, command_buffer: vk::CommandBuffer, cull_mode: vk::CullModeFlags) {
-        (self.fns.cmd_set_cull_mode.unwrap_unchecked())(command_buffer, cull_mode);
+        (load_fn(self.fns.cmd_set_cull_mode, "vkCmdSetCullMode"))(command_buffer, cull_mode);
     }
 
     #[inline]
@@ -1789,7 +3321,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdSetDepthTestEnable`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthTestEnable.html)"]
     pub unsafe fn cmd_set_depth_test_enable(&self, command_buffer: vk::CommandBuffer, depth_test_enable: vk::Bool32) {
-        (self.fns.cmd_set_depth_test_enable.unwrap_unchecked())(command_buffer, depth_test_enable);
+        (load_fn(self.fns.cmd_set_depth_test_enable, "vkCmdSetDepthTestEnable"))(command_buffer, depth_test_enable);
     }
 
     #[inline]
@@ -1801,7 +3333,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdSetDepthCompareOp`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthCompareOp.html)"]
     pub unsafe fn cmd_set_depth_compare_op(&self, command_buffer: vk::CommandBuffer, depth_compare_op: vk::CompareOp) {
-        (self.fns.cmd_set_depth_compare_op.unwrap_unchecked())(command_buffer, depth_compare_op);
+        (load_fn(self.fns.cmd_set_depth_compare_op, "vkCmdSetDepthCompareOp"))(command_buffer, depth_compare_op);
     }
 
     #[inline]
@@ -1813,7 +3345,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdSetDepthWriteEnable`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetDepthWriteEnable.html)"]
     pub unsafe fn cmd_set_depth_write_enable(&self, command_buffer: vk::CommandBuffer, depth_write_enable: vk::Bool32) {
-        (self.fns.cmd_set_depth_write_enable.unwrap_unchecked())(command_buffer, depth_write_enable);
+        (load_fn(self.fns.cmd_set_depth_write_enable, "vkCmdSetDepthWriteEnable"))(command_buffer, depth_write_enable);
     }
 
     #[inline]
@@ -1825,7 +3357,24 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdSetColorBlendEnableEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetColorBlendEnableEXT.html)"]
     pub unsafe fn cmd_set_color_blend_enable_ext(&self, command_buffer: vk::CommandBuffer, first_attachment: u32, attachment_count: u32, p_color_blend_enables: *const vk::Bool32) {
-        (self.fns.cmd_set_color_blend_enable_ext.unwrap_unchecked())(command_buffer, first_attachment, attachment_count, p_color_blend_enables);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_count("vkCmdSetColorBlendEnableEXT", "attachmentCount", attachment_count);
+            vk::check_array("vkCmdSetColorBlendEnableEXT", "pColorBlendEnables", p_color_blend_enables, attachment_count as usize);
+        }
+        (load_fn(self.fns.cmd_set_color_blend_enable_ext, "vkCmdSetColorBlendEnableEXT"))(command_buffer, first_attachment, attachment_count, p_color_blend_enables);
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: The Framebuffer"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Specify the pname:blendEnable for each attachment dynamically for a command buffer"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_EXT_extended_dynamic_state3`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_extended_dynamic_state3.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkCmdSetColorBlendEnableEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetColorBlendEnableEXT.html)"]
+    pub unsafe fn cmd_set_color_blend_enable_ext_slice(&self, command_buffer: vk::CommandBuffer, first_attachment: u32, color_blend_enables: &[vk::Bool32]) {
+        self.cmd_set_color_blend_enable_ext(command_buffer, first_attachment, u32::try_from(color_blend_enables.len()).unwrap(), color_blend_enables.as_ptr());
     }
 
     #[inline]
@@ -1837,7 +3386,24 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdSetColorBlendEquationEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetColorBlendEquationEXT.html)"]
     pub unsafe fn cmd_set_color_blend_equation_ext(&self, command_buffer: vk::CommandBuffer, first_attachment: u32, attachment_count: u32, p_color_blend_equations: *const vk::ColorBlendEquationEXT) {
-        (self.fns.cmd_set_color_blend_equation_ext.unwrap_unchecked())(command_buffer, first_attachment, attachment_count, p_color_blend_equations);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_count("vkCmdSetColorBlendEquationEXT", "attachmentCount", attachment_count);
+            vk::check_array("vkCmdSetColorBlendEquationEXT", "pColorBlendEquations", p_color_blend_equations, attachment_count as usize);
+        }
+        (load_fn(self.fns.cmd_set_color_blend_equation_ext, "vkCmdSetColorBlendEquationEXT"))(command_buffer, first_attachment, attachment_count, p_color_blend_equations);
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: The Framebuffer"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Specify the blend factors and operations dynamically for a command buffer"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_EXT_extended_dynamic_state3`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_extended_dynamic_state3.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkCmdSetColorBlendEquationEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetColorBlendEquationEXT.html)"]
+    pub unsafe fn cmd_set_color_blend_equation_ext_slice(&self, command_buffer: vk::CommandBuffer, first_attachment: u32, color_blend_equations: &[vk::ColorBlendEquationEXT]) {
+        self.cmd_set_color_blend_equation_ext(command_buffer, first_attachment, u32::try_from(color_blend_equations.len()).unwrap(), color_blend_equations.as_ptr());
     }
 
     #[inline]
@@ -1849,7 +3415,24 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdSetColorWriteMaskEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetColorWriteMaskEXT.html)"]
     pub unsafe fn cmd_set_color_write_mask_ext(&self, command_buffer: vk::CommandBuffer, first_attachment: u32, attachment_count: u32, p_color_write_masks: *const vk::ColorComponentFlags) {
-        (self.fns.cmd_set_color_write_mask_ext.unwrap_unchecked())(command_buffer, first_attachment, attachment_count, p_color_write_masks);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_count("vkCmdSetColorWriteMaskEXT", "attachmentCount", attachment_count);
+            vk::check_array("vkCmdSetColorWriteMaskEXT", "pColorWriteMasks", p_color_write_masks, attachment_count as usize);
+        }
+        (load_fn(self.fns.cmd_set_color_write_mask_ext, "vkCmdSetColorWriteMaskEXT"))(command_buffer, first_attachment, attachment_count, p_color_write_masks);
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: The Framebuffer"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Specify the color write masks for each attachment dynamically for a command buffer"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_EXT_extended_dynamic_state3`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_EXT_extended_dynamic_state3.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkCmdSetColorWriteMaskEXT`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdSetColorWriteMaskEXT.html)"]
+    pub unsafe fn cmd_set_color_write_mask_ext_slice(&self, command_buffer: vk::CommandBuffer, first_attachment: u32, color_write_masks: &[vk::ColorComponentFlags]) {
+        self.cmd_set_color_write_mask_ext(command_buffer, first_attachment, u32::try_from(color_write_masks.len()).unwrap(), color_write_masks.as_ptr());
     }
 
     #[inline]
@@ -1861,7 +3444,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdDispatch`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatch.html)"]
     pub unsafe fn cmd_dispatch(&self, command_buffer: vk::CommandBuffer, group_count_x: u32, group_count_y: u32, group_count_z: u32) {
-        (self.fns.cmd_dispatch.unwrap_unchecked())(command_buffer, group_count_x, group_count_y, group_count_z);
+        (load_fn(self.fns.cmd_dispatch, "vkCmdDispatch"))(command_buffer, group_count_x, group_count_y, group_count_z);
     }
 
     #[inline]
@@ -1873,7 +3456,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdDispatchIndirect`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdDispatchIndirect.html)"]
     pub unsafe fn cmd_dispatch_indirect(&self, command_buffer: vk::CommandBuffer, buffer: vk::Buffer, offset: vk::DeviceSize) {
-        (self.fns.cmd_dispatch_indirect.unwrap_unchecked())(command_buffer, buffer, offset);
+        (load_fn(self.fns.cmd_dispatch_indirect, "vkCmdDispatchIndirect"))(command_buffer, buffer, offset);
     }
 
     #[inline]
@@ -1885,10 +3468,19 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCreateSwapchainKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCreateSwapchainKHR.html)"]
     pub unsafe fn create_swapchain_khr(&self, p_create_info: *const vk::SwapchainCreateInfoKHR) -> Result<vk::SwapchainKHR, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCreateSwapchainKHR", "pCreateInfo", p_create_info);
+            vk::check_structs("vkCreateSwapchainKHR", p_create_info, 1);
+        }
         let mut p_swapchain = std::mem::MaybeUninit::uninit();
-        match (self.fns.create_swapchain_khr.unwrap_unchecked())(self.handle, p_create_info, std::ptr::null(), p_swapchain.as_mut_ptr()) {
+        match (try_load_fn(self.fns.create_swapchain_khr, "vkCreateSwapchainKHR")?)(self.handle, p_create_info, std::ptr::null(), p_swapchain.as_mut_ptr()) {
             vk::Result::Success => Ok(p_swapchain.assume_init()),
-            result => Err(Error::Vulkan(result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkCreateSwapchainKHR",
+                create_info: Some("VkSwapchainCreateInfoKHR"),
+            }),
         }
     }
 
@@ -1901,7 +3493,7 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkDestroySwapchainKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkDestroySwapchainKHR.html)"]
     pub unsafe fn destroy_swapchain_khr(&self, swapchain: vk::SwapchainKHR) {
-        (self.fns.destroy_swapchain_khr.unwrap_unchecked())(self.handle, swapchain, std::ptr::null());
+        (load_fn(self.fns.destroy_swapchain_khr, "vkDestroySwapchainKHR"))(self.handle, swapchain, std::ptr::null());
     }
 
     #[inline]
@@ -1912,10 +3504,53 @@ impl Device {
     #[doc = "**Provided by**: [`VK_KHR_swapchain`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_swapchain.html)"]
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkGetSwapchainImagesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSwapchainImagesKHR.html)"]
-    pub unsafe fn get_swapchain_images_khr(&self, swapchain: vk::SwapchainKHR, p_swapchain_image_count: *mut u32, p_swapchain_images: *mut vk::Image) -> Result<(), Error> {
-        match (self.fns.get_swapchain_images_khr.unwrap_unchecked())(self.handle, swapchain, p_swapchain_image_count, p_swapchain_images) {
-            vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+    pub unsafe fn get_swapchain_images_khr(&self, swapchain: vk::SwapchainKHR, p_swapchain_image_count: *mut u32, p_swapchain_images: *mut vk::Image) -> Result<vk::Result, Error> {
+        match (try_load_fn(self.fns.get_swapchain_images_khr, "vkGetSwapchainImagesKHR")?)(self.handle, swapchain, p_swapchain_image_count, p_swapchain_images) {
+            result @ (vk::Result::Success | vk::Result::Incomplete) => Ok(result),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkGetSwapchainImagesKHR",
+                create_info: None,
+            }),
+        }
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Window System Integration (WSI)"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Obtain the array of presentable images associated with a swapchain"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_KHR_swapchain`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_swapchain.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkGetSwapchainImagesKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkGetSwapchainImagesKHR.html)"]
+    pub unsafe fn get_swapchain_images_khr_to_vec(&self, swapchain: vk::SwapchainKHR) -> Result<Vec<vk::Image>, Error> {
+        loop {
+            let mut swapchain_image_count = 0;
+            match (try_load_fn(self.fns.get_swapchain_images_khr, "vkGetSwapchainImagesKHR")?)(self.handle, swapchain, &raw mut swapchain_image_count, std::ptr::null_mut()) {
+                vk::Result::Success => {}
+                result => {
+                    return Err(Error::Vulkan {
+                        result,
+                        command: "vkGetSwapchainImagesKHR",
+                        create_info: None,
+                    })
+                }
+            }
+            let mut swapchain_images = Vec::with_capacity(swapchain_image_count as usize);
+            match (try_load_fn(self.fns.get_swapchain_images_khr, "vkGetSwapchainImagesKHR")?)(self.handle, swapchain, &raw mut swapchain_image_count, swapchain_images.as_mut_ptr()) {
+                vk::Result::Success => {
+                    swapchain_images.set_len(swapchain_image_count as usize);
+                    return Ok(swapchain_images);
+                }
+                vk::Result::Incomplete => {}
+                result => {
+                    return Err(Error::Vulkan {
+                        result,
+                        command: "vkGetSwapchainImagesKHR",
+                        create_info: None,
+                    })
+                }
+            }
         }
     }
 
@@ -1927,11 +3562,20 @@ impl Device {
     #[doc = "**Provided by**: [`VK_KHR_swapchain`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_swapchain.html)"]
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkAcquireNextImage2KHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkAcquireNextImage2KHR.html)"]
-    pub unsafe fn acquire_next_image2_khr(&self, p_acquire_info: *const vk::AcquireNextImageInfoKHR) -> Result<u32, Error> {
-        let mut p_image_index = std::mem::MaybeUninit::uninit();
-        match (self.fns.acquire_next_image2_khr.unwrap_unchecked())(self.handle, p_acquire_info, p_image_index.as_mut_ptr()) {
-            vk::Result::Success => Ok(p_image_index.assume_init()),
-            result => Err(Error::Vulkan(result)),
+    pub unsafe fn acquire_next_image2_khr(&self, p_acquire_info: *const vk::AcquireNextImageInfoKHR) -> Result<(u32, vk::Result), Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkAcquireNextImage2KHR", "pAcquireInfo", p_acquire_info);
+            vk::check_structs("vkAcquireNextImage2KHR", p_acquire_info, 1);
+        }
+        let mut p_image_index = std::mem::MaybeUninit::zeroed();
+        match (try_load_fn(self.fns.acquire_next_image2_khr, "vkAcquireNextImage2KHR")?)(self.handle, p_acquire_info, p_image_index.as_mut_ptr()) {
+            result @ (vk::Result::Success | vk::Result::Timeout | vk::Result::NotReady | vk::Result::SuboptimalKHR) => Ok((p_image_index.assume_init(), result)),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkAcquireNextImage2KHR",
+                create_info: None,
+            }),
         }
     }
 
@@ -1943,10 +3587,19 @@ impl Device {
     #[doc = "**Provided by**: [`VK_KHR_swapchain`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_swapchain.html)"]
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkQueuePresentKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkQueuePresentKHR.html)"]
-    pub unsafe fn queue_present_khr(&self, queue: vk::Queue, p_present_info: *const vk::PresentInfoKHR) -> Result<(), Error> {
-        match (self.fns.queue_present_khr.unwrap_unchecked())(queue, p_present_info) {
-            vk::Result::Success => Ok(()),
-            result => Err(Error::Vulkan(result)),
+    pub unsafe fn queue_present_khr(&self, queue: vk::Queue, p_present_info: *const vk::PresentInfoKHR) -> Result<vk::Result, Error> {
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkQueuePresentKHR", "pPresentInfo", p_present_info);
+            vk::check_structs("vkQueuePresentKHR", p_present_info, 1);
+        }
+        match (try_load_fn(self.fns.queue_present_khr, "vkQueuePresentKHR")?)(queue, p_present_info) {
+            result @ (vk::Result::Success | vk::Result::SuboptimalKHR) => Ok(result),
+            result => Err(Error::Vulkan {
+                result,
+                command: "vkQueuePresentKHR",
+                create_info: None,
+            }),
         }
     }
 
@@ -1965,7 +3618,31 @@ impl Device {
         p_infos: *const vk::AccelerationStructureBuildGeometryInfoKHR,
         pp_build_range_infos: *const *const vk::AccelerationStructureBuildRangeInfoKHR,
     ) {
-        (self.fns.cmd_build_acceleration_structures_khr.unwrap_unchecked())(command_buffer, info_count, p_infos, pp_build_range_infos);
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_count("vkCmdBuildAccelerationStructuresKHR", "infoCount", info_count);
+            vk::check_array("vkCmdBuildAccelerationStructuresKHR", "pInfos", p_infos, info_count as usize);
+            vk::check_structs("vkCmdBuildAccelerationStructuresKHR", p_infos, info_count as usize);
+            vk::check_array("vkCmdBuildAccelerationStructuresKHR", "ppBuildRangeInfos", pp_build_range_infos, info_count as usize);
+        }
+        (load_fn(self.fns.cmd_build_acceleration_structures_khr, "vkCmdBuildAccelerationStructuresKHR"))(command_buffer, info_count, p_infos, pp_build_range_infos);
+    }
+
+    #[inline]
+    #[doc = "**Chapter**: Acceleration Structures"]
+    #[doc = "<br>"]
+    #[doc = "**Description**: Build an acceleration structure"]
+    #[doc = "<br>"]
+    #[doc = "**Provided by**: [`VK_KHR_acceleration_structure`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/VK_KHR_acceleration_structure.html)"]
+    #[doc = "<br>"]
+    #[doc = "**Reference**: [`vkCmdBuildAccelerationStructuresKHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdBuildAccelerationStructuresKHR.html)"]
+    pub unsafe fn cmd_build_acceleration_structures_khr_slice(
+        &self,
+        command_buffer: vk::CommandBuffer,
+        infos: &[vk::AccelerationStructureBuildGeometryInfoKHR],
+        pp_build_range_infos: *const *const vk::AccelerationStructureBuildRangeInfoKHR,
+    ) {
+        self.cmd_build_acceleration_structures_khr(command_buffer, u32::try_from(infos.len()).unwrap(), infos.as_ptr(), pp_build_range_infos);
     }
 
     #[inline]
@@ -1987,7 +3664,14 @@ impl Device {
         height: u32,
         depth: u32,
     ) {
-        (self.fns.cmd_trace_rays_khr.unwrap_unchecked())(
+        #[cfg(all(debug_assertions, feature = "valid-usage"))]
+        {
+            vk::check_ptr("vkCmdTraceRaysKHR", "pRaygenShaderBindingTable", p_raygen_shader_binding_table);
+            vk::check_ptr("vkCmdTraceRaysKHR", "pMissShaderBindingTable", p_miss_shader_binding_table);
+            vk::check_ptr("vkCmdTraceRaysKHR", "pHitShaderBindingTable", p_hit_shader_binding_table);
+            vk::check_ptr("vkCmdTraceRaysKHR", "pCallableShaderBindingTable", p_callable_shader_binding_table);
+        }
+        (load_fn(self.fns.cmd_trace_rays_khr, "vkCmdTraceRaysKHR"))(
             command_buffer,
             p_raygen_shader_binding_table,
             p_miss_shader_binding_table,
@@ -2008,6 +3692,6 @@ impl Device {
     #[doc = "<br>"]
     #[doc = "**Reference**: [`vkCmdTraceRaysIndirect2KHR`](https://www.khronos.org/registry/vulkan/specs/1.3-extensions/man/html/vkCmdTraceRaysIndirect2KHR.html)"]
     pub unsafe fn cmd_trace_rays_indirect2_khr(&self, command_buffer: vk::CommandBuffer, indirect_device_address: vk::DeviceAddress) {
-        (self.fns.cmd_trace_rays_indirect2_khr.unwrap_unchecked())(command_buffer, indirect_device_address);
+        (load_fn(self.fns.cmd_trace_rays_indirect2_khr, "vkCmdTraceRaysIndirect2KHR"))(command_buffer, indirect_device_address);
     }
 }